@@ -0,0 +1,86 @@
+use soroban_sdk::{Address, Env, IntoVal, String, TryFromVal, Val};
+
+use crate::errors::ContributorError;
+use crate::storage::{DataKey, LEDGER_BUMP, LEDGER_THRESHOLD};
+
+// ── Internal helpers ─────────────────────────────────────────
+
+/// Seconds a deregistered address must wait before registering again.
+/// 0 (the default) disables the cooldown.
+pub(crate) fn get_cooldown(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ReregistrationCooldown)
+        .unwrap_or(0)
+}
+
+pub(crate) fn get_deregistered_at(env: &Env, address: &Address) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DeregisteredAt(address.clone()))
+}
+
+pub(crate) fn mark_deregistered(env: &Env, address: &Address) {
+    let key = DataKey::DeregisteredAt(address.clone());
+    env.storage()
+        .persistent()
+        .set(&key, &env.ledger().timestamp());
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+}
+
+/// Fail while `address` is still cooling off from a deregistration;
+/// otherwise drop the stale tombstone so it can (re-)register.
+pub(crate) fn ensure_can_register(env: &Env, address: &Address) -> Result<(), ContributorError> {
+    if let Some(deregistered_at) = get_deregistered_at(env, address) {
+        if env.ledger().timestamp() < deregistered_at.saturating_add(get_cooldown(env)) {
+            return Err(ContributorError::ReregistrationCooldownActive);
+        }
+        env.storage()
+            .persistent()
+            .remove(&DataKey::DeregisteredAt(address.clone()));
+    }
+    Ok(())
+}
+
+pub(crate) fn mark_handle_deregistered(env: &Env, github_handle: &String) {
+    let key = DataKey::DeregisteredHandleAt(github_handle.clone());
+    env.storage()
+        .persistent()
+        .set(&key, &env.ledger().timestamp());
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+}
+
+/// Fail while `github_handle` is still cooling off from its holder's
+/// deregistration, so the same handle cannot come straight back from a
+/// fresh wallet with a clean record; otherwise drop the stale tombstone.
+pub(crate) fn ensure_handle_can_register(
+    env: &Env,
+    github_handle: &String,
+) -> Result<(), ContributorError> {
+    let key = DataKey::DeregisteredHandleAt(github_handle.clone());
+    if let Some(deregistered_at) = env.storage().persistent().get::<_, u64>(&key) {
+        if env.ledger().timestamp() < deregistered_at.saturating_add(get_cooldown(env)) {
+            return Err(ContributorError::ReregistrationCooldownActive);
+        }
+        env.storage().persistent().remove(&key);
+    }
+    Ok(())
+}
+
+/// Move a persistent entry to a new key, if present.
+pub(crate) fn move_entry<V>(env: &Env, from: &DataKey, to: &DataKey)
+where
+    V: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    if let Some(value) = env.storage().persistent().get::<_, V>(from) {
+        env.storage().persistent().set(to, &value);
+        env.storage()
+            .persistent()
+            .extend_ttl(to, LEDGER_THRESHOLD, LEDGER_BUMP);
+        env.storage().persistent().remove(from);
+    }
+}
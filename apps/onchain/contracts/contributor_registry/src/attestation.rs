@@ -0,0 +1,114 @@
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, String};
+
+use crate::errors::ContributorError;
+use crate::storage::{DataKey, LEDGER_BUMP, LEDGER_THRESHOLD};
+
+// ── Types ────────────────────────────────────────────────────
+
+/// The statement the off-chain verifier signs after completing GitHub OAuth
+/// for `address`.  The XDR encoding of this struct is the signed message;
+/// `contract` binds the attestation to this registry deployment.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GitHubAttestation {
+    pub contract: Address,
+    pub address: Address,
+    pub github_handle: String,
+    /// Ledger timestamp after which the attestation can no longer be used.
+    pub expires_at: u64,
+}
+
+/// A verified claimant contesting a handle held by another verified
+/// contributor.  Resolved by multisig.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HandleDispute {
+    pub github_handle: String,
+    pub claimant: Address,
+    pub holder: Address,
+    pub opened_at: u64,
+}
+
+// ── Internal helpers ─────────────────────────────────────────
+
+pub(crate) fn get_verifier_key(env: &Env) -> Option<BytesN<32>> {
+    env.storage().instance().get(&DataKey::VerifierKey)
+}
+
+pub(crate) fn message(
+    env: &Env,
+    address: &Address,
+    github_handle: &String,
+    expires_at: u64,
+) -> Bytes {
+    GitHubAttestation {
+        contract: env.current_contract_address(),
+        address: address.clone(),
+        github_handle: github_handle.clone(),
+        expires_at,
+    }
+    .to_xdr(env)
+}
+
+/// Check the verifier's signature over the attestation.  An invalid
+/// signature traps inside `ed25519_verify`.
+pub(crate) fn verify(
+    env: &Env,
+    address: &Address,
+    github_handle: &String,
+    expires_at: u64,
+    signature: &BytesN<64>,
+) -> Result<(), ContributorError> {
+    let key = get_verifier_key(env).ok_or(ContributorError::VerifierNotConfigured)?;
+    if github_handle.is_empty() {
+        return Err(ContributorError::InvalidGitHubHandle);
+    }
+    if env.ledger().timestamp() >= expires_at {
+        return Err(ContributorError::AttestationExpired);
+    }
+    env.crypto().ed25519_verify(
+        &key,
+        &message(env, address, github_handle, expires_at),
+        signature,
+    );
+    Ok(())
+}
+
+pub(crate) fn is_verified(env: &Env, address: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::HandleVerified(address.clone()))
+}
+
+pub(crate) fn set_verified(env: &Env, address: &Address, verified: bool) {
+    let key = DataKey::HandleVerified(address.clone());
+    if verified {
+        env.storage().persistent().set(&key, &true);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+pub(crate) fn get_dispute(env: &Env, github_handle: &String) -> Option<HandleDispute> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::HandleDispute(github_handle.clone()))
+}
+
+pub(crate) fn set_dispute(env: &Env, dispute: &HandleDispute) {
+    let key = DataKey::HandleDispute(dispute.github_handle.clone());
+    env.storage().persistent().set(&key, dispute);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+}
+
+pub(crate) fn remove_dispute(env: &Env, github_handle: &String) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::HandleDispute(github_handle.clone()));
+}
@@ -0,0 +1,273 @@
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
+
+use crate::errors::ContributorError;
+use crate::storage::{DataKey, LEDGER_BUMP, LEDGER_THRESHOLD};
+
+// ── Constants ────────────────────────────────────────────────
+
+/// Ids 1..=4 belonged to the former fixed `Badge` enum (EarlyAdopter,
+/// BugHunter, TopContributor, SecurityAuditor).  They keep those ids and
+/// resolve to built-in definitions until governance overrides them.
+pub const LEGACY_BADGE_COUNT: u32 = 4;
+
+/// Caps name and metadata URI sizes to keep definitions cheap to store.
+pub const MAX_BADGE_NAME_LEN: u32 = 64;
+pub const MAX_BADGE_URI_LEN: u32 = 256;
+
+/// Collection metadata reported to wallets through `name` / `symbol`.
+pub const COLLECTION_NAME: &str = "LumenPulse Contributor Badges";
+pub const COLLECTION_SYMBOL: &str = "LPBADGE";
+
+// ── Types ────────────────────────────────────────────────────
+
+/// A governance-defined badge.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BadgeDefinition {
+    pub name: String,
+    pub metadata_uri: String,
+    /// Reputation awarded on grant and taken back on revocation.
+    pub reputation_bonus: u64,
+    /// How long a grant stays valid, in seconds.  0 = never expires.
+    pub validity_secs: u64,
+    /// Whether holders may move the badge to another contributor.  Grants
+    /// that awarded a reputation bonus never move.
+    pub transferable: bool,
+    /// Inactive badges can no longer be granted; existing grants remain.
+    pub active: bool,
+}
+
+/// A badge held by a contributor, as stored.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BadgeGrant {
+    pub badge_id: u32,
+    /// Certificate token minted for this grant.
+    pub token_id: u32,
+    pub issuer: Address,
+    pub granted_at: u64,
+    /// 0 = never expires.
+    pub expires_at: u64,
+    /// Reputation awarded to the holder for this grant; exactly this is
+    /// taken back on revocation, whatever the definition says by then.
+    pub bonus: u64,
+}
+
+/// A grant joined with its definition, as returned by `get_badges`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BadgeRecord {
+    pub badge_id: u32,
+    pub token_id: u32,
+    pub name: String,
+    pub metadata_uri: String,
+    pub issuer: Address,
+    pub granted_at: u64,
+    pub expires_at: u64,
+}
+
+/// Reverse index from a certificate token to the grant it represents.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BadgeToken {
+    pub owner: Address,
+    pub badge_id: u32,
+}
+
+// ── Internal helpers ─────────────────────────────────────────
+
+pub(crate) fn validate_definition(definition: &BadgeDefinition) -> Result<(), ContributorError> {
+    if definition.name.is_empty()
+        || definition.name.len() > MAX_BADGE_NAME_LEN
+        || definition.metadata_uri.len() > MAX_BADGE_URI_LEN
+    {
+        return Err(ContributorError::InvalidBadgeDefinition);
+    }
+    Ok(())
+}
+
+fn legacy_definition(env: &Env, badge_id: u32) -> Option<BadgeDefinition> {
+    let name = match badge_id {
+        1 => "Early Adopter",
+        2 => "Bug Hunter",
+        3 => "Top Contributor",
+        4 => "Security Auditor",
+        _ => return None,
+    };
+    Some(BadgeDefinition {
+        name: String::from_str(env, name),
+        metadata_uri: String::from_str(env, ""),
+        reputation_bonus: 0,
+        validity_secs: 0,
+        transferable: false,
+        active: true,
+    })
+}
+
+pub(crate) fn get_definition(env: &Env, badge_id: u32) -> Option<BadgeDefinition> {
+    let key = DataKey::BadgeDefinition(badge_id);
+    match env.storage().persistent().get(&key) {
+        Some(definition) => {
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+            Some(definition)
+        }
+        None => legacy_definition(env, badge_id),
+    }
+}
+
+pub(crate) fn set_definition(env: &Env, badge_id: u32, definition: &BadgeDefinition) {
+    let key = DataKey::BadgeDefinition(badge_id);
+    env.storage().persistent().set(&key, definition);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+}
+
+pub(crate) fn next_badge_id(env: &Env) -> u32 {
+    let id: u32 = env
+        .storage()
+        .instance()
+        .get(&DataKey::NextBadgeId)
+        .unwrap_or(LEGACY_BADGE_COUNT + 1);
+    env.storage()
+        .instance()
+        .set(&DataKey::NextBadgeId, &(id + 1));
+    id
+}
+
+/// Convert the pre-registry `Vec<Badge>` entry (stored as enum discriminants)
+/// into grants, removing the legacy key.  Returns the number migrated.
+pub(crate) fn migrate_legacy(env: &Env, contributor: &Address) -> u32 {
+    let legacy_key = DataKey::Badges(contributor.clone());
+    let legacy: Vec<u32> = match env.storage().persistent().get(&legacy_key) {
+        Some(legacy) => legacy,
+        None => return 0,
+    };
+
+    let mut grants = load_grants(env, contributor);
+    let mut migrated = 0;
+    for badge_id in legacy.iter() {
+        if grants.iter().any(|grant| grant.badge_id == badge_id) {
+            continue;
+        }
+        grants.push_back(BadgeGrant {
+            badge_id,
+            token_id: mint_token(env, contributor, badge_id),
+            issuer: env.current_contract_address(),
+            granted_at: env.ledger().timestamp(),
+            expires_at: 0,
+            bonus: 0,
+        });
+        migrated += 1;
+    }
+    env.storage().persistent().remove(&legacy_key);
+    save_grants(env, contributor, &grants);
+    migrated
+}
+
+fn load_grants(env: &Env, contributor: &Address) -> Vec<BadgeGrant> {
+    let key = DataKey::BadgeGrants(contributor.clone());
+    let grants: Vec<BadgeGrant> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+    if !grants.is_empty() {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+    }
+    grants
+}
+
+/// All stored grants for a contributor (including expired ones), migrating
+/// legacy storage first if needed.
+pub(crate) fn get_grants(env: &Env, contributor: &Address) -> Vec<BadgeGrant> {
+    if env
+        .storage()
+        .persistent()
+        .has(&DataKey::Badges(contributor.clone()))
+    {
+        migrate_legacy(env, contributor);
+    }
+    load_grants(env, contributor)
+}
+
+pub(crate) fn save_grants(env: &Env, contributor: &Address, grants: &Vec<BadgeGrant>) {
+    let key = DataKey::BadgeGrants(contributor.clone());
+    if grants.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, grants);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+    }
+}
+
+/// Mint a certificate token for a new grant and return its id.  Token ids
+/// start at 1 and are never reused.
+pub(crate) fn mint_token(env: &Env, owner: &Address, badge_id: u32) -> u32 {
+    let token_id: u32 = env
+        .storage()
+        .instance()
+        .get(&DataKey::NextBadgeTokenId)
+        .unwrap_or(1);
+    env.storage()
+        .instance()
+        .set(&DataKey::NextBadgeTokenId, &(token_id + 1));
+    set_token_owner(env, token_id, owner, badge_id);
+    token_id
+}
+
+pub(crate) fn set_token_owner(env: &Env, token_id: u32, owner: &Address, badge_id: u32) {
+    let key = DataKey::BadgeToken(token_id);
+    env.storage().persistent().set(
+        &key,
+        &BadgeToken {
+            owner: owner.clone(),
+            badge_id,
+        },
+    );
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+}
+
+pub(crate) fn burn_token(env: &Env, token_id: u32) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::BadgeToken(token_id));
+}
+
+/// The live grant behind `token_id`, with its owner.  Burned tokens and
+/// expired grants resolve to `None`.
+pub(crate) fn live_token(env: &Env, token_id: u32) -> Option<(Address, BadgeGrant)> {
+    let token: BadgeToken = env
+        .storage()
+        .persistent()
+        .get(&DataKey::BadgeToken(token_id))?;
+    let now = env.ledger().timestamp();
+    get_grants(env, &token.owner)
+        .iter()
+        .find(|grant| grant.token_id == token_id && is_live(grant, now))
+        .map(|grant| (token.owner, grant))
+}
+
+pub(crate) fn is_live(grant: &BadgeGrant, now: u64) -> bool {
+    grant.expires_at == 0 || now < grant.expires_at
+}
+
+/// Ids of the contributor's unexpired badges.
+pub(crate) fn live_badge_ids(env: &Env, contributor: &Address) -> Vec<u32> {
+    let now = env.ledger().timestamp();
+    let mut ids = Vec::new(env);
+    for grant in get_grants(env, contributor).iter() {
+        if is_live(&grant, now) {
+            ids.push_back(grant.badge_id);
+        }
+    }
+    ids
+}
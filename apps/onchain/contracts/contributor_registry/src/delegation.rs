@@ -0,0 +1,389 @@
+use soroban_sdk::{contracttype, Address, Env, IntoVal, Symbol, TryFromVal, Val, Vec};
+
+use crate::errors::ContributorError;
+use crate::storage::{DataKey, LEDGER_BUMP, LEDGER_THRESHOLD};
+
+// ── Constants ────────────────────────────────────────────────
+
+/// Checkpoints retained per delegator / delegatee and scope.  Once the cap
+/// is reached the oldest checkpoint is dropped for every new one recorded,
+/// and snapshots older than the oldest retained one fail with
+/// `SnapshotTooOld` rather than guessing.
+pub const MAX_DELEGATION_CHECKPOINTS: u32 = 50;
+
+/// Narrower-than-global scopes a single contributor may delegate in.
+pub const MAX_DELEGATION_SCOPES: u32 = 20;
+
+// ── Types ────────────────────────────────────────────────────
+
+/// Where a delegation applies.  A `Contract` or `Topic` delegation overrides
+/// the `Global` one for votes in that scope; delegating a narrower scope to
+/// yourself keeps your own vote there.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DelegationScope {
+    Global,
+    /// Votes cast in one voting contract (e.g. project_registry).
+    Contract(Address),
+    /// Votes on a governance-defined topic.
+    Topic(Symbol),
+}
+
+/// A delegator's current delegation in one scope.  `amount` is the
+/// reputation it carries, refreshed on every recorded reputation change and
+/// whenever pending inactivity decay is materialised (`sync_reputation`).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Delegation {
+    pub delegatee: Address,
+    pub amount: u64,
+}
+
+/// Who a delegator pointed at from `timestamp` on (`None` = nobody).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DelegateCheckpoint {
+    pub timestamp: u64,
+    pub delegatee: Option<Address>,
+}
+
+/// A delegated total from `timestamp` on.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PowerCheckpoint {
+    pub timestamp: u64,
+    pub power: u64,
+}
+
+// ── Internal helpers ─────────────────────────────────────────
+
+fn load<T>(env: &Env, key: &DataKey) -> Vec<T>
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    let items: Vec<T> = env.storage().persistent().get(key).unwrap_or(Vec::new(env));
+    if !items.is_empty() {
+        env.storage()
+            .persistent()
+            .extend_ttl(key, LEDGER_THRESHOLD, LEDGER_BUMP);
+    }
+    items
+}
+
+fn save<T>(env: &Env, key: &DataKey, items: &Vec<T>)
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    if items.is_empty() {
+        env.storage().persistent().remove(key);
+    } else {
+        env.storage().persistent().set(key, items);
+        env.storage()
+            .persistent()
+            .extend_ttl(key, LEDGER_THRESHOLD, LEDGER_BUMP);
+    }
+}
+
+pub(crate) fn get(env: &Env, delegator: &Address, scope: &DelegationScope) -> Option<Delegation> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Delegation(delegator.clone(), scope.clone()))
+}
+
+fn put(env: &Env, delegator: &Address, scope: &DelegationScope, delegation: &Option<Delegation>) {
+    let key = DataKey::Delegation(delegator.clone(), scope.clone());
+    match delegation {
+        Some(delegation) => {
+            env.storage().persistent().set(&key, delegation);
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+
+    let key = DataKey::DelegateHistory(delegator.clone(), scope.clone());
+    let mut history: Vec<DelegateCheckpoint> = load(env, &key);
+    let now = env.ledger().timestamp();
+    if history.last().is_some_and(|last| last.timestamp == now) {
+        history.pop_back();
+    } else if history.len() >= MAX_DELEGATION_CHECKPOINTS {
+        history.pop_front();
+    }
+    history.push_back(DelegateCheckpoint {
+        timestamp: now,
+        delegatee: delegation.as_ref().map(|d| d.delegatee.clone()),
+    });
+    save(env, &key, &history);
+}
+
+/// Narrower scopes `delegator` currently has a delegation in.
+pub(crate) fn scopes(env: &Env, delegator: &Address) -> Vec<DelegationScope> {
+    load(env, &DataKey::DelegationScopes(delegator.clone()))
+}
+
+/// Add `delta` to a checkpointed total, coalescing changes within a ledger.
+fn shift(env: &Env, key: DataKey, delta: i128) {
+    if delta == 0 {
+        return;
+    }
+    let mut history: Vec<PowerCheckpoint> = load(env, &key);
+    let now = env.ledger().timestamp();
+    let current = history.last().map(|last| last.power).unwrap_or(0);
+    let power = (current as i128 + delta).clamp(0, u64::MAX as i128) as u64;
+    if history.last().is_some_and(|last| last.timestamp == now) {
+        history.pop_back();
+    } else if history.len() >= MAX_DELEGATION_CHECKPOINTS {
+        history.pop_front();
+    }
+    history.push_back(PowerCheckpoint {
+        timestamp: now,
+        power,
+    });
+    save(env, &key, &history);
+}
+
+/// Move `delta` of `delegator`'s power along its `scope` delegation to
+/// `delegatee`, keeping the override totals in step.
+fn apply(
+    env: &Env,
+    delegator: &Address,
+    scope: &DelegationScope,
+    delegatee: &Address,
+    delta: i128,
+) {
+    if *scope == DelegationScope::Global {
+        shift(
+            env,
+            DataKey::DelegatedPower(delegatee.clone(), scope.clone()),
+            delta,
+        );
+        for narrower in scopes(env, delegator).iter() {
+            shift(
+                env,
+                DataKey::OverriddenPower(delegatee.clone(), narrower),
+                delta,
+            );
+        }
+        return;
+    }
+    // A narrower self-delegation only cancels the global one; the delegator
+    // already counts their own reputation.
+    if delegatee != delegator {
+        shift(
+            env,
+            DataKey::DelegatedPower(delegatee.clone(), scope.clone()),
+            delta,
+        );
+    }
+    if let Some(global) = get(env, delegator, &DelegationScope::Global) {
+        shift(
+            env,
+            DataKey::OverriddenPower(global.delegatee, scope.clone()),
+            delta,
+        );
+    }
+}
+
+/// Replace `delegator`'s delegation in `scope` (`None` removes it).
+pub(crate) fn set(
+    env: &Env,
+    delegator: &Address,
+    scope: &DelegationScope,
+    delegatee: Option<Address>,
+    amount: u64,
+) -> Result<(), ContributorError> {
+    let scope_key = DataKey::DelegationScopes(delegator.clone());
+    let mut narrower = scopes(env, delegator);
+
+    if let Some(existing) = get(env, delegator, scope) {
+        apply(
+            env,
+            delegator,
+            scope,
+            &existing.delegatee,
+            -(existing.amount as i128),
+        );
+        if *scope != DelegationScope::Global {
+            if let Some(index) = narrower.first_index_of(scope) {
+                narrower.remove(index);
+            }
+            save(env, &scope_key, &narrower);
+        }
+    }
+
+    let delegation = match delegatee {
+        Some(delegatee) => {
+            if *scope != DelegationScope::Global {
+                if narrower.len() >= MAX_DELEGATION_SCOPES {
+                    return Err(ContributorError::InvalidDelegation);
+                }
+                narrower.push_back(scope.clone());
+                save(env, &scope_key, &narrower);
+            }
+            apply(env, delegator, scope, &delegatee, amount as i128);
+            Some(Delegation { delegatee, amount })
+        }
+        None => None,
+    };
+    put(env, delegator, scope, &delegation);
+    Ok(())
+}
+
+/// Remove every delegation `delegator` has made, returning them so a caller
+/// can re-create them (e.g. for a migrated account).
+pub(crate) fn clear_all(env: &Env, delegator: &Address) -> Vec<(DelegationScope, Address)> {
+    let mut removed = Vec::new(env);
+    for scope in scopes(env, delegator).iter() {
+        if let Some(delegation) = get(env, delegator, &scope) {
+            removed.push_back((scope.clone(), delegation.delegatee));
+            let _ = set(env, delegator, &scope, None, 0);
+        }
+    }
+    if let Some(delegation) = get(env, delegator, &DelegationScope::Global) {
+        removed.push_front((DelegationScope::Global, delegation.delegatee));
+        let _ = set(env, delegator, &DelegationScope::Global, None, 0);
+    }
+    removed
+}
+
+/// Carry a delegator's new reputation score through all their delegations.
+pub(crate) fn on_score_change(env: &Env, delegator: &Address, score: u64) {
+    let mut all = scopes(env, delegator);
+    all.push_front(DelegationScope::Global);
+    for scope in all.iter() {
+        if let Some(mut delegation) = get(env, delegator, &scope) {
+            let delta = score as i128 - delegation.amount as i128;
+            if delta == 0 {
+                continue;
+            }
+            if scope == DelegationScope::Global {
+                shift(
+                    env,
+                    DataKey::DelegatedPower(delegation.delegatee.clone(), scope.clone()),
+                    delta,
+                );
+                for narrower in scopes(env, delegator).iter() {
+                    shift(
+                        env,
+                        DataKey::OverriddenPower(delegation.delegatee.clone(), narrower),
+                        delta,
+                    );
+                }
+            } else if delegation.delegatee != *delegator {
+                shift(
+                    env,
+                    DataKey::DelegatedPower(delegation.delegatee.clone(), scope.clone()),
+                    delta,
+                );
+            }
+            delegation.amount = score;
+            let key = DataKey::Delegation(delegator.clone(), scope);
+            env.storage().persistent().set(&key, &delegation);
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+        }
+    }
+}
+
+/// Fail if `history` has been truncated past `timestamp`, i.e. the value
+/// just before `timestamp` is no longer known.
+fn ensure_retained(len: u32, oldest: Option<u64>, timestamp: u64) -> Result<(), ContributorError> {
+    if len >= MAX_DELEGATION_CHECKPOINTS && oldest.is_some_and(|oldest| timestamp <= oldest) {
+        return Err(ContributorError::SnapshotTooOld);
+    }
+    Ok(())
+}
+
+/// Who `delegator` had delegated to in exactly `scope` at `timestamp`.
+/// Snapshot reads skip checkpoints written at `timestamp` itself, so a
+/// delegation made in the snapshot ledger cannot add to power already used.
+fn delegate_at(
+    env: &Env,
+    delegator: &Address,
+    scope: &DelegationScope,
+    timestamp: u64,
+) -> Result<Option<Address>, ContributorError> {
+    let history: Vec<DelegateCheckpoint> = load(
+        env,
+        &DataKey::DelegateHistory(delegator.clone(), scope.clone()),
+    );
+    ensure_retained(
+        history.len(),
+        history.first().map(|first| first.timestamp),
+        timestamp,
+    )?;
+    let mut latest = None;
+    for checkpoint in history.iter() {
+        if checkpoint.timestamp >= timestamp {
+            break;
+        }
+        latest = checkpoint.delegatee;
+    }
+    Ok(latest)
+}
+
+/// Who actually votes with `delegator`'s own reputation in `scope` at
+/// `timestamp`, after applying scope overrides.  `None` means themselves.
+pub(crate) fn effective_delegate_at(
+    env: &Env,
+    delegator: &Address,
+    scope: &DelegationScope,
+    timestamp: u64,
+) -> Result<Option<Address>, ContributorError> {
+    let scoped = match scope {
+        DelegationScope::Global => None,
+        _ => delegate_at(env, delegator, scope, timestamp)?,
+    };
+    let delegate = match scoped {
+        Some(delegatee) => Some(delegatee),
+        None => delegate_at(env, delegator, &DelegationScope::Global, timestamp)?,
+    };
+    Ok(delegate.filter(|delegatee| delegatee != delegator))
+}
+
+fn total_at(env: &Env, key: DataKey, timestamp: u64) -> Result<u64, ContributorError> {
+    let history: Vec<PowerCheckpoint> = load(env, &key);
+    ensure_retained(
+        history.len(),
+        history.first().map(|first| first.timestamp),
+        timestamp,
+    )?;
+    let mut power = 0;
+    for checkpoint in history.iter() {
+        if checkpoint.timestamp >= timestamp {
+            break;
+        }
+        power = checkpoint.power;
+    }
+    Ok(power)
+}
+
+/// Reputation delegated to `delegatee` for votes in `scope` at `timestamp`.
+pub(crate) fn delegated_power_at(
+    env: &Env,
+    delegatee: &Address,
+    scope: &DelegationScope,
+    timestamp: u64,
+) -> Result<u64, ContributorError> {
+    let direct = total_at(
+        env,
+        DataKey::DelegatedPower(delegatee.clone(), scope.clone()),
+        timestamp,
+    )?;
+    if *scope == DelegationScope::Global {
+        return Ok(direct);
+    }
+    let global = total_at(
+        env,
+        DataKey::DelegatedPower(delegatee.clone(), DelegationScope::Global),
+        timestamp,
+    )?;
+    let overridden = total_at(
+        env,
+        DataKey::OverriddenPower(delegatee.clone(), scope.clone()),
+        timestamp,
+    )?;
+    Ok(direct.saturating_add(global.saturating_sub(overridden)))
+}
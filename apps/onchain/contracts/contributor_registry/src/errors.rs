@@ -21,4 +21,24 @@ pub enum ContributorError {
     BelowThreshold = 15,
     InvalidNonce = 16,
     InvalidSignature = 17,
+    InvalidDecayConfig = 18,
+    InvalidTierThresholds = 19,
+    BadgeNotFound = 20,
+    BadgeInactive = 21,
+    BadgeNotTransferable = 22,
+    BadgeAlreadyHeld = 23,
+    InvalidBadgeDefinition = 24,
+    BadgeTokenNotFound = 25,
+    InvalidNotificationRule = 26,
+    InvalidNotificationConfig = 27,
+    VerifierNotConfigured = 28,
+    AttestationExpired = 29,
+    GitHubHandleVerified = 30,
+    HandleDisputeNotFound = 31,
+    HandleDisputeOpen = 32,
+    HandleNotDisputable = 33,
+    ReregistrationCooldownActive = 34,
+    InvalidDelegation = 35,
+    DelegationNotFound = 36,
+    SnapshotTooOld = 37,
 }
@@ -1,7 +1,11 @@
-use soroban_sdk::{contractevent, Address, BytesN, String};
+use soroban_sdk::{contractevent, Address, BytesN, String, Symbol};
 
+use crate::delegation::DelegationScope;
 use crate::multisig::{ProposalAction, ProposalStatus};
-use crate::storage::{Badge, PenaltySeverity};
+use crate::notifications::{NotificationConfig, NotificationRule};
+use crate::permissions::{PermissionRule, TierThresholds};
+use crate::reputation::{DecayConfig, ReputationSource};
+use crate::storage::PenaltySeverity;
 
 #[contractevent]
 pub struct UpgradedEvent {
@@ -73,11 +77,21 @@ pub struct GaslessRegistrationEvent {
     pub consumed_nonce: u64,
 }
 
+#[contractevent]
+pub struct BadgeDefinedEvent {
+    #[topic]
+    pub badge_id: u32,
+    pub name: String,
+    pub executor: Address,
+}
+
 #[contractevent]
 pub struct BadgeGrantedEvent {
     #[topic]
     pub contributor: Address,
-    pub badge: Badge,
+    pub badge_id: u32,
+    pub token_id: u32,
+    pub expires_at: u64,
     pub executor: Address,
 }
 
@@ -85,10 +99,20 @@ pub struct BadgeGrantedEvent {
 pub struct BadgeRevokedEvent {
     #[topic]
     pub contributor: Address,
-    pub badge: Badge,
+    pub badge_id: u32,
+    pub token_id: u32,
     pub executor: Address,
 }
 
+#[contractevent]
+pub struct BadgeTransferredEvent {
+    #[topic]
+    pub badge_id: u32,
+    pub token_id: u32,
+    pub from: Address,
+    pub to: Address,
+}
+
 #[contractevent]
 pub struct ReputationPenaltyAppliedEvent {
     #[topic]
@@ -99,3 +123,128 @@ pub struct ReputationPenaltyAppliedEvent {
     pub reason: String,
     pub executor: Address,
 }
+
+#[contractevent]
+pub struct ReputationChangedEvent {
+    #[topic]
+    pub contributor: Address,
+    pub source: ReputationSource,
+    pub delta: i64,
+    pub new_score: u64,
+}
+
+#[contractevent]
+pub struct DecayConfiguredEvent {
+    #[topic]
+    pub executor: Address,
+    pub config: Option<DecayConfig>,
+}
+
+#[contractevent]
+pub struct TierThresholdsUpdatedEvent {
+    #[topic]
+    pub executor: Address,
+    pub thresholds: TierThresholds,
+}
+
+#[contractevent]
+pub struct PermissionRuleSetEvent {
+    #[topic]
+    pub permission: Symbol,
+    pub rule: Option<PermissionRule>,
+    pub executor: Address,
+}
+
+#[contractevent]
+pub struct VerifierKeySetEvent {
+    #[topic]
+    pub executor: Address,
+    pub key: Option<BytesN<32>>,
+}
+
+#[contractevent]
+pub struct GitHubVerifiedEvent {
+    #[topic]
+    pub contributor: Address,
+    pub github_handle: String,
+}
+
+#[contractevent]
+pub struct GitHubHandleReclaimedEvent {
+    #[topic]
+    pub github_handle: String,
+    pub from: Address,
+    pub to: Address,
+}
+
+#[contractevent]
+pub struct HandleDisputeOpenedEvent {
+    #[topic]
+    pub github_handle: String,
+    pub claimant: Address,
+    pub holder: Address,
+}
+
+#[contractevent]
+pub struct HandleDisputeResolvedEvent {
+    #[topic]
+    pub github_handle: String,
+    pub winner: Address,
+    pub executor: Address,
+}
+
+#[contractevent]
+pub struct TrustedSourceSetEvent {
+    #[topic]
+    pub source: Address,
+    pub trusted: bool,
+    pub executor: Address,
+}
+
+#[contractevent]
+pub struct NotificationRuleSetEvent {
+    #[topic]
+    pub event_type: Symbol,
+    pub rule: Option<NotificationRule>,
+    pub executor: Address,
+}
+
+#[contractevent]
+pub struct NotificationConfigSetEvent {
+    #[topic]
+    pub executor: Address,
+    pub config: NotificationConfig,
+}
+
+#[contractevent]
+pub struct ContributorDeregisteredEvent {
+    #[topic]
+    pub contributor: Address,
+    pub github_handle: String,
+    pub badges_burned: u32,
+}
+
+#[contractevent]
+pub struct ContributorMigratedEvent {
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub to: Address,
+    pub github_handle: String,
+}
+
+#[contractevent]
+pub struct RegistrationCooldownSetEvent {
+    #[topic]
+    pub executor: Address,
+    pub cooldown_secs: u64,
+}
+
+#[contractevent]
+pub struct DelegationChangedEvent {
+    #[topic]
+    pub delegator: Address,
+    pub scope: DelegationScope,
+    pub delegatee: Option<Address>,
+    pub amount: u64,
+}
@@ -1,26 +1,48 @@
 #![no_std]
 
+mod accounts;
+mod attestation;
+mod badges;
+mod delegation;
 mod errors;
 mod events;
 mod multisig;
+mod notifications;
+mod permissions;
+mod reputation;
 mod storage;
 
+use attestation::HandleDispute;
+use badges::{BadgeDefinition, BadgeGrant, BadgeRecord};
+use delegation::DelegationScope;
 use errors::ContributorError;
 use events::{
-    AdminChangedEvent, BadgeGrantedEvent, BadgeRevokedEvent, GaslessRegistrationEvent,
-    MultisigConfiguredEvent, ReputationPenaltyAppliedEvent, UpgradedEvent,
+    AdminChangedEvent, BadgeDefinedEvent, BadgeGrantedEvent, BadgeRevokedEvent,
+    BadgeTransferredEvent, ContributorDeregisteredEvent, ContributorMigratedEvent,
+    DecayConfiguredEvent, DelegationChangedEvent, GaslessRegistrationEvent,
+    GitHubHandleReclaimedEvent, GitHubVerifiedEvent, HandleDisputeOpenedEvent,
+    HandleDisputeResolvedEvent, MultisigConfiguredEvent, NotificationConfigSetEvent,
+    NotificationRuleSetEvent, PermissionRuleSetEvent, RegistrationCooldownSetEvent,
+    ReputationChangedEvent, ReputationPenaltyAppliedEvent, TierThresholdsUpdatedEvent,
+    TrustedSourceSetEvent, UpgradedEvent, VerifierKeySetEvent,
 };
 use multisig::{
     cancel, consume_approval, expire, get_config, get_proposal, propose, sign, validate_config,
     MultisigConfig, ProposalAction, ProposalStatus, Signer,
 };
 use notification_interface::{Notification, NotificationReceiverTrait};
-use soroban_sdk::xdr::FromXdr;
+use notifications::{EpochUsage, NotificationConfig, NotificationRule};
+pub use notifications::{EVENT_DEPOSIT, EVENT_MILESTONE_APPROVED, EVENT_REFUND, EVENT_WITHDRAW};
+use permissions::{PermissionRule, TierThresholds};
+pub use permissions::{
+    PERMISSION_DISPUTE_MILESTONE, PERMISSION_PROPOSE_PROJECT, PERMISSION_REVIEW,
+};
+use reputation::{DecayConfig, ReputationChange, ReputationSource};
 use soroban_sdk::{
-    contract, contractimpl, Address, Bytes, BytesN, Env, IntoVal, String, Symbol, Vec,
+    contract, contractimpl, vec, Address, Bytes, BytesN, Env, IntoVal, String, Symbol, Vec,
 };
 use storage::{
-    Badge, ContributorData, ContributorTier, DataKey, PenaltyRecord, PenaltySeverity, LEDGER_BUMP,
+    ContributorData, ContributorTier, DataKey, PenaltyRecord, PenaltySeverity, LEDGER_BUMP,
     LEDGER_THRESHOLD,
 };
 
@@ -41,17 +63,6 @@ impl ContributorRegistryContract {
         Ok(())
     }
 
-    fn registration_nonce_of(env: &Env, address: &Address) -> u64 {
-        let key = DataKey::RegistrationNonce(address.clone());
-        let nonce = env.storage().persistent().get(&key).unwrap_or(0);
-        if env.storage().persistent().has(&key) {
-            env.storage()
-                .persistent()
-                .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
-        }
-        nonce
-    }
-
     fn write_contributor(
         env: &Env,
         address: &Address,
@@ -67,6 +78,7 @@ impl ContributorRegistryContract {
         {
             return Err(ContributorError::ContributorAlreadyExists);
         }
+        accounts::ensure_can_register(env, address)?;
         Self::ensure_github_handle_available(env, github_handle, address)?;
 
         let timestamp = env.ledger().timestamp();
@@ -109,8 +121,106 @@ impl ContributorRegistryContract {
             if existing_address != *address {
                 return Err(ContributorError::GitHubHandleTaken);
             }
+            return Ok(());
         }
-        Ok(())
+        accounts::ensure_handle_can_register(env, github_handle)
+    }
+
+    fn put_contributor(env: &Env, contributor: &ContributorData) {
+        let key = DataKey::Contributor(contributor.address.clone());
+        env.storage().persistent().set(&key, contributor);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+    }
+
+    /// Point `github_handle` at `contributor`, releasing the contributor's
+    /// previous handle and marking the new one verified.
+    fn assign_verified_handle(
+        env: &Env,
+        contributor: &mut ContributorData,
+        github_handle: &String,
+    ) {
+        if contributor.github_handle != *github_handle && !contributor.github_handle.is_empty() {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::GitHubIndex(contributor.github_handle.clone()));
+        }
+        contributor.github_handle = github_handle.clone();
+        Self::put_contributor(env, contributor);
+        attestation::set_verified(env, &contributor.address, true);
+
+        let key = DataKey::GitHubIndex(github_handle.clone());
+        env.storage().persistent().set(&key, &contributor.address);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+    }
+
+    /// Strip a handle from its current holder, who keeps their registration
+    /// but must pick (and verify) a new handle.
+    fn release_handle(env: &Env, holder: &mut ContributorData) {
+        holder.github_handle = String::from_str(env, "");
+        Self::put_contributor(env, holder);
+        attestation::set_verified(env, &holder.address, false);
+    }
+
+    /// Load a contributor, apply a reputation change through the history /
+    /// decay pipeline, persist the result and emit `ReputationChangedEvent`.
+    fn change_reputation(
+        env: &Env,
+        address: &Address,
+        source: ReputationSource,
+        delta: i64,
+        reason: String,
+    ) -> Result<u64, ContributorError> {
+        let key = DataKey::Contributor(address.clone());
+        let mut contributor: ContributorData = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(ContributorError::ContributorNotFound)?;
+
+        let old_score = contributor.reputation_score;
+        let new_score = reputation::apply_change(env, &mut contributor, source, delta, reason)?;
+
+        env.storage().persistent().set(&key, &contributor);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+        delegation::on_score_change(env, address, new_score);
+
+        ReputationChangedEvent {
+            contributor: address.clone(),
+            source,
+            // Applied change, including materialised decay and clamping, so
+            // indexers summing deltas stay in step with `new_score`.
+            delta: new_score as i64 - old_score as i64,
+            new_score,
+        }
+        .publish(env);
+
+        Ok(new_score)
+    }
+
+    /// Materialise a contributor's pending inactivity decay, carrying it
+    /// through their delegations.  Emits `ReputationChangedEvent` if the
+    /// score dropped.
+    fn sync_decay(env: &Env, address: &Address) -> Result<ContributorData, ContributorError> {
+        let mut contributor = Self::get_contributor(env.clone(), address.clone())?;
+        let old_score = contributor.reputation_score;
+        if reputation::sync_decay(env, &mut contributor) {
+            Self::put_contributor(env, &contributor);
+            delegation::on_score_change(env, address, contributor.reputation_score);
+            ReputationChangedEvent {
+                contributor: address.clone(),
+                source: ReputationSource::Decay,
+                delta: contributor.reputation_score as i64 - old_score as i64,
+                new_score: contributor.reputation_score,
+            }
+            .publish(env);
+        }
+        Ok(contributor)
     }
 
     // ── Initialisation ───────────────────────────────────────
@@ -260,40 +370,25 @@ impl ContributorRegistryContract {
             return Err(ContributorError::InvalidSignature);
         }
 
-        // Read the current contract-layer nonce before mutating state.
-        let nonce = Self::registration_nonce_of(&env, &address);
-
         // Require that `address` has authorised this specific invocation.
         // The authorisation scope binds the function name, the handle being
         // registered, the caller address, and the current nonce — preventing
-        // cross-user, cross-handle, and replay attacks.
+        // cross-user, cross-handle, and replay attacks.  The nonce is then
+        // advanced so every future signed intent must reference a strictly
+        // higher value.
         // NOTE: `signature` is intentionally excluded from this scope; it is
         // the SorobanAuthorizationEntry itself that carries the cryptographic
         // proof, not a raw bytes argument.
-        address.require_auth_for_args(
-            (
-                Symbol::new(&env, "register_contributor_with_sig"),
-                github_handle.clone(),
-                address.clone(),
-                nonce,
-            )
-                .into_val(&env),
+        let nonce = signed_intent::require_signed(
+            &env,
+            &DataKey::RegistrationNonce(address.clone()),
+            &address,
+            "register_contributor_with_sig",
+            vec![&env, github_handle.into_val(&env), address.into_val(&env)],
         );
 
         Self::write_contributor(&env, &address, &github_handle)?;
 
-        // Advance the contract-layer nonce so every future signed intent must
-        // reference a strictly higher value.
-        let new_nonce = nonce + 1;
-        env.storage()
-            .persistent()
-            .set(&DataKey::RegistrationNonce(address.clone()), &new_nonce);
-        env.storage().persistent().extend_ttl(
-            &DataKey::RegistrationNonce(address.clone()),
-            LEDGER_THRESHOLD,
-            LEDGER_BUMP,
-        );
-
         GaslessRegistrationEvent {
             contributor: address,
             github_handle,
@@ -309,13 +404,35 @@ impl ContributorRegistryContract {
         address: Address,
         github_handle: String,
     ) -> Result<(), ContributorError> {
-        if !env.storage().instance().has(&DataKey::MultisigConfig) {
-            return Err(ContributorError::NotInitialized);
-        }
-        env.storage()
-            .instance()
-            .extend_ttl(LEDGER_THRESHOLD, LEDGER_BUMP);
+        Self::ensure_initialized(&env)?;
         address.require_auth();
+        Self::change_github_handle(&env, &address, &github_handle)
+    }
+
+    /// Gasless variant of `update_contributor`.  `address` signs
+    /// `("update_contributor_with_sig", github_handle, address, nonce)` using
+    /// the same per-address nonce as `register_contributor_with_sig`.
+    pub fn update_contributor_with_sig(
+        env: Env,
+        address: Address,
+        github_handle: String,
+    ) -> Result<(), ContributorError> {
+        Self::ensure_initialized(&env)?;
+        signed_intent::require_signed(
+            &env,
+            &DataKey::RegistrationNonce(address.clone()),
+            &address,
+            "update_contributor_with_sig",
+            vec![&env, github_handle.into_val(&env), address.into_val(&env)],
+        );
+        Self::change_github_handle(&env, &address, &github_handle)
+    }
+
+    fn change_github_handle(
+        env: &Env,
+        address: &Address,
+        github_handle: &String,
+    ) -> Result<(), ContributorError> {
         if github_handle.is_empty() {
             return Err(ContributorError::InvalidGitHubHandle);
         }
@@ -330,11 +447,12 @@ impl ContributorRegistryContract {
             LEDGER_BUMP,
         );
 
-        Self::ensure_github_handle_available(&env, &github_handle, &address)?;
-        if contributor.github_handle != github_handle {
+        Self::ensure_github_handle_available(env, github_handle, address)?;
+        if contributor.github_handle != *github_handle {
             env.storage()
                 .persistent()
                 .remove(&DataKey::GitHubIndex(contributor.github_handle.clone()));
+            attestation::set_verified(env, address, false);
         }
         contributor.github_handle = github_handle.clone();
         env.storage()
@@ -347,126 +465,157 @@ impl ContributorRegistryContract {
         );
         env.storage()
             .persistent()
-            .set(&DataKey::GitHubIndex(github_handle.clone()), &address);
+            .set(&DataKey::GitHubIndex(github_handle.clone()), address);
         env.storage().persistent().extend_ttl(
-            &DataKey::GitHubIndex(github_handle),
+            &DataKey::GitHubIndex(github_handle.clone()),
             LEDGER_THRESHOLD,
             LEDGER_BUMP,
         );
         Ok(())
     }
 
-    /// Deregister a contributor, removing all associated storage entries.
-    ///
-    /// Requires the contributor's own authorization. Removes:
-    /// - `DataKey::Contributor(address)`
-    /// - `DataKey::GitHubIndex(github_handle)`
-    /// - `DataKey::RegistrationNonce(address)`
+    // ── GitHub attestation ───────────────────────────────────
+
+    /// Mark the caller's GitHub handle as verified using an attestation
+    /// signed by the configured verifier key over
+    /// `GitHubAttestation { contract, address, github_handle, expires_at }`
+    /// (see `get_attestation_message`).
     ///
-    /// This prevents orphaned index entries and reclaims rent.
-    pub fn deregister_contributor(env: Env, address: Address) -> Result<(), ContributorError> {
+    /// If the handle differs from the caller's current one it is switched.  A
+    /// handle squatted by an unverified contributor is reclaimed from them; a
+    /// handle held by a verified contributor must go through
+    /// `dispute_github_handle` instead.
+    pub fn verify_github(
+        env: Env,
+        address: Address,
+        github_handle: String,
+        expires_at: u64,
+        signature: BytesN<64>,
+    ) -> Result<(), ContributorError> {
         Self::ensure_initialized(&env)?;
         address.require_auth();
+        attestation::verify(&env, &address, &github_handle, expires_at, &signature)?;
 
-        let contributor: ContributorData = env
+        let mut contributor = Self::get_contributor(env.clone(), address.clone())?;
+        accounts::ensure_handle_can_register(&env, &github_handle)?;
+
+        if let Some(holder) = env
             .storage()
             .persistent()
-            .get(&DataKey::Contributor(address.clone()))
-            .ok_or(ContributorError::ContributorNotFound)?;
+            .get::<_, Address>(&DataKey::GitHubIndex(github_handle.clone()))
+        {
+            if holder != address {
+                let mut holder_data = Self::get_contributor(env.clone(), holder.clone())?;
+                if attestation::is_verified(&env, &holder) {
+                    return Err(ContributorError::GitHubHandleVerified);
+                }
+                Self::release_handle(&env, &mut holder_data);
+                GitHubHandleReclaimedEvent {
+                    github_handle: github_handle.clone(),
+                    from: holder,
+                    to: address.clone(),
+                }
+                .publish(&env);
+            }
+        }
 
-        // State compaction: remove all three related entries atomically.
-        env.storage()
-            .persistent()
-            .remove(&DataKey::GitHubIndex(contributor.github_handle));
-        env.storage()
-            .persistent()
-            .remove(&DataKey::Contributor(address.clone()));
-        env.storage()
-            .persistent()
-            .remove(&DataKey::RegistrationNonce(address));
+        Self::assign_verified_handle(&env, &mut contributor, &github_handle);
+
+        GitHubVerifiedEvent {
+            contributor: address,
+            github_handle,
+        }
+        .publish(&env);
 
         Ok(())
     }
 
-    // ── Sensitive functions — multisig-gated ─────────────────
-
-    pub fn update_reputation(
+    /// Contest a handle held by another verified contributor, backed by a
+    /// fresh attestation for the claimant.  The dispute is settled by
+    /// `resolve_handle_dispute`.
+    pub fn dispute_github_handle(
         env: Env,
-        executor: Address,
-        proposal_id: u64,
-        contributor_address: Address,
-        delta: i64,
+        claimant: Address,
+        github_handle: String,
+        expires_at: u64,
+        signature: BytesN<64>,
     ) -> Result<(), ContributorError> {
-        consume_approval(
-            &env,
-            &executor,
-            proposal_id,
-            &ProposalAction::UpdateReputation,
-        )?;
+        Self::ensure_initialized(&env)?;
+        claimant.require_auth();
+        attestation::verify(&env, &claimant, &github_handle, expires_at, &signature)?;
 
-        let mut contributor: ContributorData = env
+        let _ = Self::get_contributor(env.clone(), claimant.clone())?;
+        if attestation::get_dispute(&env, &github_handle).is_some() {
+            return Err(ContributorError::HandleDisputeOpen);
+        }
+
+        let holder: Address = env
             .storage()
             .persistent()
-            .get(&DataKey::Contributor(contributor_address.clone()))
-            .ok_or(ContributorError::ContributorNotFound)?;
-        env.storage().persistent().extend_ttl(
-            &DataKey::Contributor(contributor_address.clone()),
-            LEDGER_THRESHOLD,
-            LEDGER_BUMP,
-        );
+            .get(&DataKey::GitHubIndex(github_handle.clone()))
+            .ok_or(ContributorError::HandleNotDisputable)?;
+        let _ = Self::get_contributor(env.clone(), holder.clone())?;
+        if holder == claimant || !attestation::is_verified(&env, &holder) {
+            return Err(ContributorError::HandleNotDisputable);
+        }
 
-        let new_score = if delta > 0 {
-            contributor
-                .reputation_score
-                .checked_add(delta as u64)
-                .ok_or(ContributorError::ReputationOverflow)?
-        } else {
-            let abs = delta.checked_abs().unwrap_or(0) as u64;
-            contributor.reputation_score.saturating_sub(abs)
-        };
-        contributor.reputation_score = new_score;
-        env.storage().persistent().set(
-            &DataKey::Contributor(contributor_address.clone()),
-            &contributor,
-        );
-        env.storage().persistent().extend_ttl(
-            &DataKey::Contributor(contributor_address),
-            LEDGER_THRESHOLD,
-            LEDGER_BUMP,
+        attestation::set_dispute(
+            &env,
+            &HandleDispute {
+                github_handle: github_handle.clone(),
+                claimant: claimant.clone(),
+                holder: holder.clone(),
+                opened_at: env.ledger().timestamp(),
+            },
         );
+
+        HandleDisputeOpenedEvent {
+            github_handle,
+            claimant,
+            holder,
+        }
+        .publish(&env);
+
         Ok(())
     }
 
-    pub fn grant_badge(
+    /// Settle a handle dispute.  When `award_to_claimant` is set the handle
+    /// moves to the claimant (verified) and the holder is left without one.
+    ///
+    /// Requires multisig approval for `ProposalAction::ResolveHandleDispute`.
+    pub fn resolve_handle_dispute(
         env: Env,
         executor: Address,
         proposal_id: u64,
-        contributor_address: Address,
-        badge: Badge,
+        github_handle: String,
+        award_to_claimant: bool,
     ) -> Result<(), ContributorError> {
-        consume_approval(&env, &executor, proposal_id, &ProposalAction::GrantBadge)?;
-
-        // Ensure contributor exists
-        let _ = Self::get_contributor(env.clone(), contributor_address.clone())?;
-
-        let key = DataKey::Badges(contributor_address.clone());
-        let mut badges: Vec<Badge> = env
-            .storage()
-            .persistent()
-            .get(&key)
-            .unwrap_or(Vec::new(&env));
-
-        if !badges.contains(badge) {
-            badges.push_back(badge);
-            env.storage().persistent().set(&key, &badges);
-            env.storage()
-                .persistent()
-                .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
-        }
+        consume_approval(
+            &env,
+            &executor,
+            proposal_id,
+            &ProposalAction::ResolveHandleDispute,
+        )?;
+        let dispute = attestation::get_dispute(&env, &github_handle)
+            .ok_or(ContributorError::HandleDisputeNotFound)?;
+
+        let winner = if award_to_claimant {
+            let mut claimant = Self::get_contributor(env.clone(), dispute.claimant.clone())?;
+            if let Ok(mut holder) = Self::get_contributor(env.clone(), dispute.holder.clone()) {
+                if holder.github_handle == github_handle {
+                    Self::release_handle(&env, &mut holder);
+                }
+            }
+            Self::assign_verified_handle(&env, &mut claimant, &github_handle);
+            dispute.claimant
+        } else {
+            dispute.holder
+        };
+        attestation::remove_dispute(&env, &github_handle);
 
-        BadgeGrantedEvent {
-            contributor: contributor_address,
-            badge,
+        HandleDisputeResolvedEvent {
+            github_handle,
+            winner,
             executor,
         }
         .publish(&env);
@@ -474,982 +623,3533 @@ impl ContributorRegistryContract {
         Ok(())
     }
 
-    pub fn revoke_badge(
-        env: Env,
-        executor: Address,
-        proposal_id: u64,
-        contributor_address: Address,
-        badge: Badge,
-    ) -> Result<(), ContributorError> {
-        consume_approval(&env, &executor, proposal_id, &ProposalAction::RevokeBadge)?;
-
-        // Ensure contributor exists
-        let _ = Self::get_contributor(env.clone(), contributor_address.clone())?;
+    /// Deregister a contributor, removing all associated storage entries.
+    ///
+    /// Requires the contributor's own authorization. Removes:
+    /// - `DataKey::Contributor(address)`
+    /// - `DataKey::GitHubIndex(github_handle)` and any open dispute over it
+    /// - `DataKey::RegistrationNonce(address)`
+    /// - `DataKey::HandleVerified(address)`
+    /// - badge grants (legacy and current), burning their certificate tokens
+    /// - `DataKey::ReputationPenalty(address)`, reputation history and
+    ///   notification epoch usage
+    ///
+    /// The full penalty log (`PenaltyCount` / `PenaltyAt`) is kept, and
+    /// neither the address nor its handle may be registered again until the
+    /// re-registration cooldown has elapsed, so deregistering cannot be used
+    /// to wash penalties.
+    pub fn deregister_contributor(env: Env, address: Address) -> Result<(), ContributorError> {
+        Self::ensure_initialized(&env)?;
+        address.require_auth();
 
-        let key = DataKey::Badges(contributor_address.clone());
-        let mut badges: Vec<Badge> = env
+        let contributor: ContributorData = env
             .storage()
             .persistent()
-            .get(&key)
-            .unwrap_or(Vec::new(&env));
+            .get(&DataKey::Contributor(address.clone()))
+            .ok_or(ContributorError::ContributorNotFound)?;
 
-        if let Some(index) = badges.first_index_of(badge) {
-            badges.remove(index);
-            env.storage().persistent().set(&key, &badges);
-            if !badges.is_empty() {
-                env.storage()
-                    .persistent()
-                    .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+        if let Some(dispute) = attestation::get_dispute(&env, &contributor.github_handle) {
+            if dispute.holder == address {
+                attestation::remove_dispute(&env, &contributor.github_handle);
             }
         }
+        env.storage()
+            .persistent()
+            .remove(&DataKey::GitHubIndex(contributor.github_handle.clone()));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Contributor(address.clone()));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::RegistrationNonce(address.clone()));
 
-        BadgeRevokedEvent {
-            contributor: contributor_address,
-            badge,
-            executor,
+        // Drop legacy badges outright rather than migrating them only to burn.
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Badges(address.clone()));
+        let grants = badges::get_grants(&env, &address);
+        for grant in grants.iter() {
+            badges::burn_token(&env, grant.token_id);
+        }
+        badges::save_grants(&env, &address, &Vec::new(&env));
+
+        for key in [
+            DataKey::ReputationPenalty(address.clone()),
+            DataKey::ReputationHistory(address.clone()),
+            DataKey::NotificationEpoch(address.clone()),
+            DataKey::HandleVerified(address.clone()),
+        ] {
+            env.storage().persistent().remove(&key);
+        }
+
+        delegation::clear_all(&env, &address);
+        accounts::mark_deregistered(&env, &address);
+        if !contributor.github_handle.is_empty() {
+            accounts::mark_handle_deregistered(&env, &contributor.github_handle);
+        }
+
+        ContributorDeregisteredEvent {
+            contributor: address,
+            github_handle: contributor.github_handle,
+            badges_burned: grants.len(),
         }
         .publish(&env);
 
         Ok(())
     }
 
-    /// Apply a reputation penalty triggered by a resolved dispute.
+    /// Move a registration to a new wallet.  Both addresses must authorize.
     ///
-    /// Requires multisig approval for `ProposalAction::ApplyPenalty`.
-    /// Deducts `points` from the contributor's reputation (floored at 0),
-    /// stores a `PenaltyRecord` for auditability, and emits
-    /// `ReputationPenaltyAppliedEvent`.
-    #[allow(clippy::too_many_arguments)]
-    pub fn apply_reputation_penalty(
+    /// The handle, reputation, badges (and their certificate tokens),
+    /// reputation and penalty history and outgoing delegations follow the
+    /// account; `old` is left
+    /// unregistered.  `new` must not be registered or cooling off from a
+    /// deregistration.
+    pub fn migrate_contributor(
         env: Env,
-        executor: Address,
-        proposal_id: u64,
-        contributor_address: Address,
-        dispute_id: u64,
-        severity: PenaltySeverity,
-        points: u64,
-        reason: String,
+        old: Address,
+        new: Address,
     ) -> Result<(), ContributorError> {
-        consume_approval(&env, &executor, proposal_id, &ProposalAction::ApplyPenalty)?;
+        Self::ensure_initialized(&env)?;
+        old.require_auth();
+        new.require_auth();
 
         let mut contributor: ContributorData = env
             .storage()
             .persistent()
-            .get(&DataKey::Contributor(contributor_address.clone()))
+            .get(&DataKey::Contributor(old.clone()))
             .ok_or(ContributorError::ContributorNotFound)?;
-        env.storage().persistent().extend_ttl(
-            &DataKey::Contributor(contributor_address.clone()),
-            LEDGER_THRESHOLD,
-            LEDGER_BUMP,
-        );
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::Contributor(new.clone()))
+        {
+            return Err(ContributorError::ContributorAlreadyExists);
+        }
+        accounts::ensure_can_register(&env, &new)?;
 
-        contributor.reputation_score = contributor.reputation_score.saturating_sub(points);
-        env.storage().persistent().set(
-            &DataKey::Contributor(contributor_address.clone()),
-            &contributor,
-        );
-        env.storage().persistent().extend_ttl(
-            &DataKey::Contributor(contributor_address.clone()),
-            LEDGER_THRESHOLD,
-            LEDGER_BUMP,
-        );
-
-        let record = PenaltyRecord {
-            dispute_id,
-            severity,
-            points_deducted: points,
-            reason: reason.clone(),
-            applied_at: env.ledger().timestamp(),
-        };
-        let penalty_key = DataKey::ReputationPenalty(contributor_address.clone());
-        env.storage().persistent().set(&penalty_key, &record);
+        contributor.address = new.clone();
+        Self::put_contributor(&env, &contributor);
         env.storage()
             .persistent()
-            .extend_ttl(&penalty_key, LEDGER_THRESHOLD, LEDGER_BUMP);
+            .remove(&DataKey::Contributor(old.clone()));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::RegistrationNonce(old.clone()));
 
-        ReputationPenaltyAppliedEvent {
-            contributor: contributor_address,
-            dispute_id,
-            severity,
-            points_deducted: points,
-            reason,
-            executor,
+        let handle = contributor.github_handle.clone();
+        if !handle.is_empty() {
+            let key = DataKey::GitHubIndex(handle.clone());
+            env.storage().persistent().set(&key, &new);
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+            if let Some(mut dispute) = attestation::get_dispute(&env, &handle) {
+                if dispute.holder == old {
+                    dispute.holder = new.clone();
+                    attestation::set_dispute(&env, &dispute);
+                }
+            }
+        }
+
+        let grants = badges::get_grants(&env, &old);
+        for grant in grants.iter() {
+            badges::set_token_owner(&env, grant.token_id, &new, grant.badge_id);
+        }
+        badges::save_grants(&env, &new, &grants);
+        badges::save_grants(&env, &old, &Vec::new(&env));
+
+        accounts::move_entry::<Vec<ReputationChange>>(
+            &env,
+            &DataKey::ReputationHistory(old.clone()),
+            &DataKey::ReputationHistory(new.clone()),
+        );
+        accounts::move_entry::<PenaltyRecord>(
+            &env,
+            &DataKey::ReputationPenalty(old.clone()),
+            &DataKey::ReputationPenalty(new.clone()),
+        );
+        accounts::move_entry::<EpochUsage>(
+            &env,
+            &DataKey::NotificationEpoch(old.clone()),
+            &DataKey::NotificationEpoch(new.clone()),
+        );
+        accounts::move_entry::<bool>(
+            &env,
+            &DataKey::HandleVerified(old.clone()),
+            &DataKey::HandleVerified(new.clone()),
+        );
+
+        // Append the penalty log after any history `new` kept from an earlier
+        // registration.
+        let old_count_key = DataKey::PenaltyCount(old.clone());
+        let old_count: u32 = env.storage().persistent().get(&old_count_key).unwrap_or(0);
+        if old_count > 0 {
+            let new_count_key = DataKey::PenaltyCount(new.clone());
+            let new_count: u32 = env.storage().persistent().get(&new_count_key).unwrap_or(0);
+            for index in 0..old_count {
+                accounts::move_entry::<PenaltyRecord>(
+                    &env,
+                    &DataKey::PenaltyAt(old.clone(), index),
+                    &DataKey::PenaltyAt(new.clone(), new_count + index),
+                );
+            }
+            env.storage()
+                .persistent()
+                .set(&new_count_key, &(new_count + old_count));
+            env.storage()
+                .persistent()
+                .extend_ttl(&new_count_key, LEDGER_THRESHOLD, LEDGER_BUMP);
+            env.storage().persistent().remove(&old_count_key);
+        }
+
+        // Outgoing delegations follow the account; delegations made *to* `old`
+        // stay with it until their delegators re-delegate.
+        for (scope, delegatee) in delegation::clear_all(&env, &old).iter() {
+            let delegatee = if delegatee == old {
+                new.clone()
+            } else {
+                delegatee
+            };
+            delegation::set(
+                &env,
+                &new,
+                &scope,
+                Some(delegatee),
+                contributor.reputation_score,
+            )?;
+        }
+
+        ContributorMigratedEvent {
+            from: old,
+            to: new,
+            github_handle: handle,
         }
         .publish(&env);
 
         Ok(())
     }
 
-    pub fn upgrade(
+    // ── Delegation ───────────────────────────────────────────
+
+    /// Delegate the caller's reputation-based voting power in `scope` to
+    /// another registered contributor.  Replaces any earlier delegation in
+    /// the same scope.  A `Contract` / `Topic` delegation overrides the
+    /// `Global` one there; delegating such a scope to yourself keeps your
+    /// own vote in it.
+    ///
+    /// Delegation is not transitive: a delegatee's own delegation moves only
+    /// their own reputation, not what was delegated to them.
+    pub fn delegate(
         env: Env,
-        executor: Address,
-        proposal_id: u64,
-        new_wasm_hash: BytesN<32>,
+        delegator: Address,
+        delegatee: Address,
+        scope: DelegationScope,
     ) -> Result<(), ContributorError> {
-        consume_approval(&env, &executor, proposal_id, &ProposalAction::Upgrade)?;
+        Self::ensure_initialized(&env)?;
+        delegator.require_auth();
+        if delegatee == delegator && scope == DelegationScope::Global {
+            return Err(ContributorError::InvalidDelegation);
+        }
+        let data = Self::sync_decay(&env, &delegator)?;
+        Self::get_contributor(env.clone(), delegatee.clone())?;
 
-        env.deployer()
-            .update_current_contract_wasm(new_wasm_hash.clone());
+        delegation::set(
+            &env,
+            &delegator,
+            &scope,
+            Some(delegatee.clone()),
+            data.reputation_score,
+        )?;
 
-        UpgradedEvent {
-            admin: executor,
-            new_wasm_hash,
+        DelegationChangedEvent {
+            delegator,
+            scope,
+            delegatee: Some(delegatee),
+            amount: data.reputation_score,
         }
         .publish(&env);
 
         Ok(())
     }
 
-    pub fn set_admin(
+    /// Materialise `contributor`'s pending inactivity decay so the reputation
+    /// they delegate decays too.  Anyone may call this (e.g. a keeper before
+    /// a vote snapshot).  Returns the current score.
+    pub fn sync_reputation(env: Env, contributor: Address) -> Result<u64, ContributorError> {
+        Self::ensure_initialized(&env)?;
+        Ok(Self::sync_decay(&env, &contributor)?.reputation_score)
+    }
+
+    /// Withdraw the caller's delegation in `scope`.
+    pub fn undelegate(
         env: Env,
-        executor: Address,
-        proposal_id: u64,
-        new_admin: Address,
+        delegator: Address,
+        scope: DelegationScope,
     ) -> Result<(), ContributorError> {
-        consume_approval(&env, &executor, proposal_id, &ProposalAction::SetAdmin)?;
+        Self::ensure_initialized(&env)?;
+        delegator.require_auth();
+        if delegation::get(&env, &delegator, &scope).is_none() {
+            return Err(ContributorError::DelegationNotFound);
+        }
 
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
-        env.storage()
-            .instance()
-            .extend_ttl(LEDGER_THRESHOLD, LEDGER_BUMP);
+        delegation::set(&env, &delegator, &scope, None, 0)?;
 
-        AdminChangedEvent {
-            old_admin: executor,
-            new_admin,
+        DelegationChangedEvent {
+            delegator,
+            scope,
+            delegatee: None,
+            amount: 0,
         }
         .publish(&env);
 
         Ok(())
     }
 
-    // ── Queries ──────────────────────────────────────────────
+    // ── Sensitive functions — multisig-gated ─────────────────
 
-    pub fn get_reputation(env: Env, contributor: Address) -> Result<u64, ContributorError> {
-        Ok(Self::get_contributor(env, contributor)?.reputation_score)
-    }
+    pub fn update_reputation(
+        env: Env,
+        executor: Address,
+        proposal_id: u64,
+        contributor_address: Address,
+        delta: i64,
+    ) -> Result<(), ContributorError> {
+        consume_approval(
+            &env,
+            &executor,
+            proposal_id,
+            &ProposalAction::UpdateReputation,
+        )?;
 
-    pub fn get_tier(env: Env, contributor: Address) -> Result<ContributorTier, ContributorError> {
-        let rep = Self::get_reputation(env, contributor)?;
-        Ok(match rep {
-            0..=9 => ContributorTier::Novice,
-            10..=49 => ContributorTier::Builder,
-            50..=99 => ContributorTier::Architect,
-            _ => ContributorTier::Core,
-        })
-    }
-
-    pub fn get_badges(env: Env, contributor: Address) -> Vec<Badge> {
-        let key = DataKey::Badges(contributor);
-        let badges: Vec<Badge> = env
-            .storage()
-            .persistent()
-            .get(&key)
-            .unwrap_or(Vec::new(&env));
-        if env.storage().persistent().has(&key) {
-            env.storage()
-                .persistent()
-                .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
-        }
-        badges
+        Self::change_reputation(
+            &env,
+            &contributor_address,
+            ReputationSource::Governance,
+            delta,
+            String::from_str(&env, "governance"),
+        )?;
+        Ok(())
     }
 
-    /// Returns the most recent penalty record for a contributor, if any.
-    pub fn get_penalty_record(env: Env, contributor: Address) -> Option<PenaltyRecord> {
-        let key = DataKey::ReputationPenalty(contributor);
-        let record: Option<PenaltyRecord> = env.storage().persistent().get(&key);
-        if record.is_some() {
-            env.storage()
-                .persistent()
-                .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+    /// Define a new badge and return its id.
+    ///
+    /// Requires multisig approval for `ProposalAction::DefineBadge`.
+    pub fn define_badge(
+        env: Env,
+        executor: Address,
+        proposal_id: u64,
+        definition: BadgeDefinition,
+    ) -> Result<u32, ContributorError> {
+        consume_approval(&env, &executor, proposal_id, &ProposalAction::DefineBadge)?;
+        badges::validate_definition(&definition)?;
+
+        let badge_id = badges::next_badge_id(&env);
+        badges::set_definition(&env, badge_id, &definition);
+
+        BadgeDefinedEvent {
+            badge_id,
+            name: definition.name,
+            executor,
         }
-        record
-    }
+        .publish(&env);
 
-    pub fn get_contributor(
-        env: Env,
-        address: Address,
-    ) -> Result<ContributorData, ContributorError> {
-        let key = DataKey::Contributor(address);
-        let data = env
-            .storage()
-            .persistent()
-            .get(&key)
-            .ok_or(ContributorError::ContributorNotFound)?;
-        env.storage()
-            .persistent()
-            .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
-        Ok(data)
+        Ok(badge_id)
     }
 
-    pub fn get_contributor_by_github(
+    /// Replace an existing badge definition (including the legacy ids).
+    /// Existing grants keep their original expiry.
+    ///
+    /// Requires multisig approval for `ProposalAction::DefineBadge`.
+    pub fn update_badge(
         env: Env,
-        github_handle: String,
-    ) -> Result<ContributorData, ContributorError> {
-        let index_key = DataKey::GitHubIndex(github_handle);
-        let address: Address = env
-            .storage()
-            .persistent()
-            .get(&index_key)
-            .ok_or(ContributorError::ContributorNotFound)?;
-        env.storage()
-            .persistent()
-            .extend_ttl(&index_key, LEDGER_THRESHOLD, LEDGER_BUMP);
-        Self::get_contributor(env, address)
-    }
+        executor: Address,
+        proposal_id: u64,
+        badge_id: u32,
+        definition: BadgeDefinition,
+    ) -> Result<(), ContributorError> {
+        consume_approval(&env, &executor, proposal_id, &ProposalAction::DefineBadge)?;
+        badges::validate_definition(&definition)?;
+        badges::get_definition(&env, badge_id).ok_or(ContributorError::BadgeNotFound)?;
 
-    pub fn get_multisig_config(env: Env) -> Result<MultisigConfig, ContributorError> {
-        env.storage()
-            .instance()
-            .extend_ttl(LEDGER_THRESHOLD, LEDGER_BUMP);
-        get_config(&env)
-    }
+        badges::set_definition(&env, badge_id, &definition);
 
-    pub fn get_registration_nonce(env: Env, address: Address) -> u64 {
-        Self::registration_nonce_of(&env, &address)
+        BadgeDefinedEvent {
+            badge_id,
+            name: definition.name,
+            executor,
+        }
+        .publish(&env);
+
+        Ok(())
     }
 
-    pub fn get_proposal(
+    /// Grant a badge, awarding its reputation bonus.  Granting a badge the
+    /// contributor already holds (unexpired) is a no-op; an expired grant is
+    /// renewed with the bonus it already awarded, since expiry does not take
+    /// it back.
+    pub fn grant_badge(
         env: Env,
+        executor: Address,
         proposal_id: u64,
-    ) -> Result<multisig::Proposal, ContributorError> {
-        get_proposal(&env, proposal_id)
-    }
+        contributor_address: Address,
+        badge_id: u32,
+    ) -> Result<(), ContributorError> {
+        consume_approval(&env, &executor, proposal_id, &ProposalAction::GrantBadge)?;
 
-    pub fn get_next_proposal_id(env: Env) -> u64 {
-        env.storage()
-            .instance()
-            .extend_ttl(LEDGER_THRESHOLD, LEDGER_BUMP);
-        env.storage()
-            .instance()
-            .get(&DataKey::NextProposalId)
-            .unwrap_or(0)
-    }
-}
+        // Ensure contributor exists
+        let _ = Self::get_contributor(env.clone(), contributor_address.clone())?;
 
-// ── Notification receiver ─────────────────────────────────────
+        let definition =
+            badges::get_definition(&env, badge_id).ok_or(ContributorError::BadgeNotFound)?;
+        if !definition.active {
+            return Err(ContributorError::BadgeInactive);
+        }
 
-#[contractimpl]
-impl NotificationReceiverTrait for ContributorRegistryContract {
-    fn on_notify(env: Env, notification: Notification) {
-        if notification.event_type == Symbol::new(&env, "deposit") {
-            let (user, _project_id, _amount): (Address, u64, i128) =
-                <(Address, u64, i128)>::from_xdr(&env, &notification.data).unwrap();
-
-            let key = DataKey::Contributor(user.clone());
-            if let Some(mut contributor) =
-                env.storage().persistent().get::<_, ContributorData>(&key)
-            {
-                env.storage()
-                    .persistent()
-                    .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
-                contributor.reputation_score = contributor.reputation_score.saturating_add(1);
-                env.storage().persistent().set(&key, &contributor);
-                env.storage()
-                    .persistent()
-                    .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+        let now = env.ledger().timestamp();
+        let mut grants = badges::get_grants(&env, &contributor_address);
+        let mut renewed_bonus = None;
+        if let Some(index) = grants.iter().position(|grant| grant.badge_id == badge_id) {
+            let existing = grants.get_unchecked(index as u32);
+            if badges::is_live(&existing, now) {
+                return Ok(());
             }
+            badges::burn_token(&env, existing.token_id);
+            grants.remove(index as u32);
+            renewed_bonus = Some(existing.bonus);
         }
-    }
-}
+        let bonus = renewed_bonus.unwrap_or(definition.reputation_bonus);
 
-// ── Tests ─────────────────────────────────────────────────────
+        let expires_at = if definition.validity_secs == 0 {
+            0
+        } else {
+            now.saturating_add(definition.validity_secs)
+        };
+        let token_id = badges::mint_token(&env, &contributor_address, badge_id);
+        grants.push_back(BadgeGrant {
+            badge_id,
+            token_id,
+            issuer: executor.clone(),
+            granted_at: now,
+            expires_at,
+            bonus,
+        });
+        badges::save_grants(&env, &contributor_address, &grants);
+
+        if bonus > 0 && renewed_bonus.is_none() {
+            Self::change_reputation(
+                &env,
+                &contributor_address,
+                ReputationSource::Badge,
+                bonus.min(i64::MAX as u64) as i64,
+                definition.name,
+            )?;
+        }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use soroban_sdk::{
-        testutils::{Address as TestAddress, Ledger}, // Ledger trait for set_timestamp
-        Address,
-        Bytes,
-        Env,
-        Vec,
-    };
+        BadgeGrantedEvent {
+            contributor: contributor_address,
+            badge_id,
+            token_id,
+            expires_at,
+            executor,
+        }
+        .publish(&env);
 
-    struct Setup {
-        env: Env,
-        contract: Address,
-        alice: Address,
-        bob: Address,
-        carol: Address,
+        Ok(())
     }
 
-    fn setup() -> Setup {
-        let env = Env::default();
-        env.mock_all_auths();
-        // env.register() replaces the deprecated env.register_contract()
-        let contract = env.register(ContributorRegistryContract, ());
-        let alice = Address::generate(&env);
-        let bob = Address::generate(&env);
-        let carol = Address::generate(&env);
+    /// Revoke a badge, taking back the reputation bonus its grant awarded.
+    /// The bonus stays with the grant until it is revoked, so this applies
+    /// to expired grants too.
+    pub fn revoke_badge(
+        env: Env,
+        executor: Address,
+        proposal_id: u64,
+        contributor_address: Address,
+        badge_id: u32,
+    ) -> Result<(), ContributorError> {
+        consume_approval(&env, &executor, proposal_id, &ProposalAction::RevokeBadge)?;
 
-        let mut signers = Vec::new(&env);
-        signers.push_back(Signer {
-            address: alice.clone(),
-            weight: 2,
-        });
-        signers.push_back(Signer {
-            address: bob.clone(),
-            weight: 1,
-        });
-        signers.push_back(Signer {
+        // Ensure contributor exists
+        let _ = Self::get_contributor(env.clone(), contributor_address.clone())?;
+
+        let mut grants = badges::get_grants(&env, &contributor_address);
+        let mut token_id = 0;
+        if let Some(index) = grants.iter().position(|grant| grant.badge_id == badge_id) {
+            let grant = grants.get_unchecked(index as u32);
+            token_id = grant.token_id;
+            grants.remove(index as u32);
+            badges::save_grants(&env, &contributor_address, &grants);
+            badges::burn_token(&env, token_id);
+
+            if grant.bonus > 0 {
+                let name = badges::get_definition(&env, badge_id)
+                    .map(|definition| definition.name)
+                    .unwrap_or(String::from_str(&env, ""));
+                Self::change_reputation(
+                    &env,
+                    &contributor_address,
+                    ReputationSource::Badge,
+                    -(grant.bonus.min(i64::MAX as u64) as i64),
+                    name,
+                )?;
+            }
+        }
+
+        BadgeRevokedEvent {
+            contributor: contributor_address,
+            badge_id,
+            token_id,
+            executor,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Move a transferable badge to another registered contributor.  The
+    /// grant (issuer, timestamps) moves unchanged.  Grants that awarded a
+    /// reputation bonus cannot move, since the bonus stays with whoever
+    /// received it.  A recipient's expired grant is replaced, and any bonus
+    /// it awarded carries over so it can still be revoked.
+    pub fn transfer_badge(
+        env: Env,
+        from: Address,
+        to: Address,
+        badge_id: u32,
+    ) -> Result<(), ContributorError> {
+        Self::ensure_initialized(&env)?;
+        from.require_auth();
+
+        let definition =
+            badges::get_definition(&env, badge_id).ok_or(ContributorError::BadgeNotFound)?;
+        if !definition.transferable {
+            return Err(ContributorError::BadgeNotTransferable);
+        }
+        let _ = Self::get_contributor(env.clone(), to.clone())?;
+
+        let now = env.ledger().timestamp();
+        let mut from_grants = badges::get_grants(&env, &from);
+        let index = from_grants
+            .iter()
+            .position(|grant| grant.badge_id == badge_id && badges::is_live(&grant, now))
+            .ok_or(ContributorError::BadgeNotFound)? as u32;
+        let mut grant = from_grants.get_unchecked(index);
+        if grant.bonus > 0 {
+            return Err(ContributorError::BadgeNotTransferable);
+        }
+
+        let mut to_grants = badges::get_grants(&env, &to);
+        if to_grants
+            .iter()
+            .any(|grant| grant.badge_id == badge_id && badges::is_live(&grant, now))
+        {
+            return Err(ContributorError::BadgeAlreadyHeld);
+        }
+        if let Some(stale) = to_grants
+            .iter()
+            .position(|grant| grant.badge_id == badge_id)
+        {
+            let stale_grant = to_grants.get_unchecked(stale as u32);
+            badges::burn_token(&env, stale_grant.token_id);
+            grant.bonus = stale_grant.bonus;
+            to_grants.remove(stale as u32);
+        }
+
+        let token_id = grant.token_id;
+        from_grants.remove(index);
+        to_grants.push_back(grant);
+        badges::save_grants(&env, &from, &from_grants);
+        badges::save_grants(&env, &to, &to_grants);
+        badges::set_token_owner(&env, token_id, &to, badge_id);
+
+        BadgeTransferredEvent {
+            badge_id,
+            token_id,
+            from,
+            to,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Convert a contributor's badges from the pre-registry enum storage.
+    /// Permissionless and idempotent; legacy entries are also migrated
+    /// lazily on first access.  Returns the number of grants migrated.
+    pub fn migrate_badges(env: Env, contributor: Address) -> u32 {
+        badges::migrate_legacy(&env, &contributor)
+    }
+
+    /// Apply a reputation penalty triggered by a resolved dispute.
+    ///
+    /// Requires multisig approval for `ProposalAction::ApplyPenalty`.
+    /// Deducts `points` from the contributor's reputation (floored at 0),
+    /// appends a `PenaltyRecord` to the contributor's penalty log for
+    /// auditability, and emits `ReputationPenaltyAppliedEvent`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn apply_reputation_penalty(
+        env: Env,
+        executor: Address,
+        proposal_id: u64,
+        contributor_address: Address,
+        dispute_id: u64,
+        severity: PenaltySeverity,
+        points: u64,
+        reason: String,
+    ) -> Result<(), ContributorError> {
+        consume_approval(&env, &executor, proposal_id, &ProposalAction::ApplyPenalty)?;
+
+        let delta = -(points.min(i64::MAX as u64) as i64);
+        Self::change_reputation(
+            &env,
+            &contributor_address,
+            ReputationSource::Penalty,
+            delta,
+            reason.clone(),
+        )?;
+
+        let record = PenaltyRecord {
+            dispute_id,
+            severity,
+            points_deducted: points,
+            reason: reason.clone(),
+            applied_at: env.ledger().timestamp(),
+        };
+        let penalty_key = DataKey::ReputationPenalty(contributor_address.clone());
+        env.storage().persistent().set(&penalty_key, &record);
+        env.storage()
+            .persistent()
+            .extend_ttl(&penalty_key, LEDGER_THRESHOLD, LEDGER_BUMP);
+
+        let count_key = DataKey::PenaltyCount(contributor_address.clone());
+        let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+        let record_key = DataKey::PenaltyAt(contributor_address.clone(), count);
+        env.storage().persistent().set(&record_key, &record);
+        env.storage()
+            .persistent()
+            .extend_ttl(&record_key, LEDGER_THRESHOLD, LEDGER_BUMP);
+        env.storage().persistent().set(&count_key, &(count + 1));
+        env.storage()
+            .persistent()
+            .extend_ttl(&count_key, LEDGER_THRESHOLD, LEDGER_BUMP);
+
+        ReputationPenaltyAppliedEvent {
+            contributor: contributor_address,
+            dispute_id,
+            severity,
+            points_deducted: points,
+            reason,
+            executor,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Configure (or, with `None`, disable) inactivity decay from the current
+    /// ledger on.  Past scores keep decaying under the setting of their time.
+    ///
+    /// Requires multisig approval for `ProposalAction::SetDecayConfig`.
+    pub fn set_decay_config(
+        env: Env,
+        executor: Address,
+        proposal_id: u64,
+        config: Option<DecayConfig>,
+    ) -> Result<(), ContributorError> {
+        consume_approval(
+            &env,
+            &executor,
+            proposal_id,
+            &ProposalAction::SetDecayConfig,
+        )?;
+
+        if let Some(ref config) = config {
+            reputation::validate_decay_config(config)?;
+        }
+        reputation::set_decay_config(&env, &config);
+        env.storage()
+            .instance()
+            .extend_ttl(LEDGER_THRESHOLD, LEDGER_BUMP);
+
+        DecayConfiguredEvent { executor, config }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Replace the reputation cutoffs used by `get_tier`.
+    ///
+    /// Requires multisig approval for `ProposalAction::SetTierThresholds`.
+    /// Cutoffs must be non-zero and strictly increasing.
+    pub fn set_tier_thresholds(
+        env: Env,
+        executor: Address,
+        proposal_id: u64,
+        thresholds: TierThresholds,
+    ) -> Result<(), ContributorError> {
+        consume_approval(
+            &env,
+            &executor,
+            proposal_id,
+            &ProposalAction::SetTierThresholds,
+        )?;
+
+        permissions::validate_thresholds(&thresholds)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::TierThresholds, &thresholds);
+        env.storage()
+            .instance()
+            .extend_ttl(LEDGER_THRESHOLD, LEDGER_BUMP);
+
+        TierThresholdsUpdatedEvent {
+            executor,
+            thresholds,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Map a permission to a minimum tier and/or qualifying badges, or remove
+    /// it with `None` (after which nobody holds it).
+    ///
+    /// Requires multisig approval for `ProposalAction::SetPermission`.
+    pub fn set_permission(
+        env: Env,
+        executor: Address,
+        proposal_id: u64,
+        permission: Symbol,
+        rule: Option<PermissionRule>,
+    ) -> Result<(), ContributorError> {
+        consume_approval(&env, &executor, proposal_id, &ProposalAction::SetPermission)?;
+
+        let key = DataKey::Permission(permission.clone());
+        match rule {
+            Some(ref rule) => {
+                env.storage().persistent().set(&key, rule);
+                env.storage()
+                    .persistent()
+                    .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+            }
+            None => env.storage().persistent().remove(&key),
+        }
+
+        PermissionRuleSetEvent {
+            permission,
+            rule,
+            executor,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Set how long (in seconds) a deregistered address must wait before it
+    /// can register again.  0 disables the cooldown.
+    ///
+    /// Requires multisig approval for `ProposalAction::SetReregistrationCooldown`.
+    pub fn set_reregistration_cooldown(
+        env: Env,
+        executor: Address,
+        proposal_id: u64,
+        cooldown_secs: u64,
+    ) -> Result<(), ContributorError> {
+        consume_approval(
+            &env,
+            &executor,
+            proposal_id,
+            &ProposalAction::SetReregistrationCooldown,
+        )?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::ReregistrationCooldown, &cooldown_secs);
+        env.storage()
+            .instance()
+            .extend_ttl(LEDGER_THRESHOLD, LEDGER_BUMP);
+
+        RegistrationCooldownSetEvent {
+            executor,
+            cooldown_secs,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Set (or with `None`, clear) the ed25519 key of the GitHub OAuth
+    /// verifier.
+    ///
+    /// Requires multisig approval for `ProposalAction::SetVerifier`.
+    pub fn set_verifier_key(
+        env: Env,
+        executor: Address,
+        proposal_id: u64,
+        key: Option<BytesN<32>>,
+    ) -> Result<(), ContributorError> {
+        consume_approval(&env, &executor, proposal_id, &ProposalAction::SetVerifier)?;
+
+        match key {
+            Some(ref key) => env.storage().instance().set(&DataKey::VerifierKey, key),
+            None => env.storage().instance().remove(&DataKey::VerifierKey),
+        }
+
+        VerifierKeySetEvent { executor, key }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Allow or disallow a contract to deliver reputation notifications.
+    ///
+    /// Requires multisig approval for `ProposalAction::ConfigureNotifications`.
+    pub fn set_trusted_source(
+        env: Env,
+        executor: Address,
+        proposal_id: u64,
+        source: Address,
+        trusted: bool,
+    ) -> Result<(), ContributorError> {
+        consume_approval(
+            &env,
+            &executor,
+            proposal_id,
+            &ProposalAction::ConfigureNotifications,
+        )?;
+
+        let key = DataKey::TrustedSource(source.clone());
+        if trusted {
+            env.storage().persistent().set(&key, &true);
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+
+        TrustedSourceSetEvent {
+            source,
+            trusted,
+            executor,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Set (or with `None`, clear) the scoring rule for a notification event
+    /// type.  Event types without a rule are ignored.
+    ///
+    /// Requires multisig approval for `ProposalAction::ConfigureNotifications`.
+    pub fn set_notification_rule(
+        env: Env,
+        executor: Address,
+        proposal_id: u64,
+        event_type: Symbol,
+        rule: Option<NotificationRule>,
+    ) -> Result<(), ContributorError> {
+        consume_approval(
+            &env,
+            &executor,
+            proposal_id,
+            &ProposalAction::ConfigureNotifications,
+        )?;
+
+        let key = DataKey::NotificationRule(event_type.clone());
+        match rule {
+            Some(ref rule) => {
+                notifications::validate_rule(rule)?;
+                env.storage().persistent().set(&key, rule);
+                env.storage()
+                    .persistent()
+                    .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+            }
+            None => env.storage().persistent().remove(&key),
+        }
+
+        NotificationRuleSetEvent {
+            event_type,
+            rule,
+            executor,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Configure the pricing adapter and per-epoch cap used for notification
+    /// scoring.
+    ///
+    /// Requires multisig approval for `ProposalAction::ConfigureNotifications`.
+    pub fn set_notification_config(
+        env: Env,
+        executor: Address,
+        proposal_id: u64,
+        config: NotificationConfig,
+    ) -> Result<(), ContributorError> {
+        consume_approval(
+            &env,
+            &executor,
+            proposal_id,
+            &ProposalAction::ConfigureNotifications,
+        )?;
+        notifications::validate_config(&config)?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::NotificationConfig, &config);
+
+        NotificationConfigSetEvent { executor, config }.publish(&env);
+
+        Ok(())
+    }
+
+    pub fn upgrade(
+        env: Env,
+        executor: Address,
+        proposal_id: u64,
+        new_wasm_hash: BytesN<32>,
+    ) -> Result<(), ContributorError> {
+        consume_approval(&env, &executor, proposal_id, &ProposalAction::Upgrade)?;
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        UpgradedEvent {
+            admin: executor,
+            new_wasm_hash,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    pub fn set_admin(
+        env: Env,
+        executor: Address,
+        proposal_id: u64,
+        new_admin: Address,
+    ) -> Result<(), ContributorError> {
+        consume_approval(&env, &executor, proposal_id, &ProposalAction::SetAdmin)?;
+
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        env.storage()
+            .instance()
+            .extend_ttl(LEDGER_THRESHOLD, LEDGER_BUMP);
+
+        AdminChangedEvent {
+            old_admin: executor,
+            new_admin,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    // ── Queries ──────────────────────────────────────────────
+
+    /// Current reputation, with any pending inactivity decay applied.
+    pub fn get_reputation(env: Env, contributor: Address) -> Result<u64, ContributorError> {
+        let data = Self::get_contributor(env.clone(), contributor)?;
+        Ok(reputation::current_score(&env, &data))
+    }
+
+    /// Reputation as it stood at `timestamp`, reconstructed from the retained
+    /// history.  Voting contracts use this to snapshot voting power.
+    pub fn get_reputation_at(
+        env: Env,
+        contributor: Address,
+        timestamp: u64,
+    ) -> Result<u64, ContributorError> {
+        let data = Self::get_contributor(env.clone(), contributor)?;
+        Ok(reputation::score_at(&env, &data, timestamp))
+    }
+
+    /// Global voting power at `timestamp`: the contributor's own reputation
+    /// (unless delegated away) plus the checkpointed reputation delegated to
+    /// them.  Voting contracts should pass the proposal's start time.
+    pub fn get_voting_power(
+        env: Env,
+        address: Address,
+        timestamp: u64,
+    ) -> Result<u64, ContributorError> {
+        Self::get_voting_power_in(env, address, DelegationScope::Global, timestamp)
+    }
+
+    /// Voting power for votes in `scope` at `timestamp`, honouring scoped
+    /// delegations and overrides.  Delegations changed at `timestamp` itself
+    /// only count from the next ledger.  Fails with `SnapshotTooOld` if the
+    /// delegation checkpoints covering `timestamp` have been pruned.
+    ///
+    /// Delegated reputation reflects inactivity decay up to each
+    /// delegator's last recorded change or `sync_reputation` call.
+    pub fn get_voting_power_in(
+        env: Env,
+        address: Address,
+        scope: DelegationScope,
+        timestamp: u64,
+    ) -> Result<u64, ContributorError> {
+        let data = Self::get_contributor(env.clone(), address.clone())?;
+        let own = match delegation::effective_delegate_at(&env, &address, &scope, timestamp)? {
+            Some(_) => 0,
+            None => reputation::score_at(&env, &data, timestamp),
+        };
+        Ok(own.saturating_add(delegation::delegated_power_at(
+            &env, &address, &scope, timestamp,
+        )?))
+    }
+
+    /// Reputation delegated to `address` for votes in `scope` at `timestamp`.
+    /// Fails with `SnapshotTooOld` if `timestamp` predates the retained
+    /// checkpoints (`MAX_DELEGATION_CHECKPOINTS`).
+    pub fn get_delegated_power(
+        env: Env,
+        address: Address,
+        scope: DelegationScope,
+        timestamp: u64,
+    ) -> Result<u64, ContributorError> {
+        delegation::delegated_power_at(&env, &address, &scope, timestamp)
+    }
+
+    /// The caller's current delegatee in exactly `scope`, if any.
+    pub fn get_delegate(env: Env, delegator: Address, scope: DelegationScope) -> Option<Address> {
+        delegation::get(&env, &delegator, &scope).map(|d| d.delegatee)
+    }
+
+    /// The most recent reputation changes, oldest first (bounded by
+    /// `MAX_REPUTATION_HISTORY`).
+    pub fn get_reputation_history(env: Env, contributor: Address) -> Vec<ReputationChange> {
+        reputation::get_history(&env, &contributor)
+    }
+
+    pub fn get_decay_config(env: Env) -> Option<DecayConfig> {
+        reputation::get_decay_config(&env)
+    }
+
+    pub fn get_tier(env: Env, contributor: Address) -> Result<ContributorTier, ContributorError> {
+        let rep = Self::get_reputation(env.clone(), contributor)?;
+        Ok(permissions::tier_for(
+            &permissions::get_thresholds(&env),
+            rep,
+        ))
+    }
+
+    pub fn get_tier_thresholds(env: Env) -> TierThresholds {
+        permissions::get_thresholds(&env)
+    }
+
+    pub fn get_permission_rule(env: Env, permission: Symbol) -> Option<PermissionRule> {
+        permissions::get_rule(&env, &permission)
+    }
+
+    /// Whether `contributor` currently holds `permission`.
+    ///
+    /// Unregistered addresses and permissions without a configured rule
+    /// always return `false`.  Other contracts call this to gate actions
+    /// (see the `PERMISSION_*` names in `permission_interface`).
+    pub fn has_permission(env: Env, contributor: Address, permission: Symbol) -> bool {
+        let rule = match permissions::get_rule(&env, &permission) {
+            Some(rule) => rule,
+            None => return false,
+        };
+        let tier = match Self::get_tier(env.clone(), contributor.clone()) {
+            Ok(tier) => tier,
+            Err(_) => return false,
+        };
+        let badges = badges::live_badge_ids(&env, &contributor);
+        permissions::satisfies(&rule, tier, &badges)
+    }
+
+    /// The contributor's unexpired badges, joined with their definitions.
+    pub fn get_badges(env: Env, contributor: Address) -> Vec<BadgeRecord> {
+        let now = env.ledger().timestamp();
+        let mut records = Vec::new(&env);
+        for grant in badges::get_grants(&env, &contributor).iter() {
+            if !badges::is_live(&grant, now) {
+                continue;
+            }
+            if let Some(definition) = badges::get_definition(&env, grant.badge_id) {
+                records.push_back(BadgeRecord {
+                    badge_id: grant.badge_id,
+                    token_id: grant.token_id,
+                    name: definition.name,
+                    metadata_uri: definition.metadata_uri,
+                    issuer: grant.issuer,
+                    granted_at: grant.granted_at,
+                    expires_at: grant.expires_at,
+                });
+            }
+        }
+        records
+    }
+
+    pub fn get_badge_definition(
+        env: Env,
+        badge_id: u32,
+    ) -> Result<BadgeDefinition, ContributorError> {
+        badges::get_definition(&env, badge_id).ok_or(ContributorError::BadgeNotFound)
+    }
+
+    // ── Badge certificates (SEP-0050 style) ──────────────────
+    //
+    // Certificates are non-transferable unless the badge definition allows
+    // it (see `transfer_badge`).
+
+    /// Collection name reported to wallets.
+    pub fn name(env: Env) -> String {
+        String::from_str(&env, badges::COLLECTION_NAME)
+    }
+
+    /// Collection symbol reported to wallets.
+    pub fn symbol(env: Env) -> String {
+        String::from_str(&env, badges::COLLECTION_SYMBOL)
+    }
+
+    /// Holder of a badge certificate.  Revoked and expired certificates
+    /// return `BadgeTokenNotFound`.
+    pub fn owner_of(env: Env, token_id: u32) -> Result<Address, ContributorError> {
+        badges::live_token(&env, token_id)
+            .map(|(owner, _)| owner)
+            .ok_or(ContributorError::BadgeTokenNotFound)
+    }
+
+    /// Metadata URI of a badge certificate, taken from its badge definition.
+    pub fn token_uri(env: Env, token_id: u32) -> Result<String, ContributorError> {
+        let (_, grant) =
+            badges::live_token(&env, token_id).ok_or(ContributorError::BadgeTokenNotFound)?;
+        badges::get_definition(&env, grant.badge_id)
+            .map(|definition| definition.metadata_uri)
+            .ok_or(ContributorError::BadgeNotFound)
+    }
+
+    /// Number of live badge certificates held by `owner`.
+    pub fn balance_of(env: Env, owner: Address) -> u32 {
+        badges::live_badge_ids(&env, &owner).len()
+    }
+
+    /// Whether `address`'s current handle is backed by a verifier
+    /// attestation.
+    pub fn is_handle_verified(env: Env, address: Address) -> bool {
+        attestation::is_verified(&env, &address)
+    }
+
+    pub fn get_verifier_key(env: Env) -> Option<BytesN<32>> {
+        attestation::get_verifier_key(&env)
+    }
+
+    /// The exact bytes the verifier must sign to attest `github_handle` for
+    /// `address`.
+    pub fn get_attestation_message(
+        env: Env,
+        address: Address,
+        github_handle: String,
+        expires_at: u64,
+    ) -> Bytes {
+        attestation::message(&env, &address, &github_handle, expires_at)
+    }
+
+    pub fn get_handle_dispute(env: Env, github_handle: String) -> Option<HandleDispute> {
+        attestation::get_dispute(&env, &github_handle)
+    }
+
+    pub fn is_trusted_source(env: Env, source: Address) -> bool {
+        notifications::is_trusted(&env, &source)
+    }
+
+    pub fn get_notification_rule(env: Env, event_type: Symbol) -> Option<NotificationRule> {
+        notifications::get_rule(&env, &event_type)
+    }
+
+    pub fn get_notification_config(env: Env) -> NotificationConfig {
+        notifications::get_config(&env)
+    }
+
+    /// Notification points the contributor has earned in their last active
+    /// epoch.
+    pub fn get_notification_usage(env: Env, contributor: Address) -> Option<EpochUsage> {
+        notifications::get_usage(&env, &contributor)
+    }
+
+    /// Returns the most recent penalty record for a contributor, if any.
+    /// After deregistration this falls back to the retained penalty log.
+    pub fn get_penalty_record(env: Env, contributor: Address) -> Option<PenaltyRecord> {
+        let key = DataKey::ReputationPenalty(contributor.clone());
+        let record: Option<PenaltyRecord> = env.storage().persistent().get(&key);
+        if record.is_some() {
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+            return record;
+        }
+        Self::get_penalty_history(env, contributor).last()
+    }
+
+    /// Seconds a deregistered address must wait before registering again.
+    pub fn get_reregistration_cooldown(env: Env) -> u64 {
+        accounts::get_cooldown(&env)
+    }
+
+    /// When `address` last deregistered, if it has not registered since.
+    pub fn get_deregistered_at(env: Env, address: Address) -> Option<u64> {
+        accounts::get_deregistered_at(&env, &address)
+    }
+
+    /// Every penalty ever applied to a contributor, oldest first.
+    pub fn get_penalty_history(env: Env, contributor: Address) -> Vec<PenaltyRecord> {
+        let count: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PenaltyCount(contributor.clone()))
+            .unwrap_or(0);
+        let mut records = Vec::new(&env);
+        for index in 0..count {
+            let key = DataKey::PenaltyAt(contributor.clone(), index);
+            if let Some(record) = env.storage().persistent().get::<_, PenaltyRecord>(&key) {
+                env.storage()
+                    .persistent()
+                    .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+                records.push_back(record);
+            }
+        }
+        records
+    }
+
+    pub fn get_contributor(
+        env: Env,
+        address: Address,
+    ) -> Result<ContributorData, ContributorError> {
+        let key = DataKey::Contributor(address);
+        let data = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(ContributorError::ContributorNotFound)?;
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+        Ok(data)
+    }
+
+    pub fn get_contributor_by_github(
+        env: Env,
+        github_handle: String,
+    ) -> Result<ContributorData, ContributorError> {
+        let index_key = DataKey::GitHubIndex(github_handle);
+        let address: Address = env
+            .storage()
+            .persistent()
+            .get(&index_key)
+            .ok_or(ContributorError::ContributorNotFound)?;
+        env.storage()
+            .persistent()
+            .extend_ttl(&index_key, LEDGER_THRESHOLD, LEDGER_BUMP);
+        Self::get_contributor(env, address)
+    }
+
+    pub fn get_multisig_config(env: Env) -> Result<MultisigConfig, ContributorError> {
+        env.storage()
+            .instance()
+            .extend_ttl(LEDGER_THRESHOLD, LEDGER_BUMP);
+        get_config(&env)
+    }
+
+    pub fn get_registration_nonce(env: Env, address: Address) -> u64 {
+        signed_intent::nonce_of(&env, &DataKey::RegistrationNonce(address))
+    }
+
+    pub fn get_proposal(
+        env: Env,
+        proposal_id: u64,
+    ) -> Result<multisig::Proposal, ContributorError> {
+        get_proposal(&env, proposal_id)
+    }
+
+    pub fn get_next_proposal_id(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .extend_ttl(LEDGER_THRESHOLD, LEDGER_BUMP);
+        env.storage()
+            .instance()
+            .get(&DataKey::NextProposalId)
+            .unwrap_or(0)
+    }
+}
+
+// ── Notification receiver ─────────────────────────────────────
+
+#[contractimpl]
+impl NotificationReceiverTrait for ContributorRegistryContract {
+    /// Score a notification from an allowlisted source contract.
+    ///
+    /// The source must authorize the call (implicit when it is the direct
+    /// invoker), so nobody can spoof a trusted contract.  Notifications from
+    /// untrusted sources, without a configured rule, with undecodable data or
+    /// for unregistered accounts are ignored rather than failing the
+    /// notifying contract's call.
+    fn on_notify(env: Env, notification: Notification) {
+        notification.source.require_auth();
+        if !notifications::is_trusted(&env, &notification.source) {
+            return;
+        }
+        let rule = match notifications::get_rule(&env, &notification.event_type) {
+            Some(rule) => rule,
+            None => return,
+        };
+        let activity = match notifications::decode(&env, &notification.data) {
+            Some(activity) => activity,
+            None => return,
+        };
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::Contributor(activity.account.clone()))
+        {
+            return;
+        }
+
+        let config = notifications::get_config(&env);
+        let normalized =
+            match notifications::normalize(&env, &config, &activity.token, activity.amount) {
+                Some(normalized) => normalized,
+                None => return,
+            };
+        let delta = notifications::apply_epoch_cap(
+            &env,
+            &config,
+            &activity.account,
+            notifications::score(&rule, normalized),
+        );
+        if delta == 0 {
+            return;
+        }
+
+        // An overflowing score must not make the notifying contract's call fail.
+        let _ = Self::change_reputation(
+            &env,
+            &activity.account,
+            ReputationSource::Notification,
+            delta,
+            notifications::reason(&env, &notification.event_type),
+        );
+    }
+}
+
+// ── Tests ─────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{
+        testutils::{Address as TestAddress, Ledger}, // Ledger trait for set_timestamp
+        Address,
+        Bytes,
+        Env,
+        Vec,
+    };
+
+    struct Setup {
+        env: Env,
+        contract: Address,
+        alice: Address,
+        bob: Address,
+        carol: Address,
+    }
+
+    fn setup() -> Setup {
+        let env = Env::default();
+        env.mock_all_auths();
+        // env.register() replaces the deprecated env.register_contract()
+        let contract = env.register(ContributorRegistryContract, ());
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let carol = Address::generate(&env);
+
+        let mut signers = Vec::new(&env);
+        signers.push_back(Signer {
+            address: alice.clone(),
+            weight: 2,
+        });
+        signers.push_back(Signer {
+            address: bob.clone(),
+            weight: 1,
+        });
+        signers.push_back(Signer {
             address: carol.clone(),
             weight: 1,
         });
 
-        let client = ContributorRegistryContractClient::new(&env, &contract);
-        client.initialize(&signers, &3u32);
+        let client = ContributorRegistryContractClient::new(&env, &contract);
+        client.initialize(&signers, &3u32);
+
+        Setup {
+            env,
+            contract,
+            alice,
+            bob,
+            carol,
+        }
+    }
+
+    // ── Initialisation ────────────────────────────────────────
+
+    #[test]
+    fn test_initialize_stores_config() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        // Client methods return values directly — no .unwrap() needed.
+        let config = client.get_multisig_config();
+        assert_eq!(config.threshold, 3);
+        assert_eq!(config.signers.len(), 3);
+    }
+
+    #[test]
+    fn test_double_initialize_fails() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let mut signers = Vec::new(&s.env);
+        signers.push_back(Signer {
+            address: s.alice.clone(),
+            weight: 1,
+        });
+        // try_* variants return Result and are used to assert failure.
+        assert!(client.try_initialize(&signers, &1u32).is_err());
+    }
+
+    #[test]
+    fn test_threshold_above_total_weight_fails() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract = env.register(ContributorRegistryContract, ());
+        let client = ContributorRegistryContractClient::new(&env, &contract);
+        let alice = Address::generate(&env);
+
+        let mut signers = Vec::new(&env);
+        signers.push_back(Signer {
+            address: alice,
+            weight: 1,
+        });
+        assert!(client.try_initialize(&signers, &99u32).is_err());
+    }
+
+    // ── Propose ───────────────────────────────────────────────
+
+    #[test]
+    fn test_propose_counts_proposer_weight() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let id = client.propose(&s.alice, &ProposalAction::Upgrade);
+        let proposal = client.get_proposal(&id);
+
+        assert_eq!(proposal.weight_collected, 2);
+        assert_eq!(proposal.status, ProposalStatus::Pending);
+    }
+
+    #[test]
+    fn test_propose_by_non_signer_fails() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let outsider = Address::generate(&s.env);
+        assert!(client
+            .try_propose(&outsider, &ProposalAction::Upgrade)
+            .is_err());
+    }
+
+    #[test]
+    fn test_single_signer_above_threshold_auto_approves() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract = env.register(ContributorRegistryContract, ());
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+
+        let mut signers = Vec::new(&env);
+        signers.push_back(Signer {
+            address: alice.clone(),
+            weight: 3,
+        });
+        signers.push_back(Signer {
+            address: bob.clone(),
+            weight: 1,
+        });
+
+        let client = ContributorRegistryContractClient::new(&env, &contract);
+        client.initialize(&signers, &3u32);
+
+        let id = client.propose(&alice, &ProposalAction::Upgrade);
+        let proposal = client.get_proposal(&id);
+        assert_eq!(proposal.status, ProposalStatus::Approved);
+    }
+
+    // ── Sign ──────────────────────────────────────────────────
+
+    #[test]
+    fn test_sign_reaches_threshold() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let id = client.propose(&s.alice, &ProposalAction::Upgrade);
+        let status = client.sign(&s.bob, &id);
+        assert_eq!(status, ProposalStatus::Approved);
+    }
+
+    #[test]
+    fn test_double_sign_rejected() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let id = client.propose(&s.bob, &ProposalAction::Upgrade);
+        assert!(client.try_sign(&s.bob, &id).is_err());
+    }
+
+    #[test]
+    fn test_non_signer_cannot_sign() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let outsider = Address::generate(&s.env);
+
+        let id = client.propose(&s.alice, &ProposalAction::Upgrade);
+        assert!(client.try_sign(&outsider, &id).is_err());
+    }
+
+    #[test]
+    fn test_bob_carol_together_cannot_reach_threshold() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let id = client.propose(&s.bob, &ProposalAction::Upgrade);
+        client.sign(&s.carol, &id);
+
+        let proposal = client.get_proposal(&id);
+        assert_eq!(proposal.status, ProposalStatus::Pending);
+    }
+
+    // ── Gasless registration ─────────────────────────────────
+
+    #[test]
+    fn test_register_contributor_with_sig_increments_nonce() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let contributor = Address::generate(&s.env);
+        let handle = soroban_sdk::String::from_str(&s.env, "gasless_dev");
+        let signature = Bytes::from_slice(&s.env, &[1u8; 64]);
+
+        assert_eq!(client.get_registration_nonce(&contributor), 0);
+        client.register_contributor_with_sig(&handle, &contributor, &signature);
+        assert_eq!(client.get_registration_nonce(&contributor), 1);
+
+        let data = client.get_contributor(&contributor);
+        assert_eq!(data.github_handle, handle);
+    }
+
+    #[test]
+    fn test_register_contributor_with_sig_rejects_empty_signature() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let contributor = Address::generate(&s.env);
+        let handle = soroban_sdk::String::from_str(&s.env, "gasless_dev");
+        let empty_signature = Bytes::new(&s.env);
+
+        let result =
+            client.try_register_contributor_with_sig(&handle, &contributor, &empty_signature);
+        assert_eq!(result, Err(Ok(ContributorError::InvalidSignature)));
+        // Nonce must NOT advance on failure.
+        assert_eq!(client.get_registration_nonce(&contributor), 0);
+    }
+
+    /// A relayer cannot replay a successful gasless registration with the same
+    /// signed intent.  After the first call succeeds the address is already
+    /// stored (`ContributorAlreadyExists`) and the nonce has advanced, so the
+    /// old auth-entry's nonce no longer matches — two independent guards.
+    #[test]
+    fn test_gasless_registration_replay_is_rejected() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let contributor = Address::generate(&s.env);
+        let handle = soroban_sdk::String::from_str(&s.env, "replay_dev");
+        let signature = Bytes::from_slice(&s.env, &[0xABu8; 64]);
+
+        // First call succeeds; nonce advances to 1.
+        client.register_contributor_with_sig(&handle, &contributor, &signature);
+        assert_eq!(client.get_registration_nonce(&contributor), 1);
+
+        // Second call with the same arguments must be rejected because the
+        // contributor already exists (and the nonce has changed).
+        let result = client.try_register_contributor_with_sig(&handle, &contributor, &signature);
+        assert_eq!(result, Err(Ok(ContributorError::ContributorAlreadyExists)));
+
+        // Nonce must NOT advance on the failed replay attempt.
+        assert_eq!(client.get_registration_nonce(&contributor), 1);
+    }
+
+    /// The `get_registration_nonce` query returns 0 for an unknown address and
+    /// the correct incremented value after a successful gasless registration.
+    #[test]
+    fn test_get_registration_nonce_query() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let unknown = Address::generate(&s.env);
+        assert_eq!(client.get_registration_nonce(&unknown), 0);
+
+        let contributor = Address::generate(&s.env);
+        let handle = soroban_sdk::String::from_str(&s.env, "nonce_check_dev");
+        let signature = Bytes::from_slice(&s.env, &[0x01u8; 64]);
+
+        client.register_contributor_with_sig(&handle, &contributor, &signature);
+        assert_eq!(client.get_registration_nonce(&contributor), 1);
+    }
+
+    /// `update_contributor_with_sig` shares the registration nonce, and the
+    /// signed scope binds the function, handle, address and that nonce.
+    #[test]
+    fn test_update_contributor_with_sig_uses_shared_nonce() {
+        extern crate std;
+        use soroban_sdk::testutils::{AuthorizedFunction, AuthorizedInvocation};
+
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let contributor = Address::generate(&s.env);
+        let signature = Bytes::from_slice(&s.env, &[0x02u8; 64]);
+        client.register_contributor_with_sig(
+            &soroban_sdk::String::from_str(&s.env, "before_rename"),
+            &contributor,
+            &signature,
+        );
+
+        let handle = soroban_sdk::String::from_str(&s.env, "after_rename");
+        client.update_contributor_with_sig(&contributor, &handle);
+
+        assert_eq!(
+            s.env.auths(),
+            std::vec![(
+                contributor.clone(),
+                AuthorizedInvocation {
+                    function: AuthorizedFunction::Contract((
+                        s.contract.clone(),
+                        Symbol::new(&s.env, "update_contributor_with_sig"),
+                        (
+                            Symbol::new(&s.env, "update_contributor_with_sig"),
+                            handle.clone(),
+                            contributor.clone(),
+                            1u64,
+                        )
+                            .into_val(&s.env),
+                    )),
+                    sub_invocations: std::vec![],
+                }
+            )]
+        );
+        assert_eq!(client.get_registration_nonce(&contributor), 2);
+        assert_eq!(client.get_contributor(&contributor).github_handle, handle);
+    }
+
+    // ── Execute ───────────────────────────────────────────────
+
+    #[test]
+    fn test_consume_approval_executes_after_threshold() {
+        // env.deployer().update_current_contract_wasm() is unavailable in the
+        // test environment, so we verify consume_approval via set_admin, which
+        // exercises the identical code path without hitting the deployer.
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let id = client.propose(&s.alice, &ProposalAction::SetAdmin);
+        client.sign(&s.bob, &id); // threshold reached
+
+        let new_admin = Address::generate(&s.env);
+        client.set_admin(&s.alice, &id, &new_admin);
+
+        // Proposal must be Executed — replay is now impossible.
+        assert_eq!(client.get_proposal(&id).status, ProposalStatus::Executed);
+    }
+
+    #[test]
+    fn test_upgrade_approved_before_execution() {
+        // Verifies the proposal reaches Approved status when threshold is met,
+        // which is the pre-condition for upgrade execution.
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let id = client.propose(&s.alice, &ProposalAction::Upgrade);
+        assert_eq!(client.get_proposal(&id).status, ProposalStatus::Pending);
+
+        let status = client.sign(&s.bob, &id);
+        assert_eq!(status, ProposalStatus::Approved);
+        assert_eq!(client.get_proposal(&id).status, ProposalStatus::Approved);
+    }
+
+    #[test]
+    fn test_execute_below_threshold_fails() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let id = client.propose(&s.bob, &ProposalAction::Upgrade);
+        let wasm_hash = BytesN::from_array(&s.env, &[1u8; 32]);
+        assert!(client.try_upgrade(&s.bob, &id, &wasm_hash).is_err());
+    }
+
+    #[test]
+    fn test_wrong_action_type_rejected() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let id = client.propose(&s.alice, &ProposalAction::Upgrade);
+        client.sign(&s.bob, &id);
+
+        let new_admin = Address::generate(&s.env);
+        assert!(client.try_set_admin(&s.alice, &id, &new_admin).is_err());
+    }
+
+    #[test]
+    fn test_replay_blocked_after_execution() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let id = client.propose(&s.alice, &ProposalAction::Upgrade);
+        client.sign(&s.bob, &id);
+        let wasm_hash = BytesN::from_array(&s.env, &[1u8; 32]);
+        let _ = client.try_upgrade(&s.alice, &id, &wasm_hash);
+
+        assert!(client.try_upgrade(&s.alice, &id, &wasm_hash).is_err());
+    }
+
+    // ── update_reputation ─────────────────────────────────────
+
+    #[test]
+    fn test_update_reputation_requires_multisig() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let contributor = Address::generate(&s.env);
+        let handle = soroban_sdk::String::from_str(&s.env, "dev_handle");
+        client.register_contributor(&contributor, &handle);
+
+        let fake_id = 999u64;
+        assert!(client
+            .try_update_reputation(&s.alice, &fake_id, &contributor, &10i64)
+            .is_err());
+    }
+
+    #[test]
+    fn test_update_reputation_succeeds_with_approval() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let contributor = Address::generate(&s.env);
+        let handle = soroban_sdk::String::from_str(&s.env, "dev_handle");
+        client.register_contributor(&contributor, &handle);
+
+        let id = client.propose(&s.alice, &ProposalAction::UpdateReputation);
+        client.sign(&s.bob, &id);
+
+        client.update_reputation(&s.alice, &id, &contributor, &50i64);
+
+        assert_eq!(client.get_reputation(&contributor), 50);
+    }
+
+    // ── Cancel & expire ───────────────────────────────────────
+
+    #[test]
+    fn test_cancel_blocks_execution() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let id = client.propose(&s.alice, &ProposalAction::Upgrade);
+        client.sign(&s.bob, &id);
+        client.cancel_proposal(&s.alice, &id);
+
+        let wasm_hash = BytesN::from_array(&s.env, &[1u8; 32]);
+        assert!(client.try_upgrade(&s.alice, &id, &wasm_hash).is_err());
+    }
+
+    #[test]
+    fn test_expire_after_ttl() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        s.env.ledger().set_timestamp(1_000_000);
+        let id = client.propose(&s.alice, &ProposalAction::Upgrade);
+
+        s.env
+            .ledger()
+            .set_timestamp(1_000_000 + multisig::PROPOSAL_TTL_SECS + 1);
+
+        client.expire_proposal(&id);
+
+        let proposal = client.get_proposal(&id);
+        assert_eq!(proposal.status, ProposalStatus::Expired);
+    }
+
+    #[test]
+    fn test_expire_before_ttl_fails() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        s.env.ledger().set_timestamp(1_000_000);
+        let id = client.propose(&s.alice, &ProposalAction::Upgrade);
+        s.env.ledger().set_timestamp(1_000_000 + 3600);
+
+        assert!(client.try_expire_proposal(&id).is_err());
+    }
+
+    // ── Full integration flow ─────────────────────────────────
+
+    #[test]
+    fn test_full_proposal_flow() {
+        // Full lifecycle: propose → sign → sign → execute → replay blocked.
+        // Uses SetAdmin as the action since it doesn't require the deployer.
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        // 1. Alice proposes (w=2, threshold=3) → Pending
+        let id = client.propose(&s.alice, &ProposalAction::SetAdmin);
+        assert_eq!(client.get_proposal(&id).status, ProposalStatus::Pending);
+
+        // 2. Bob signs (w=1) → total=3=threshold → Approved
+        let status = client.sign(&s.bob, &id);
+        assert_eq!(status, ProposalStatus::Approved);
+
+        // 3. Carol signs over threshold — still Approved, not a state change
+        let status = client.sign(&s.carol, &id);
+        assert_eq!(status, ProposalStatus::Approved);
+
+        // 4. Alice executes → Executed
+        let new_admin = Address::generate(&s.env);
+        client.set_admin(&s.alice, &id, &new_admin);
+        assert_eq!(client.get_proposal(&id).status, ProposalStatus::Executed);
+
+        // 5. Replay attempt fails
+        let new_admin2 = Address::generate(&s.env);
+        assert!(client.try_set_admin(&s.alice, &id, &new_admin2).is_err());
+    }
+
+    // ── TTL / storage-rent tests ──────────────────────────────
+
+    /// Verify that a contributor entry remains accessible after a simulated
+    /// ledger advance (TTL bump keeps the entry alive).
+    #[test]
+    fn test_contributor_entry_accessible_after_ledger_advance() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let contributor = Address::generate(&s.env);
+        let handle = soroban_sdk::String::from_str(&s.env, "ttl_dev");
+        client.register_contributor(&contributor, &handle);
+
+        // Advance the ledger sequence significantly (simulates time passing).
+        s.env.ledger().set_sequence_number(200_000);
+
+        // The entry must still be readable — TTL bump on write keeps it alive.
+        let data = client.get_contributor(&contributor);
+        assert_eq!(data.github_handle, handle);
+    }
+
+    /// Verify that TTL is extended after a write (registration) and a read
+    /// (get_contributor) by confirming the entry survives a large ledger jump.
+    #[test]
+    fn test_ttl_extended_after_read_write() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let contributor = Address::generate(&s.env);
+        let handle = soroban_sdk::String::from_str(&s.env, "ttl_rw_dev");
+        client.register_contributor(&contributor, &handle);
+
+        // Simulate a large ledger advance.
+        s.env.ledger().set_sequence_number(100_001);
+
+        // Read triggers another TTL bump; entry must still be accessible.
+        let data = client.get_contributor(&contributor);
+        assert_eq!(data.github_handle, handle);
+
+        // Advance again — the read-triggered bump should keep it alive.
+        s.env.ledger().set_sequence_number(200_002);
+        let data2 = client.get_contributor(&contributor);
+        assert_eq!(data2.github_handle, handle);
+    }
+
+    /// Verify that deregistering a contributor removes all three storage keys
+    /// (Contributor, GitHubIndex, RegistrationNonce) — no orphaned entries.
+    #[test]
+    fn test_deregister_removes_all_keys() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let contributor = Address::generate(&s.env);
+        let handle = soroban_sdk::String::from_str(&s.env, "deregister_dev");
+        let signature = soroban_sdk::Bytes::from_slice(&s.env, &[1u8; 64]);
+
+        // Use gasless registration so a nonce entry is also created.
+        client.register_contributor_with_sig(&handle, &contributor, &signature);
+        assert_eq!(client.get_registration_nonce(&contributor), 1);
+
+        // Deregister — all three keys must be removed.
+        client.deregister_contributor(&contributor);
+
+        // Contributor entry gone.
+        assert!(client.try_get_contributor(&contributor).is_err());
+        // GitHub index entry gone — a new address can now claim the same handle.
+        let other = Address::generate(&s.env);
+        let sig2 = soroban_sdk::Bytes::from_slice(&s.env, &[2u8; 64]);
+        client.register_contributor_with_sig(&handle, &other, &sig2);
+        let data = client.get_contributor(&other);
+        assert_eq!(data.github_handle, handle);
+    }
+
+    fn penalize(s: &Setup, contributor: &Address, points: u64) {
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let id = client.propose(&s.alice, &ProposalAction::ApplyPenalty);
+        client.sign(&s.bob, &id);
+        client.apply_reputation_penalty(
+            &s.alice,
+            &id,
+            contributor,
+            &7u64,
+            &PenaltySeverity::Minor,
+            &points,
+            &soroban_sdk::String::from_str(&s.env, "spam"),
+        );
+    }
+
+    #[test]
+    fn test_deregister_burns_badges_and_keeps_penalty_log() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let contributor = Address::generate(&s.env);
+        let handle = soroban_sdk::String::from_str(&s.env, "cleanup_dev");
+        client.register_contributor(&contributor, &handle);
+        let id = client.propose(&s.alice, &ProposalAction::GrantBadge);
+        client.sign(&s.bob, &id);
+        client.grant_badge(&s.alice, &id, &contributor, &1u32);
+        let token_id = client.get_badges(&contributor).get(0).unwrap().token_id;
+        penalize(&s, &contributor, 5);
+
+        s.env.ledger().set_timestamp(1_000);
+        client.deregister_contributor(&contributor);
+
+        assert_eq!(client.get_badges(&contributor).len(), 0);
+        assert_eq!(
+            client.try_owner_of(&token_id),
+            Err(Ok(ContributorError::BadgeTokenNotFound))
+        );
+        assert_eq!(client.get_reputation_history(&contributor).len(), 0);
+        assert_eq!(client.get_penalty_history(&contributor).len(), 1);
+        assert_eq!(
+            client
+                .get_penalty_record(&contributor)
+                .unwrap()
+                .points_deducted,
+            5
+        );
+        assert_eq!(client.get_deregistered_at(&contributor), Some(1_000));
+    }
+
+    #[test]
+    fn test_reregistration_cooldown() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let id = client.propose(&s.alice, &ProposalAction::SetReregistrationCooldown);
+        client.sign(&s.bob, &id);
+        client.set_reregistration_cooldown(&s.alice, &id, &500u64);
+        assert_eq!(client.get_reregistration_cooldown(), 500);
+
+        let contributor = Address::generate(&s.env);
+        let handle = soroban_sdk::String::from_str(&s.env, "cooldown_dev");
+        client.register_contributor(&contributor, &handle);
+        penalize(&s, &contributor, 3);
+
+        s.env.ledger().set_timestamp(1_000);
+        client.deregister_contributor(&contributor);
+
+        s.env.ledger().set_timestamp(1_499);
+        assert_eq!(
+            client.try_register_contributor(&contributor, &handle),
+            Err(Ok(ContributorError::ReregistrationCooldownActive))
+        );
+
+        // The handle cools off too, so it cannot move to a fresh wallet.
+        let fresh = Address::generate(&s.env);
+        assert_eq!(
+            client.try_register_contributor(&fresh, &handle),
+            Err(Ok(ContributorError::ReregistrationCooldownActive))
+        );
+        let other = Address::generate(&s.env);
+        client.register_contributor(&other, &soroban_sdk::String::from_str(&s.env, "other_dev"));
+        assert_eq!(
+            client.try_update_contributor(&other, &handle),
+            Err(Ok(ContributorError::ReregistrationCooldownActive))
+        );
+
+        s.env.ledger().set_timestamp(1_500);
+        client.register_contributor(&contributor, &handle);
+        assert_eq!(client.get_deregistered_at(&contributor), None);
+        assert_eq!(client.get_penalty_history(&contributor).len(), 1);
+    }
+
+    #[test]
+    fn test_migrate_contributor_moves_account_state() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let old = Address::generate(&s.env);
+        let new = Address::generate(&s.env);
+        let handle = soroban_sdk::String::from_str(&s.env, "rotating_dev");
+        client.register_contributor(&old, &handle);
+        bump_reputation(&s, &old, 40);
+        let id = client.propose(&s.alice, &ProposalAction::GrantBadge);
+        client.sign(&s.bob, &id);
+        client.grant_badge(&s.alice, &id, &old, &1u32);
+        let token_id = client.get_badges(&old).get(0).unwrap().token_id;
+        penalize(&s, &old, 10);
+
+        client.migrate_contributor(&old, &new);
+
+        assert!(client.try_get_contributor(&old).is_err());
+        let data = client.get_contributor(&new);
+        assert_eq!(data.address, new);
+        assert_eq!(data.reputation_score, 30);
+        assert_eq!(client.get_contributor_by_github(&handle).address, new);
+        assert_eq!(client.owner_of(&token_id), new);
+        assert_eq!(client.get_badges(&old).len(), 0);
+        assert_eq!(client.get_penalty_history(&new).len(), 1);
+        assert_eq!(client.get_penalty_history(&old).len(), 0);
+        assert_eq!(client.get_reputation_history(&new).len(), 2);
+
+        // The target must be unregistered.
+        let other = Address::generate(&s.env);
+        client.register_contributor(&other, &soroban_sdk::String::from_str(&s.env, "other"));
+        assert_eq!(
+            client.try_migrate_contributor(&new, &other),
+            Err(Ok(ContributorError::ContributorAlreadyExists))
+        );
+    }
+
+    // ── Delegation ────────────────────────────────────────────
+
+    fn register_with_reputation(s: &Setup, handle: &str, reputation: i64) -> Address {
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let contributor = Address::generate(&s.env);
+        client.register_contributor(&contributor, &soroban_sdk::String::from_str(&s.env, handle));
+        bump_reputation(s, &contributor, reputation);
+        contributor
+    }
+
+    #[test]
+    fn test_delegation_checkpoints_voting_power() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        s.env.ledger().set_timestamp(100);
+        let delegator = register_with_reputation(&s, "delegator", 30);
+        let delegatee = register_with_reputation(&s, "delegatee", 20);
+
+        s.env.ledger().set_timestamp(200);
+        client.delegate(&delegator, &delegatee, &DelegationScope::Global);
+        assert_eq!(client.get_voting_power(&delegator, &201), 0);
+        assert_eq!(client.get_voting_power(&delegatee, &201), 50);
+        // Earlier snapshots are unaffected.
+        assert_eq!(client.get_voting_power(&delegator, &150), 30);
+        assert_eq!(client.get_voting_power(&delegatee, &150), 20);
+
+        // Reputation changes flow through to the delegatee.
+        s.env.ledger().set_timestamp(300);
+        bump_reputation(&s, &delegator, 10);
+        assert_eq!(client.get_voting_power(&delegatee, &301), 60);
+        assert_eq!(client.get_voting_power(&delegatee, &250), 50);
+
+        s.env.ledger().set_timestamp(400);
+        client.undelegate(&delegator, &DelegationScope::Global);
+        assert_eq!(client.get_voting_power(&delegator, &401), 40);
+        assert_eq!(client.get_voting_power(&delegatee, &401), 20);
+        assert_eq!(
+            client.try_undelegate(&delegator, &DelegationScope::Global),
+            Err(Ok(ContributorError::DelegationNotFound))
+        );
+        assert_eq!(
+            client.try_delegate(&delegator, &delegator, &DelegationScope::Global),
+            Err(Ok(ContributorError::InvalidDelegation))
+        );
+    }
+
+    #[test]
+    fn test_delegating_after_voting_in_the_snapshot_ledger_counts_once() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        s.env.ledger().set_timestamp(100);
+        let voter = register_with_reputation(&s, "early_voter", 30);
+        let delegatee = register_with_reputation(&s, "late_delegatee", 20);
+
+        // `voter` has already voted with their power at 200 when they
+        // delegate in the same ledger.
+        s.env.ledger().set_timestamp(200);
+        assert_eq!(client.get_voting_power(&voter, &200), 30);
+        client.delegate(&voter, &delegatee, &DelegationScope::Global);
+        assert_eq!(client.get_voting_power(&voter, &200), 30);
+        assert_eq!(client.get_voting_power(&delegatee, &200), 20);
+        assert_eq!(
+            client.get_delegated_power(&delegatee, &DelegationScope::Global, &200),
+            0
+        );
+
+        // From the next ledger on the delegation counts.
+        assert_eq!(client.get_voting_power(&voter, &201), 0);
+        assert_eq!(client.get_voting_power(&delegatee, &201), 50);
+    }
 
-        Setup {
-            env,
-            contract,
-            alice,
-            bob,
-            carol,
-        }
+    #[test]
+    fn test_delegated_reputation_decays() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        s.env.ledger().set_timestamp(0);
+        let delegator = register_with_reputation(&s, "decaying_delegator", 50);
+        let delegatee = register_with_reputation(&s, "decay_delegatee", 10);
+        configure_decay(
+            &s,
+            Some(DecayConfig {
+                period_secs: 100,
+                points_per_period: 5,
+                floor: 0,
+            }),
+        );
+
+        // Delegating carries the decayed score, not the stored one.
+        s.env.ledger().set_timestamp(250);
+        client.delegate(&delegator, &delegatee, &DelegationScope::Global);
+        assert_eq!(
+            client.get_delegated_power(&delegatee, &DelegationScope::Global, &251),
+            40
+        );
+
+        // Syncing keeps the partly elapsed period, so decay cannot be stalled.
+        s.env.ledger().set_timestamp(350);
+        assert_eq!(client.sync_reputation(&delegator), 35);
+        s.env.ledger().set_timestamp(399);
+        assert_eq!(client.sync_reputation(&delegator), 35);
+        s.env.ledger().set_timestamp(400);
+        assert_eq!(client.sync_reputation(&delegator), 30);
+        // The delegatee's own 10 has fully decayed by now.
+        assert_eq!(client.get_voting_power(&delegatee, &401), 30);
+        assert_eq!(
+            client.get_delegated_power(&delegatee, &DelegationScope::Global, &300),
+            40
+        );
     }
 
-    // ── Initialisation ────────────────────────────────────────
+    #[test]
+    fn test_delegation_snapshot_older_than_retained_checkpoints_fails() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        s.env.ledger().set_timestamp(10);
+        let delegator = register_with_reputation(&s, "busy_delegator", 5);
+        let a = register_with_reputation(&s, "delegatee_a", 0);
+        let b = register_with_reputation(&s, "delegatee_b", 0);
+
+        for i in 0..delegation::MAX_DELEGATION_CHECKPOINTS as u64 {
+            s.env.ledger().set_timestamp(100 + i);
+            let to = if i % 2 == 0 { &a } else { &b };
+            client.delegate(&delegator, to, &DelegationScope::Global);
+        }
+
+        assert_eq!(
+            client.try_get_voting_power(&delegator, &99),
+            Err(Ok(ContributorError::SnapshotTooOld))
+        );
+        assert_eq!(
+            client.try_get_delegated_power(&a, &DelegationScope::Global, &50),
+            Err(Ok(ContributorError::SnapshotTooOld))
+        );
+        assert_eq!(client.get_voting_power(&delegator, &120), 0);
+    }
 
     #[test]
-    fn test_initialize_stores_config() {
+    fn test_scoped_delegation_overrides_global() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
-        // Client methods return values directly — no .unwrap() needed.
-        let config = client.get_multisig_config();
-        assert_eq!(config.threshold, 3);
-        assert_eq!(config.signers.len(), 3);
+        let delegator = register_with_reputation(&s, "scoped_a", 30);
+        let general = register_with_reputation(&s, "scoped_b", 5);
+        let specialist = register_with_reputation(&s, "scoped_c", 7);
+        let voting_contract = Address::generate(&s.env);
+        let in_contract = DelegationScope::Contract(voting_contract);
+        let topic = DelegationScope::Topic(Symbol::new(&s.env, "grants"));
+
+        s.env.ledger().set_timestamp(10);
+        client.delegate(&delegator, &general, &DelegationScope::Global);
+        client.delegate(&delegator, &specialist, &in_contract);
+        client.delegate(&delegator, &delegator, &topic);
+
+        assert_eq!(client.get_voting_power(&general, &11), 35);
+        assert_eq!(client.get_voting_power(&specialist, &11), 7);
+        assert_eq!(client.get_voting_power_in(&general, &in_contract, &11), 5);
+        assert_eq!(
+            client.get_voting_power_in(&specialist, &in_contract, &11),
+            37
+        );
+        assert_eq!(client.get_voting_power_in(&delegator, &in_contract, &11), 0);
+        // Self-delegating a topic keeps the delegator's own vote there.
+        assert_eq!(client.get_voting_power_in(&delegator, &topic, &11), 30);
+        assert_eq!(client.get_voting_power_in(&general, &topic, &11), 5);
+
+        // Overrides track reputation changes as well.
+        s.env.ledger().set_timestamp(20);
+        bump_reputation(&s, &delegator, 10);
+        assert_eq!(client.get_voting_power(&general, &21), 45);
+        assert_eq!(client.get_voting_power_in(&general, &in_contract, &21), 5);
+        assert_eq!(
+            client.get_voting_power_in(&specialist, &in_contract, &21),
+            47
+        );
+        assert_eq!(
+            client.get_delegate(&delegator, &in_contract),
+            Some(specialist.clone())
+        );
+
+        // Dropping the scoped delegation falls back to the global one.
+        s.env.ledger().set_timestamp(30);
+        client.undelegate(&delegator, &in_contract);
+        assert_eq!(client.get_voting_power_in(&general, &in_contract, &31), 45);
+        assert_eq!(
+            client.get_voting_power_in(&specialist, &in_contract, &31),
+            7
+        );
     }
 
+    // ── Badges & Tiers ────────────────────────────────────────
+
     #[test]
-    fn test_double_initialize_fails() {
+    fn test_tier_calculation() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
-        let mut signers = Vec::new(&s.env);
-        signers.push_back(Signer {
-            address: s.alice.clone(),
-            weight: 1,
-        });
-        // try_* variants return Result and are used to assert failure.
-        assert!(client.try_initialize(&signers, &1u32).is_err());
+
+        let contributor = Address::generate(&s.env);
+        let handle = soroban_sdk::String::from_str(&s.env, "tier_dev");
+        client.register_contributor(&contributor, &handle);
+
+        assert_eq!(client.get_tier(&contributor), ContributorTier::Novice);
+
+        let id = client.propose(&s.alice, &ProposalAction::UpdateReputation);
+        client.sign(&s.bob, &id);
+        client.update_reputation(&s.alice, &id, &contributor, &20i64);
+        assert_eq!(client.get_tier(&contributor), ContributorTier::Builder);
+
+        let id2 = client.propose(&s.alice, &ProposalAction::UpdateReputation);
+        client.sign(&s.bob, &id2);
+        client.update_reputation(&s.alice, &id2, &contributor, &50i64);
+        assert_eq!(client.get_tier(&contributor), ContributorTier::Architect);
+
+        let id3 = client.propose(&s.alice, &ProposalAction::UpdateReputation);
+        client.sign(&s.bob, &id3);
+        client.update_reputation(&s.alice, &id3, &contributor, &50i64);
+        assert_eq!(client.get_tier(&contributor), ContributorTier::Core);
     }
 
     #[test]
-    fn test_threshold_above_total_weight_fails() {
-        let env = Env::default();
-        env.mock_all_auths();
-        let contract = env.register(ContributorRegistryContract, ());
-        let client = ContributorRegistryContractClient::new(&env, &contract);
-        let alice = Address::generate(&env);
+    fn test_grant_and_revoke_badge() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
 
-        let mut signers = Vec::new(&env);
-        signers.push_back(Signer {
-            address: alice,
-            weight: 1,
-        });
-        assert!(client.try_initialize(&signers, &99u32).is_err());
+        let contributor = Address::generate(&s.env);
+        let handle = soroban_sdk::String::from_str(&s.env, "badge_dev");
+        client.register_contributor(&contributor, &handle);
+
+        assert_eq!(client.get_badges(&contributor).len(), 0);
+
+        let id = client.propose(&s.alice, &ProposalAction::GrantBadge);
+        client.sign(&s.bob, &id);
+        client.grant_badge(&s.alice, &id, &contributor, &1u32);
+
+        let badges = client.get_badges(&contributor);
+        assert_eq!(badges.len(), 1);
+        assert_eq!(badges.get(0).unwrap().badge_id, 1);
+
+        let id2 = client.propose(&s.alice, &ProposalAction::RevokeBadge);
+        client.sign(&s.bob, &id2);
+        client.revoke_badge(&s.alice, &id2, &contributor, &1u32);
+
+        assert_eq!(client.get_badges(&contributor).len(), 0);
     }
 
-    // ── Propose ───────────────────────────────────────────────
+    // ── Reputation penalty hooks (issue #691) ─────────────────
 
     #[test]
-    fn test_propose_counts_proposer_weight() {
+    fn test_apply_penalty_deducts_reputation() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
 
-        let id = client.propose(&s.alice, &ProposalAction::Upgrade);
-        let proposal = client.get_proposal(&id);
+        let contributor = Address::generate(&s.env);
+        let handle = soroban_sdk::String::from_str(&s.env, "penalty_dev");
+        client.register_contributor(&contributor, &handle);
 
-        assert_eq!(proposal.weight_collected, 2);
-        assert_eq!(proposal.status, ProposalStatus::Pending);
+        // Give the contributor some reputation first.
+        let id = client.propose(&s.alice, &ProposalAction::UpdateReputation);
+        client.sign(&s.bob, &id);
+        client.update_reputation(&s.alice, &id, &contributor, &100i64);
+        assert_eq!(client.get_reputation(&contributor), 100);
+
+        // Apply a penalty via multisig.
+        let pid = client.propose(&s.alice, &ProposalAction::ApplyPenalty);
+        client.sign(&s.bob, &pid);
+        let reason = soroban_sdk::String::from_str(&s.env, "fraudulent milestone");
+        client.apply_reputation_penalty(
+            &s.alice,
+            &pid,
+            &contributor,
+            &1u64,
+            &PenaltySeverity::Moderate,
+            &30u64,
+            &reason,
+        );
+
+        assert_eq!(client.get_reputation(&contributor), 70);
     }
 
     #[test]
-    fn test_propose_by_non_signer_fails() {
+    fn test_apply_penalty_floors_at_zero() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
-        let outsider = Address::generate(&s.env);
-        assert!(client
-            .try_propose(&outsider, &ProposalAction::Upgrade)
-            .is_err());
+
+        let contributor = Address::generate(&s.env);
+        let handle = soroban_sdk::String::from_str(&s.env, "floor_dev");
+        client.register_contributor(&contributor, &handle);
+
+        // Reputation is 0; penalty should not underflow.
+        let pid = client.propose(&s.alice, &ProposalAction::ApplyPenalty);
+        client.sign(&s.bob, &pid);
+        let reason = soroban_sdk::String::from_str(&s.env, "severe violation");
+        client.apply_reputation_penalty(
+            &s.alice,
+            &pid,
+            &contributor,
+            &2u64,
+            &PenaltySeverity::Severe,
+            &999u64,
+            &reason,
+        );
+
+        assert_eq!(client.get_reputation(&contributor), 0);
     }
 
     #[test]
-    fn test_single_signer_above_threshold_auto_approves() {
-        let env = Env::default();
-        env.mock_all_auths();
-        let contract = env.register(ContributorRegistryContract, ());
-        let alice = Address::generate(&env);
-        let bob = Address::generate(&env);
+    fn test_apply_penalty_stores_record() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
 
-        let mut signers = Vec::new(&env);
-        signers.push_back(Signer {
-            address: alice.clone(),
-            weight: 3,
-        });
-        signers.push_back(Signer {
-            address: bob.clone(),
-            weight: 1,
-        });
+        let contributor = Address::generate(&s.env);
+        let handle = soroban_sdk::String::from_str(&s.env, "record_dev");
+        client.register_contributor(&contributor, &handle);
 
-        let client = ContributorRegistryContractClient::new(&env, &contract);
-        client.initialize(&signers, &3u32);
+        let pid = client.propose(&s.alice, &ProposalAction::ApplyPenalty);
+        client.sign(&s.bob, &pid);
+        let reason = soroban_sdk::String::from_str(&s.env, "dispute resolved against");
+        client.apply_reputation_penalty(
+            &s.alice,
+            &pid,
+            &contributor,
+            &42u64,
+            &PenaltySeverity::Minor,
+            &10u64,
+            &reason,
+        );
 
-        let id = client.propose(&alice, &ProposalAction::Upgrade);
-        let proposal = client.get_proposal(&id);
-        assert_eq!(proposal.status, ProposalStatus::Approved);
+        let record = client.get_penalty_record(&contributor).unwrap();
+        assert_eq!(record.dispute_id, 42);
+        assert_eq!(record.severity, PenaltySeverity::Minor);
+        assert_eq!(record.points_deducted, 10);
     }
 
-    // ── Sign ──────────────────────────────────────────────────
+    #[test]
+    fn test_apply_penalty_requires_multisig() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let contributor = Address::generate(&s.env);
+        let handle = soroban_sdk::String::from_str(&s.env, "noauth_dev");
+        client.register_contributor(&contributor, &handle);
+
+        let reason = soroban_sdk::String::from_str(&s.env, "test");
+        let fake_id = 999u64;
+        assert!(client
+            .try_apply_reputation_penalty(
+                &s.alice,
+                &fake_id,
+                &contributor,
+                &1u64,
+                &PenaltySeverity::Minor,
+                &5u64,
+                &reason,
+            )
+            .is_err());
+    }
 
     #[test]
-    fn test_sign_reaches_threshold() {
+    fn test_get_penalty_record_returns_none_when_absent() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
 
-        let id = client.propose(&s.alice, &ProposalAction::Upgrade);
-        let status = client.sign(&s.bob, &id);
-        assert_eq!(status, ProposalStatus::Approved);
+        let contributor = Address::generate(&s.env);
+        let handle = soroban_sdk::String::from_str(&s.env, "no_penalty_dev");
+        client.register_contributor(&contributor, &handle);
+
+        assert!(client.get_penalty_record(&contributor).is_none());
+    }
+
+    // ── Reputation history & decay ────────────────────────────
+
+    fn bump_reputation(s: &Setup, contributor: &Address, delta: i64) {
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let id = client.propose(&s.alice, &ProposalAction::UpdateReputation);
+        client.sign(&s.bob, &id);
+        client.update_reputation(&s.alice, &id, contributor, &delta);
+    }
+
+    fn configure_decay(s: &Setup, config: Option<DecayConfig>) {
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let id = client.propose(&s.alice, &ProposalAction::SetDecayConfig);
+        client.sign(&s.bob, &id);
+        client.set_decay_config(&s.alice, &id, &config);
     }
 
     #[test]
-    fn test_double_sign_rejected() {
+    fn test_reputation_changes_are_recorded_in_history() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
 
-        let id = client.propose(&s.bob, &ProposalAction::Upgrade);
-        assert!(client.try_sign(&s.bob, &id).is_err());
+        let contributor = Address::generate(&s.env);
+        client.register_contributor(
+            &contributor,
+            &soroban_sdk::String::from_str(&s.env, "history_dev"),
+        );
+
+        s.env.ledger().set_timestamp(100);
+        bump_reputation(&s, &contributor, 40);
+        s.env.ledger().set_timestamp(200);
+        bump_reputation(&s, &contributor, -60);
+
+        let history = client.get_reputation_history(&contributor);
+        assert_eq!(history.len(), 2);
+
+        let first = history.get(0).unwrap();
+        assert_eq!(first.source, ReputationSource::Governance);
+        assert_eq!(first.delta, 40);
+        assert_eq!(first.score_after, 40);
+        assert_eq!(first.timestamp, 100);
+
+        // The applied delta reflects the floor at zero, not the requested -60.
+        let second = history.get(1).unwrap();
+        assert_eq!(second.delta, -40);
+        assert_eq!(second.score_after, 0);
     }
 
     #[test]
-    fn test_non_signer_cannot_sign() {
+    fn test_reputation_event_publishes_applied_delta() {
+        use soroban_sdk::testutils::Events;
+        use soroban_sdk::{Map, Symbol, TryFromVal, Val};
+
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
-        let outsider = Address::generate(&s.env);
 
-        let id = client.propose(&s.alice, &ProposalAction::Upgrade);
-        assert!(client.try_sign(&outsider, &id).is_err());
+        let contributor = Address::generate(&s.env);
+        client.register_contributor(
+            &contributor,
+            &soroban_sdk::String::from_str(&s.env, "event_dev"),
+        );
+        bump_reputation(&s, &contributor, 40);
+        bump_reputation(&s, &contributor, -60);
+
+        let (_, _, data) = s
+            .env
+            .events()
+            .all()
+            .iter()
+            .rev()
+            .find(|(_, topics, _)| {
+                Symbol::try_from_val(&s.env, &topics.get(0).unwrap())
+                    == Ok(Symbol::new(&s.env, "reputation_changed_event"))
+            })
+            .unwrap();
+        let data = Map::<Symbol, Val>::try_from_val(&s.env, &data).unwrap();
+        let field = |name: &str| data.get(Symbol::new(&s.env, name)).unwrap();
+        assert_eq!(i64::try_from_val(&s.env, &field("delta")).unwrap(), -40);
+        assert_eq!(u64::try_from_val(&s.env, &field("new_score")).unwrap(), 0);
     }
 
     #[test]
-    fn test_bob_carol_together_cannot_reach_threshold() {
+    fn test_reputation_history_is_bounded() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
 
-        let id = client.propose(&s.bob, &ProposalAction::Upgrade);
-        client.sign(&s.carol, &id);
+        let contributor = Address::generate(&s.env);
+        client.register_contributor(
+            &contributor,
+            &soroban_sdk::String::from_str(&s.env, "bounded_dev"),
+        );
 
-        let proposal = client.get_proposal(&id);
-        assert_eq!(proposal.status, ProposalStatus::Pending);
-    }
+        for _ in 0..(reputation::MAX_REPUTATION_HISTORY + 5) {
+            bump_reputation(&s, &contributor, 1);
+        }
 
-    // ── Gasless registration ─────────────────────────────────
+        let history = client.get_reputation_history(&contributor);
+        assert_eq!(history.len(), reputation::MAX_REPUTATION_HISTORY);
+        // Oldest entries were dropped: the first retained entry is change #6.
+        assert_eq!(history.get(0).unwrap().score_after, 6);
+        assert_eq!(
+            client.get_reputation(&contributor),
+            (reputation::MAX_REPUTATION_HISTORY + 5) as u64
+        );
+    }
 
     #[test]
-    fn test_register_contributor_with_sig_increments_nonce() {
+    fn test_get_reputation_at_snapshots_past_scores() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
 
+        s.env.ledger().set_timestamp(1_000);
         let contributor = Address::generate(&s.env);
-        let handle = soroban_sdk::String::from_str(&s.env, "gasless_dev");
-        let signature = Bytes::from_slice(&s.env, &[1u8; 64]);
-
-        assert_eq!(client.get_registration_nonce(&contributor), 0);
-        client.register_contributor_with_sig(&handle, &contributor, &signature);
-        assert_eq!(client.get_registration_nonce(&contributor), 1);
+        client.register_contributor(
+            &contributor,
+            &soroban_sdk::String::from_str(&s.env, "snapshot_dev"),
+        );
 
-        let data = client.get_contributor(&contributor);
-        assert_eq!(data.github_handle, handle);
+        s.env.ledger().set_timestamp(2_000);
+        bump_reputation(&s, &contributor, 30);
+        s.env.ledger().set_timestamp(3_000);
+        bump_reputation(&s, &contributor, 20);
+
+        assert_eq!(client.get_reputation_at(&contributor, &500), 0);
+        assert_eq!(client.get_reputation_at(&contributor, &1_500), 0);
+        assert_eq!(client.get_reputation_at(&contributor, &2_000), 30);
+        assert_eq!(client.get_reputation_at(&contributor, &2_999), 30);
+        assert_eq!(client.get_reputation_at(&contributor, &3_000), 50);
+        assert_eq!(client.get_reputation_at(&contributor, &10_000), 50);
     }
 
     #[test]
-    fn test_register_contributor_with_sig_rejects_empty_signature() {
+    fn test_inactivity_decay_erodes_toward_floor() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
 
+        s.env.ledger().set_timestamp(0);
         let contributor = Address::generate(&s.env);
-        let handle = soroban_sdk::String::from_str(&s.env, "gasless_dev");
-        let empty_signature = Bytes::new(&s.env);
+        client.register_contributor(
+            &contributor,
+            &soroban_sdk::String::from_str(&s.env, "decay_dev"),
+        );
+        bump_reputation(&s, &contributor, 100);
+
+        configure_decay(
+            &s,
+            Some(DecayConfig {
+                period_secs: 1_000,
+                points_per_period: 10,
+                floor: 60,
+            }),
+        );
 
-        let result =
-            client.try_register_contributor_with_sig(&handle, &contributor, &empty_signature);
-        assert_eq!(result, Err(Ok(ContributorError::InvalidSignature)));
-        // Nonce must NOT advance on failure.
-        assert_eq!(client.get_registration_nonce(&contributor), 0);
+        s.env.ledger().set_timestamp(999);
+        assert_eq!(client.get_reputation(&contributor), 100);
+        s.env.ledger().set_timestamp(2_500);
+        assert_eq!(client.get_reputation(&contributor), 80);
+        s.env.ledger().set_timestamp(50_000);
+        assert_eq!(client.get_reputation(&contributor), 60);
+        assert_eq!(client.get_reputation_at(&contributor, &2_500), 80);
+
+        // The next change materialises the decay and restarts the clock.
+        bump_reputation(&s, &contributor, 5);
+        assert_eq!(client.get_reputation(&contributor), 65);
+        let history = client.get_reputation_history(&contributor);
+        let decay = history.get(history.len() - 2).unwrap();
+        assert_eq!(decay.source, ReputationSource::Decay);
+        assert_eq!(decay.delta, -40);
+
+        configure_decay(&s, None);
+        s.env.ledger().set_timestamp(500_000);
+        assert_eq!(client.get_reputation(&contributor), 65);
     }
 
-    /// A relayer cannot replay a successful gasless registration with the same
-    /// signed intent.  After the first call succeeds the address is already
-    /// stored (`ContributorAlreadyExists`) and the nonce has advanced, so the
-    /// old auth-entry's nonce no longer matches — two independent guards.
     #[test]
-    fn test_gasless_registration_replay_is_rejected() {
+    fn test_past_scores_decay_under_the_config_of_their_time() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
 
-        let contributor = Address::generate(&s.env);
-        let handle = soroban_sdk::String::from_str(&s.env, "replay_dev");
-        let signature = Bytes::from_slice(&s.env, &[0xABu8; 64]);
+        s.env.ledger().set_timestamp(0);
+        let contributor = register_with_reputation(&s, "epoch_dev", 100);
+        configure_decay(
+            &s,
+            Some(DecayConfig {
+                period_secs: 1_000,
+                points_per_period: 10,
+                floor: 0,
+            }),
+        );
 
-        // First call succeeds; nonce advances to 1.
-        client.register_contributor_with_sig(&handle, &contributor, &signature);
-        assert_eq!(client.get_registration_nonce(&contributor), 1);
+        // Halfway through a period the rules soften to a point per period.
+        s.env.ledger().set_timestamp(5_500);
+        configure_decay(
+            &s,
+            Some(DecayConfig {
+                period_secs: 1_000,
+                points_per_period: 1,
+                floor: 0,
+            }),
+        );
+        assert_eq!(client.get_reputation_at(&contributor, &3_000), 70);
+        assert_eq!(client.get_reputation(&contributor), 50);
 
-        // Second call with the same arguments must be rejected because the
-        // contributor already exists (and the nonce has changed).
-        let result = client.try_register_contributor_with_sig(&handle, &contributor, &signature);
-        assert_eq!(result, Err(Ok(ContributorError::ContributorAlreadyExists)));
+        s.env.ledger().set_timestamp(10_500);
+        assert_eq!(client.get_reputation(&contributor), 45);
+        assert_eq!(client.get_reputation_at(&contributor, &3_000), 70);
 
-        // Nonce must NOT advance on the failed replay attempt.
-        assert_eq!(client.get_registration_nonce(&contributor), 1);
+        configure_decay(&s, None);
+        s.env.ledger().set_timestamp(50_000);
+        assert_eq!(client.get_reputation(&contributor), 45);
+        assert_eq!(client.get_reputation_at(&contributor, &8_500), 47);
+
+        // Materialising keeps what each setting took.
+        bump_reputation(&s, &contributor, 5);
+        assert_eq!(client.get_reputation(&contributor), 50);
+        let history = client.get_reputation_history(&contributor);
+        let decay = history.get(history.len() - 2).unwrap();
+        assert_eq!(decay.source, ReputationSource::Decay);
+        assert_eq!(decay.delta, -55);
+        assert_eq!(client.get_reputation_at(&contributor, &3_000), 70);
     }
 
-    /// The `get_registration_nonce` query returns 0 for an unknown address and
-    /// the correct incremented value after a successful gasless registration.
     #[test]
-    fn test_get_registration_nonce_query() {
+    fn test_invalid_decay_config_rejected() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
 
-        let unknown = Address::generate(&s.env);
-        assert_eq!(client.get_registration_nonce(&unknown), 0);
+        let id = client.propose(&s.alice, &ProposalAction::SetDecayConfig);
+        client.sign(&s.bob, &id);
+        let result = client.try_set_decay_config(
+            &s.alice,
+            &id,
+            &Some(DecayConfig {
+                period_secs: 0,
+                points_per_period: 1,
+                floor: 0,
+            }),
+        );
+        assert_eq!(result, Err(Ok(ContributorError::InvalidDecayConfig)));
+    }
+
+    #[test]
+    fn test_penalty_history_keeps_every_record() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
 
         let contributor = Address::generate(&s.env);
-        let handle = soroban_sdk::String::from_str(&s.env, "nonce_check_dev");
-        let signature = Bytes::from_slice(&s.env, &[0x01u8; 64]);
+        client.register_contributor(
+            &contributor,
+            &soroban_sdk::String::from_str(&s.env, "repeat_offender"),
+        );
+        bump_reputation(&s, &contributor, 100);
 
-        client.register_contributor_with_sig(&handle, &contributor, &signature);
-        assert_eq!(client.get_registration_nonce(&contributor), 1);
+        for dispute_id in 1..=3u64 {
+            let pid = client.propose(&s.alice, &ProposalAction::ApplyPenalty);
+            client.sign(&s.bob, &pid);
+            client.apply_reputation_penalty(
+                &s.alice,
+                &pid,
+                &contributor,
+                &dispute_id,
+                &PenaltySeverity::Minor,
+                &10u64,
+                &soroban_sdk::String::from_str(&s.env, "late delivery"),
+            );
+        }
+
+        let penalties = client.get_penalty_history(&contributor);
+        assert_eq!(penalties.len(), 3);
+        assert_eq!(penalties.get(0).unwrap().dispute_id, 1);
+        assert_eq!(penalties.get(2).unwrap().dispute_id, 3);
+        assert_eq!(
+            client.get_penalty_record(&contributor).unwrap().dispute_id,
+            3
+        );
+        assert_eq!(client.get_reputation(&contributor), 70);
+
+        let history = client.get_reputation_history(&contributor);
+        assert_eq!(
+            history.get(history.len() - 1).unwrap().source,
+            ReputationSource::Penalty
+        );
     }
 
-    // ── Execute ───────────────────────────────────────────────
+    // ── Tier thresholds & permissions ─────────────────────────
+
+    fn set_permission_rule(s: &Setup, permission: &Symbol, rule: Option<PermissionRule>) {
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let id = client.propose(&s.alice, &ProposalAction::SetPermission);
+        client.sign(&s.bob, &id);
+        client.set_permission(&s.alice, &id, permission, &rule);
+    }
 
     #[test]
-    fn test_consume_approval_executes_after_threshold() {
-        // env.deployer().update_current_contract_wasm() is unavailable in the
-        // test environment, so we verify consume_approval via set_admin, which
-        // exercises the identical code path without hitting the deployer.
+    fn test_tier_thresholds_are_configurable() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
 
-        let id = client.propose(&s.alice, &ProposalAction::SetAdmin);
-        client.sign(&s.bob, &id); // threshold reached
+        let contributor = Address::generate(&s.env);
+        client.register_contributor(
+            &contributor,
+            &soroban_sdk::String::from_str(&s.env, "threshold_dev"),
+        );
+        bump_reputation(&s, &contributor, 20);
+        assert_eq!(client.get_tier(&contributor), ContributorTier::Builder);
 
-        let new_admin = Address::generate(&s.env);
-        client.set_admin(&s.alice, &id, &new_admin);
+        let thresholds = TierThresholds {
+            builder: 25,
+            architect: 200,
+            core: 1_000,
+        };
+        let id = client.propose(&s.alice, &ProposalAction::SetTierThresholds);
+        client.sign(&s.bob, &id);
+        client.set_tier_thresholds(&s.alice, &id, &thresholds);
 
-        // Proposal must be Executed — replay is now impossible.
-        assert_eq!(client.get_proposal(&id).status, ProposalStatus::Executed);
+        assert_eq!(client.get_tier_thresholds(), thresholds);
+        assert_eq!(client.get_tier(&contributor), ContributorTier::Novice);
     }
 
     #[test]
-    fn test_upgrade_approved_before_execution() {
-        // Verifies the proposal reaches Approved status when threshold is met,
-        // which is the pre-condition for upgrade execution.
+    fn test_non_increasing_tier_thresholds_rejected() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
 
-        let id = client.propose(&s.alice, &ProposalAction::Upgrade);
-        assert_eq!(client.get_proposal(&id).status, ProposalStatus::Pending);
-
-        let status = client.sign(&s.bob, &id);
-        assert_eq!(status, ProposalStatus::Approved);
-        assert_eq!(client.get_proposal(&id).status, ProposalStatus::Approved);
+        let id = client.propose(&s.alice, &ProposalAction::SetTierThresholds);
+        client.sign(&s.bob, &id);
+        let result = client.try_set_tier_thresholds(
+            &s.alice,
+            &id,
+            &TierThresholds {
+                builder: 10,
+                architect: 10,
+                core: 100,
+            },
+        );
+        assert_eq!(result, Err(Ok(ContributorError::InvalidTierThresholds)));
     }
 
     #[test]
-    fn test_execute_below_threshold_fails() {
+    fn test_has_permission_by_tier() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let review = Symbol::new(&s.env, PERMISSION_REVIEW);
 
-        let id = client.propose(&s.bob, &ProposalAction::Upgrade);
-        let wasm_hash = BytesN::from_array(&s.env, &[1u8; 32]);
-        assert!(client.try_upgrade(&s.bob, &id, &wasm_hash).is_err());
+        let contributor = Address::generate(&s.env);
+        client.register_contributor(
+            &contributor,
+            &soroban_sdk::String::from_str(&s.env, "reviewer_dev"),
+        );
+
+        // No rule configured: nobody holds the permission.
+        assert!(!client.has_permission(&contributor, &review));
+
+        set_permission_rule(
+            &s,
+            &review,
+            Some(PermissionRule {
+                min_tier: ContributorTier::Builder,
+                badges: Vec::new(&s.env),
+            }),
+        );
+        assert!(!client.has_permission(&contributor, &review));
+
+        bump_reputation(&s, &contributor, 10);
+        assert!(client.has_permission(&contributor, &review));
+
+        // Unregistered addresses never hold a permission.
+        let outsider = Address::generate(&s.env);
+        assert!(!client.has_permission(&outsider, &review));
+
+        set_permission_rule(&s, &review, None);
+        assert!(!client.has_permission(&contributor, &review));
     }
 
     #[test]
-    fn test_wrong_action_type_rejected() {
+    fn test_has_permission_by_badge() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dispute = Symbol::new(&s.env, PERMISSION_DISPUTE_MILESTONE);
 
-        let id = client.propose(&s.alice, &ProposalAction::Upgrade);
+        let contributor = Address::generate(&s.env);
+        client.register_contributor(
+            &contributor,
+            &soroban_sdk::String::from_str(&s.env, "auditor_dev"),
+        );
+
+        let mut badges = Vec::new(&s.env);
+        badges.push_back(4u32);
+        set_permission_rule(
+            &s,
+            &dispute,
+            Some(PermissionRule {
+                min_tier: ContributorTier::Core,
+                badges,
+            }),
+        );
+        assert!(!client.has_permission(&contributor, &dispute));
+
+        let id = client.propose(&s.alice, &ProposalAction::GrantBadge);
         client.sign(&s.bob, &id);
+        client.grant_badge(&s.alice, &id, &contributor, &4u32);
 
-        let new_admin = Address::generate(&s.env);
-        assert!(client.try_set_admin(&s.alice, &id, &new_admin).is_err());
+        assert!(client.has_permission(&contributor, &dispute));
+    }
+
+    // ── Badge registry ────────────────────────────────────────
+
+    fn define_test_badge(s: &Setup, bonus: u64, validity_secs: u64, transferable: bool) -> u32 {
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let id = client.propose(&s.alice, &ProposalAction::DefineBadge);
+        client.sign(&s.bob, &id);
+        client.define_badge(
+            &s.alice,
+            &id,
+            &BadgeDefinition {
+                name: soroban_sdk::String::from_str(&s.env, "Mentor"),
+                metadata_uri: soroban_sdk::String::from_str(&s.env, "ipfs://mentor"),
+                reputation_bonus: bonus,
+                validity_secs,
+                transferable,
+                active: true,
+            },
+        )
+    }
+
+    fn grant(s: &Setup, contributor: &Address, badge_id: u32) {
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let id = client.propose(&s.alice, &ProposalAction::GrantBadge);
+        client.sign(&s.bob, &id);
+        client.grant_badge(&s.alice, &id, contributor, &badge_id);
     }
 
     #[test]
-    fn test_replay_blocked_after_execution() {
+    fn test_define_badge_assigns_ids_after_legacy_range() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
 
-        let id = client.propose(&s.alice, &ProposalAction::Upgrade);
-        client.sign(&s.bob, &id);
-        let wasm_hash = BytesN::from_array(&s.env, &[1u8; 32]);
-        let _ = client.try_upgrade(&s.alice, &id, &wasm_hash);
+        let first = define_test_badge(&s, 0, 0, false);
+        let second = define_test_badge(&s, 0, 0, false);
+        assert_eq!(first, badges::LEGACY_BADGE_COUNT + 1);
+        assert_eq!(second, first + 1);
 
-        assert!(client.try_upgrade(&s.alice, &id, &wasm_hash).is_err());
+        let definition = client.get_badge_definition(&first);
+        assert_eq!(
+            definition.metadata_uri,
+            soroban_sdk::String::from_str(&s.env, "ipfs://mentor")
+        );
+        assert_eq!(
+            client.get_badge_definition(&1).name,
+            soroban_sdk::String::from_str(&s.env, "Early Adopter")
+        );
+        assert_eq!(
+            client.try_get_badge_definition(&99),
+            Err(Ok(ContributorError::BadgeNotFound))
+        );
     }
 
-    // ── update_reputation ─────────────────────────────────────
-
     #[test]
-    fn test_update_reputation_requires_multisig() {
+    fn test_grant_records_issuer_and_bonus_and_revoke_takes_it_back() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
 
         let contributor = Address::generate(&s.env);
-        let handle = soroban_sdk::String::from_str(&s.env, "dev_handle");
-        client.register_contributor(&contributor, &handle);
+        client.register_contributor(
+            &contributor,
+            &soroban_sdk::String::from_str(&s.env, "mentor_dev"),
+        );
+        let badge_id = define_test_badge(&s, 15, 0, false);
 
-        let fake_id = 999u64;
-        assert!(client
-            .try_update_reputation(&s.alice, &fake_id, &contributor, &10i64)
-            .is_err());
+        s.env.ledger().set_timestamp(5_000);
+        grant(&s, &contributor, badge_id);
+
+        let record = client.get_badges(&contributor).get(0).unwrap();
+        assert_eq!(record.badge_id, badge_id);
+        assert_eq!(record.issuer, s.alice);
+        assert_eq!(record.granted_at, 5_000);
+        assert_eq!(record.expires_at, 0);
+        assert_eq!(client.get_reputation(&contributor), 15);
+
+        // Re-granting a held badge does not stack the bonus.
+        grant(&s, &contributor, badge_id);
+        assert_eq!(client.get_reputation(&contributor), 15);
+
+        let id = client.propose(&s.alice, &ProposalAction::RevokeBadge);
+        client.sign(&s.bob, &id);
+        client.revoke_badge(&s.alice, &id, &contributor, &badge_id);
+        assert_eq!(client.get_badges(&contributor).len(), 0);
+        assert_eq!(client.get_reputation(&contributor), 0);
     }
 
     #[test]
-    fn test_update_reputation_succeeds_with_approval() {
+    fn test_badges_expire() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
 
         let contributor = Address::generate(&s.env);
-        let handle = soroban_sdk::String::from_str(&s.env, "dev_handle");
-        client.register_contributor(&contributor, &handle);
+        client.register_contributor(
+            &contributor,
+            &soroban_sdk::String::from_str(&s.env, "expiring_dev"),
+        );
+        let badge_id = define_test_badge(&s, 0, 100, false);
 
-        let id = client.propose(&s.alice, &ProposalAction::UpdateReputation);
-        client.sign(&s.bob, &id);
+        s.env.ledger().set_timestamp(1_000);
+        grant(&s, &contributor, badge_id);
+        assert_eq!(
+            client.get_badges(&contributor).get(0).unwrap().expires_at,
+            1_100
+        );
 
-        client.update_reputation(&s.alice, &id, &contributor, &50i64);
+        s.env.ledger().set_timestamp(1_100);
+        assert_eq!(client.get_badges(&contributor).len(), 0);
 
-        assert_eq!(client.get_reputation(&contributor), 50);
+        // An expired badge can be granted again.
+        grant(&s, &contributor, badge_id);
+        assert_eq!(
+            client.get_badges(&contributor).get(0).unwrap().expires_at,
+            1_200
+        );
     }
 
-    // ── Cancel & expire ───────────────────────────────────────
+    #[test]
+    fn test_renewing_expired_badge_does_not_stack_bonus() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let contributor = Address::generate(&s.env);
+        client.register_contributor(
+            &contributor,
+            &soroban_sdk::String::from_str(&s.env, "renewing_dev"),
+        );
+        let badge_id = define_test_badge(&s, 25, 100, false);
+
+        s.env.ledger().set_timestamp(1_000);
+        grant(&s, &contributor, badge_id);
+        assert_eq!(client.get_reputation(&contributor), 25);
+
+        for renewal in 1..=3u64 {
+            s.env.ledger().set_timestamp(1_000 + renewal * 100);
+            grant(&s, &contributor, badge_id);
+            assert_eq!(client.get_reputation(&contributor), 25);
+        }
+
+        // Revoking after expiry still takes the one bonus back.
+        s.env.ledger().set_timestamp(2_000);
+        let id = client.propose(&s.alice, &ProposalAction::RevokeBadge);
+        client.sign(&s.bob, &id);
+        client.revoke_badge(&s.alice, &id, &contributor, &badge_id);
+        assert_eq!(client.get_reputation(&contributor), 0);
+    }
 
     #[test]
-    fn test_cancel_blocks_execution() {
+    fn test_inactive_badge_cannot_be_granted() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
 
-        let id = client.propose(&s.alice, &ProposalAction::Upgrade);
+        let contributor = Address::generate(&s.env);
+        client.register_contributor(
+            &contributor,
+            &soroban_sdk::String::from_str(&s.env, "retired_dev"),
+        );
+
+        let mut definition = client.get_badge_definition(&2);
+        definition.active = false;
+        let id = client.propose(&s.alice, &ProposalAction::DefineBadge);
         client.sign(&s.bob, &id);
-        client.cancel_proposal(&s.alice, &id);
+        client.update_badge(&s.alice, &id, &2, &definition);
 
-        let wasm_hash = BytesN::from_array(&s.env, &[1u8; 32]);
-        assert!(client.try_upgrade(&s.alice, &id, &wasm_hash).is_err());
+        let id = client.propose(&s.alice, &ProposalAction::GrantBadge);
+        client.sign(&s.bob, &id);
+        assert_eq!(
+            client.try_grant_badge(&s.alice, &id, &contributor, &2),
+            Err(Ok(ContributorError::BadgeInactive))
+        );
     }
 
     #[test]
-    fn test_expire_after_ttl() {
+    fn test_transfer_badge_respects_transferable_flag() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
 
-        s.env.ledger().set_timestamp(1_000_000);
-        let id = client.propose(&s.alice, &ProposalAction::Upgrade);
+        let from = Address::generate(&s.env);
+        let to = Address::generate(&s.env);
+        client.register_contributor(&from, &soroban_sdk::String::from_str(&s.env, "from_dev"));
+        client.register_contributor(&to, &soroban_sdk::String::from_str(&s.env, "to_dev"));
 
-        s.env
-            .ledger()
-            .set_timestamp(1_000_000 + multisig::PROPOSAL_TTL_SECS + 1);
+        let soulbound = define_test_badge(&s, 0, 0, false);
+        let movable = define_test_badge(&s, 0, 0, true);
+        grant(&s, &from, soulbound);
+        grant(&s, &from, movable);
 
-        client.expire_proposal(&id);
+        assert_eq!(
+            client.try_transfer_badge(&from, &to, &soulbound),
+            Err(Ok(ContributorError::BadgeNotTransferable))
+        );
 
-        let proposal = client.get_proposal(&id);
-        assert_eq!(proposal.status, ProposalStatus::Expired);
+        let token_id = client
+            .get_badges(&from)
+            .iter()
+            .find(|record| record.badge_id == movable)
+            .unwrap()
+            .token_id;
+        assert_eq!(client.balance_of(&from), 2);
+        assert_eq!(client.balance_of(&to), 0);
+
+        client.transfer_badge(&from, &to, &movable);
+        assert_eq!(client.get_badges(&from).len(), 1);
+        let moved = client.get_badges(&to).get(0).unwrap();
+        assert_eq!(moved.badge_id, movable);
+        assert_eq!(moved.issuer, s.alice);
+
+        // The certificate follows the grant.
+        assert_eq!(moved.token_id, token_id);
+        assert_eq!(client.owner_of(&token_id), to);
+        assert_eq!(client.balance_of(&from), 1);
+        assert_eq!(client.balance_of(&to), 1);
+    }
+
+    fn set_badge_bonus(s: &Setup, badge_id: u32, bonus: u64) {
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let mut definition = client.get_badge_definition(&badge_id);
+        definition.reputation_bonus = bonus;
+        let id = client.propose(&s.alice, &ProposalAction::DefineBadge);
+        client.sign(&s.bob, &id);
+        client.update_badge(&s.alice, &id, &badge_id, &definition);
+    }
+
+    fn revoke(s: &Setup, contributor: &Address, badge_id: u32) {
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let id = client.propose(&s.alice, &ProposalAction::RevokeBadge);
+        client.sign(&s.bob, &id);
+        client.revoke_badge(&s.alice, &id, contributor, &badge_id);
     }
 
     #[test]
-    fn test_expire_before_ttl_fails() {
+    fn test_revoke_takes_back_the_bonus_the_grant_awarded() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let contributor = register_with_reputation(&s, "bonus_dev", 100);
+        let badge_id = define_test_badge(&s, 15, 0, false);
 
-        s.env.ledger().set_timestamp(1_000_000);
-        let id = client.propose(&s.alice, &ProposalAction::Upgrade);
-        s.env.ledger().set_timestamp(1_000_000 + 3600);
-
-        assert!(client.try_expire_proposal(&id).is_err());
+        grant(&s, &contributor, badge_id);
+        assert_eq!(client.get_reputation(&contributor), 115);
+        set_badge_bonus(&s, badge_id, 40);
+        revoke(&s, &contributor, badge_id);
+        assert_eq!(client.get_reputation(&contributor), 100);
     }
 
-    // ── Full integration flow ─────────────────────────────────
-
     #[test]
-    fn test_full_proposal_flow() {
-        // Full lifecycle: propose → sign → sign → execute → replay blocked.
-        // Uses SetAdmin as the action since it doesn't require the deployer.
+    fn test_badge_bonus_never_moves_with_a_transfer() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let from = register_with_reputation(&s, "bonus_from", 100);
+        let to = register_with_reputation(&s, "bonus_to", 100);
+        let badge_id = define_test_badge(&s, 10, 100, true);
+
+        // A grant that awarded a bonus stays with its holder.
+        s.env.ledger().set_timestamp(1_000);
+        grant(&s, &to, badge_id);
+        assert_eq!(client.get_reputation(&to), 110);
+        assert_eq!(
+            client.try_transfer_badge(&to, &from, &badge_id),
+            Err(Ok(ContributorError::BadgeNotTransferable))
+        );
 
-        // 1. Alice proposes (w=2, threshold=3) → Pending
-        let id = client.propose(&s.alice, &ProposalAction::SetAdmin);
-        assert_eq!(client.get_proposal(&id).status, ProposalStatus::Pending);
-
-        // 2. Bob signs (w=1) → total=3=threshold → Approved
-        let status = client.sign(&s.bob, &id);
-        assert_eq!(status, ProposalStatus::Approved);
-
-        // 3. Carol signs over threshold — still Approved, not a state change
-        let status = client.sign(&s.carol, &id);
-        assert_eq!(status, ProposalStatus::Approved);
-
-        // 4. Alice executes → Executed
-        let new_admin = Address::generate(&s.env);
-        client.set_admin(&s.alice, &id, &new_admin);
-        assert_eq!(client.get_proposal(&id).status, ProposalStatus::Executed);
-
-        // 5. Replay attempt fails
-        let new_admin2 = Address::generate(&s.env);
-        assert!(client.try_set_admin(&s.alice, &id, &new_admin2).is_err());
+        // A bonus-free grant may replace the recipient's expired one, which
+        // keeps the recipient's bonus revocable.
+        set_badge_bonus(&s, badge_id, 0);
+        s.env.ledger().set_timestamp(1_150);
+        grant(&s, &from, badge_id);
+        s.env.ledger().set_timestamp(1_200);
+        client.transfer_badge(&from, &to, &badge_id);
+        assert_eq!(client.get_reputation(&from), 100);
+        revoke(&s, &to, badge_id);
+        assert_eq!(client.get_reputation(&to), 100);
     }
 
-    // ── TTL / storage-rent tests ──────────────────────────────
-
-    /// Verify that a contributor entry remains accessible after a simulated
-    /// ledger advance (TTL bump keeps the entry alive).
     #[test]
-    fn test_contributor_entry_accessible_after_ledger_advance() {
+    fn test_legacy_enum_badges_are_migrated() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
 
         let contributor = Address::generate(&s.env);
-        let handle = soroban_sdk::String::from_str(&s.env, "ttl_dev");
-        client.register_contributor(&contributor, &handle);
+        client.register_contributor(
+            &contributor,
+            &soroban_sdk::String::from_str(&s.env, "legacy_dev"),
+        );
 
-        // Advance the ledger sequence significantly (simulates time passing).
-        s.env.ledger().set_sequence_number(200_000);
+        // Pre-registry storage: Vec<Badge> with EarlyAdopter and SecurityAuditor.
+        s.env.as_contract(&s.contract, || {
+            let mut legacy = Vec::new(&s.env);
+            legacy.push_back(1u32);
+            legacy.push_back(4u32);
+            s.env
+                .storage()
+                .persistent()
+                .set(&DataKey::Badges(contributor.clone()), &legacy);
+        });
 
-        // The entry must still be readable — TTL bump on write keeps it alive.
-        let data = client.get_contributor(&contributor);
-        assert_eq!(data.github_handle, handle);
+        assert_eq!(client.migrate_badges(&contributor), 2);
+        assert_eq!(client.migrate_badges(&contributor), 0);
+
+        let badges = client.get_badges(&contributor);
+        assert_eq!(badges.len(), 2);
+        assert_eq!(badges.get(0).unwrap().badge_id, 1);
+        assert_eq!(
+            badges.get(1).unwrap().name,
+            soroban_sdk::String::from_str(&s.env, "Security Auditor")
+        );
+        s.env.as_contract(&s.contract, || {
+            assert!(!s
+                .env
+                .storage()
+                .persistent()
+                .has(&DataKey::Badges(contributor.clone())));
+        });
     }
 
-    /// Verify that TTL is extended after a write (registration) and a read
-    /// (get_contributor) by confirming the entry survives a large ledger jump.
+    // ── Badge certificates ────────────────────────────────────
+
     #[test]
-    fn test_ttl_extended_after_read_write() {
+    fn test_badge_certificate_follows_grant_lifecycle() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
 
-        let contributor = Address::generate(&s.env);
-        let handle = soroban_sdk::String::from_str(&s.env, "ttl_rw_dev");
-        client.register_contributor(&contributor, &handle);
+        let holder = Address::generate(&s.env);
+        let other = Address::generate(&s.env);
+        client.register_contributor(&holder, &soroban_sdk::String::from_str(&s.env, "holder"));
+        client.register_contributor(&other, &soroban_sdk::String::from_str(&s.env, "other"));
 
-        // Simulate a large ledger advance.
-        s.env.ledger().set_sequence_number(100_001);
+        let badge_id = define_test_badge(&s, 0, 0, true);
+        grant(&s, &holder, badge_id);
+        let token_id = client.get_badges(&holder).get(0).unwrap().token_id;
 
-        // Read triggers another TTL bump; entry must still be accessible.
-        let data = client.get_contributor(&contributor);
-        assert_eq!(data.github_handle, handle);
+        assert_eq!(client.owner_of(&token_id), holder);
+        assert_eq!(
+            client.token_uri(&token_id),
+            soroban_sdk::String::from_str(&s.env, "ipfs://mentor")
+        );
+        assert_eq!(client.balance_of(&holder), 1);
+        assert_eq!(
+            client.symbol(),
+            soroban_sdk::String::from_str(&s.env, "LPBADGE")
+        );
 
-        // Advance again — the read-triggered bump should keep it alive.
-        s.env.ledger().set_sequence_number(200_002);
-        let data2 = client.get_contributor(&contributor);
-        assert_eq!(data2.github_handle, handle);
+        client.transfer_badge(&holder, &other, &badge_id);
+        assert_eq!(client.owner_of(&token_id), other);
+        assert_eq!(client.balance_of(&holder), 0);
+        assert_eq!(client.balance_of(&other), 1);
+
+        let id = client.propose(&s.alice, &ProposalAction::RevokeBadge);
+        client.sign(&s.bob, &id);
+        client.revoke_badge(&s.alice, &id, &other, &badge_id);
+        assert_eq!(
+            client.try_owner_of(&token_id),
+            Err(Ok(ContributorError::BadgeTokenNotFound))
+        );
+        assert_eq!(
+            client.try_token_uri(&token_id),
+            Err(Ok(ContributorError::BadgeTokenNotFound))
+        );
+        assert_eq!(client.balance_of(&other), 0);
+
+        // A fresh grant mints a new certificate; ids are never reused.
+        grant(&s, &other, badge_id);
+        let reissued = client.get_badges(&other).get(0).unwrap().token_id;
+        assert!(reissued > token_id);
     }
 
-    /// Verify that deregistering a contributor removes all three storage keys
-    /// (Contributor, GitHubIndex, RegistrationNonce) — no orphaned entries.
     #[test]
-    fn test_deregister_removes_all_keys() {
+    fn test_expired_badge_certificate_is_not_owned() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
 
-        let contributor = Address::generate(&s.env);
-        let handle = soroban_sdk::String::from_str(&s.env, "deregister_dev");
-        let signature = soroban_sdk::Bytes::from_slice(&s.env, &[1u8; 64]);
+        let holder = Address::generate(&s.env);
+        client.register_contributor(&holder, &soroban_sdk::String::from_str(&s.env, "holder"));
+        let badge_id = define_test_badge(&s, 0, 50, false);
 
-        // Use gasless registration so a nonce entry is also created.
-        client.register_contributor_with_sig(&handle, &contributor, &signature);
-        assert_eq!(client.get_registration_nonce(&contributor), 1);
+        s.env.ledger().set_timestamp(1_000);
+        grant(&s, &holder, badge_id);
+        let token_id = client.get_badges(&holder).get(0).unwrap().token_id;
+        assert_eq!(client.owner_of(&token_id), holder);
 
-        // Deregister — all three keys must be removed.
-        client.deregister_contributor(&contributor);
+        s.env.ledger().set_timestamp(1_050);
+        assert_eq!(
+            client.try_owner_of(&token_id),
+            Err(Ok(ContributorError::BadgeTokenNotFound))
+        );
+        assert_eq!(client.balance_of(&holder), 0);
+    }
 
-        // Contributor entry gone.
-        assert!(client.try_get_contributor(&contributor).is_err());
-        // GitHub index entry gone — a new address can now claim the same handle.
-        let other = Address::generate(&s.env);
-        let sig2 = soroban_sdk::Bytes::from_slice(&s.env, &[2u8; 64]);
-        client.register_contributor_with_sig(&handle, &other, &sig2);
-        let data = client.get_contributor(&other);
-        assert_eq!(data.github_handle, handle);
+    // ── Notification scoring ──────────────────────────────────
+
+    /// Prices every token at 2 base units per unit.
+    #[soroban_sdk::contract]
+    pub struct MockPricingAdapter;
+
+    #[soroban_sdk::contractimpl]
+    impl MockPricingAdapter {
+        pub fn normalize_amount(_env: Env, _asset: Address, amount: i128) -> i128 {
+            amount * 2
+        }
     }
 
-    // ── Badges & Tiers ────────────────────────────────────────
+    fn configure_notifications(
+        s: &Setup,
+        source: &Address,
+        event_type: &str,
+        rule: NotificationRule,
+        config: NotificationConfig,
+    ) {
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        for step in 0..3 {
+            let id = client.propose(&s.alice, &ProposalAction::ConfigureNotifications);
+            client.sign(&s.bob, &id);
+            match step {
+                0 => client.set_trusted_source(&s.alice, &id, source, &true),
+                1 => client.set_notification_rule(
+                    &s.alice,
+                    &id,
+                    &Symbol::new(&s.env, event_type),
+                    &Some(rule.clone()),
+                ),
+                _ => client.set_notification_config(&s.alice, &id, &config),
+            }
+        }
+    }
+
+    fn notify(s: &Setup, source: &Address, event_type: &str, data: Bytes) {
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        client.on_notify(&Notification {
+            source: source.clone(),
+            event_type: Symbol::new(&s.env, event_type),
+            data,
+        });
+    }
 
     #[test]
-    fn test_tier_calculation() {
+    fn test_notifications_require_trusted_source_and_rule() {
+        use soroban_sdk::xdr::ToXdr;
+
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
 
-        let contributor = Address::generate(&s.env);
-        let handle = soroban_sdk::String::from_str(&s.env, "tier_dev");
-        client.register_contributor(&contributor, &handle);
+        let user = Address::generate(&s.env);
+        let vault = Address::generate(&s.env);
+        let spoofer = Address::generate(&s.env);
+        let token = Address::generate(&s.env);
+        client.register_contributor(&user, &soroban_sdk::String::from_str(&s.env, "depositor"));
+
+        let payload = (user.clone(), 1u64, 500i128, token).to_xdr(&s.env);
+
+        // Nothing is trusted or configured yet.
+        notify(&s, &vault, EVENT_DEPOSIT, payload.clone());
+        assert_eq!(client.get_reputation(&user), 0);
+
+        configure_notifications(
+            &s,
+            &vault,
+            EVENT_DEPOSIT,
+            NotificationRule {
+                curve: notifications::ScoringCurve::Flat,
+                points: 3,
+                unit: 0,
+                max_points: 0,
+            },
+            notifications::default_config(),
+        );
+        assert!(client.is_trusted_source(&vault));
 
-        assert_eq!(client.get_tier(&contributor), ContributorTier::Novice);
+        notify(&s, &spoofer, EVENT_DEPOSIT, payload.clone());
+        assert_eq!(client.get_reputation(&user), 0);
 
-        let id = client.propose(&s.alice, &ProposalAction::UpdateReputation);
-        client.sign(&s.bob, &id);
-        client.update_reputation(&s.alice, &id, &contributor, &20i64);
-        assert_eq!(client.get_tier(&contributor), ContributorTier::Builder);
+        notify(&s, &vault, EVENT_WITHDRAW, payload.clone());
+        assert_eq!(client.get_reputation(&user), 0);
 
-        let id2 = client.propose(&s.alice, &ProposalAction::UpdateReputation);
-        client.sign(&s.bob, &id2);
-        client.update_reputation(&s.alice, &id2, &contributor, &50i64);
-        assert_eq!(client.get_tier(&contributor), ContributorTier::Architect);
+        notify(&s, &vault, EVENT_DEPOSIT, payload);
+        assert_eq!(client.get_reputation(&user), 3);
 
-        let id3 = client.propose(&s.alice, &ProposalAction::UpdateReputation);
-        client.sign(&s.bob, &id3);
-        client.update_reputation(&s.alice, &id3, &contributor, &50i64);
-        assert_eq!(client.get_tier(&contributor), ContributorTier::Core);
+        // Legacy three-field deposit payloads still decode.
+        notify(
+            &s,
+            &vault,
+            EVENT_DEPOSIT,
+            (user.clone(), 1u64, 500i128).to_xdr(&s.env),
+        );
+        assert_eq!(client.get_reputation(&user), 6);
+        assert_eq!(
+            client.get_reputation_history(&user).last().unwrap().reason,
+            soroban_sdk::String::from_str(&s.env, "deposit")
+        );
     }
 
     #[test]
-    fn test_grant_and_revoke_badge() {
+    fn test_notification_log_curve_and_epoch_cap() {
+        use soroban_sdk::xdr::ToXdr;
+
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
 
-        let contributor = Address::generate(&s.env);
-        let handle = soroban_sdk::String::from_str(&s.env, "badge_dev");
-        client.register_contributor(&contributor, &handle);
+        let user = Address::generate(&s.env);
+        let vault = Address::generate(&s.env);
+        let token = Address::generate(&s.env);
+        let adapter = s.env.register(MockPricingAdapter, ());
+        client.register_contributor(&user, &soroban_sdk::String::from_str(&s.env, "whale"));
+
+        configure_notifications(
+            &s,
+            &vault,
+            EVENT_DEPOSIT,
+            NotificationRule {
+                curve: notifications::ScoringCurve::Log2,
+                points: 2,
+                unit: 100,
+                max_points: 0,
+            },
+            NotificationConfig {
+                pricing_adapter: Some(adapter),
+                epoch_secs: 1_000,
+                epoch_cap: 10,
+            },
+        );
 
-        assert_eq!(client.get_badges(&contributor).len(), 0);
+        s.env.ledger().set_timestamp(1_000);
+        let deposit = |amount: i128| (user.clone(), 1u64, amount, token.clone()).to_xdr(&s.env);
 
-        let id = client.propose(&s.alice, &ProposalAction::GrantBadge);
-        client.sign(&s.bob, &id);
-        client.grant_badge(&s.alice, &id, &contributor, &Badge::EarlyAdopter);
+        // 40 raw → 80 normalised: below one unit, scores nothing.
+        notify(&s, &vault, EVENT_DEPOSIT, deposit(40));
+        assert_eq!(client.get_reputation(&user), 0);
 
-        let badges = client.get_badges(&contributor);
-        assert_eq!(badges.len(), 1);
-        assert!(badges.contains(Badge::EarlyAdopter));
+        // 400 raw → 800 normalised = 8 units → (1 + log2 8) * 2 = 8 points.
+        notify(&s, &vault, EVENT_DEPOSIT, deposit(400));
+        assert_eq!(client.get_reputation(&user), 8);
 
-        let id2 = client.propose(&s.alice, &ProposalAction::RevokeBadge);
-        client.sign(&s.bob, &id2);
-        client.revoke_badge(&s.alice, &id2, &contributor, &Badge::EarlyAdopter);
+        // Only 2 points left in this epoch.
+        notify(&s, &vault, EVENT_DEPOSIT, deposit(400));
+        assert_eq!(client.get_reputation(&user), 10);
+        notify(&s, &vault, EVENT_DEPOSIT, deposit(400));
+        assert_eq!(client.get_reputation(&user), 10);
+        assert_eq!(client.get_notification_usage(&user).unwrap().earned, 10);
 
-        assert_eq!(client.get_badges(&contributor).len(), 0);
+        s.env.ledger().set_timestamp(2_000);
+        notify(&s, &vault, EVENT_DEPOSIT, deposit(50));
+        assert_eq!(client.get_reputation(&user), 12);
     }
 
-    // ── Reputation penalty hooks (issue #691) ─────────────────
-
     #[test]
-    fn test_apply_penalty_deducts_reputation() {
+    fn test_negative_notification_rule_deducts() {
+        use soroban_sdk::xdr::ToXdr;
+
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
 
-        let contributor = Address::generate(&s.env);
-        let handle = soroban_sdk::String::from_str(&s.env, "penalty_dev");
-        client.register_contributor(&contributor, &handle);
-
-        // Give the contributor some reputation first.
-        let id = client.propose(&s.alice, &ProposalAction::UpdateReputation);
-        client.sign(&s.bob, &id);
-        client.update_reputation(&s.alice, &id, &contributor, &100i64);
-        assert_eq!(client.get_reputation(&contributor), 100);
-
-        // Apply a penalty via multisig.
-        let pid = client.propose(&s.alice, &ProposalAction::ApplyPenalty);
-        client.sign(&s.bob, &pid);
-        let reason = soroban_sdk::String::from_str(&s.env, "fraudulent milestone");
-        client.apply_reputation_penalty(
-            &s.alice,
-            &pid,
-            &contributor,
-            &1u64,
-            &PenaltySeverity::Moderate,
-            &30u64,
-            &reason,
+        let user = Address::generate(&s.env);
+        let vault = Address::generate(&s.env);
+        let token = Address::generate(&s.env);
+        client.register_contributor(&user, &soroban_sdk::String::from_str(&s.env, "refunded"));
+        bump_reputation(&s, &user, 20);
+
+        configure_notifications(
+            &s,
+            &vault,
+            EVENT_REFUND,
+            NotificationRule {
+                curve: notifications::ScoringCurve::Linear,
+                points: -1,
+                unit: 100,
+                max_points: 5,
+            },
+            NotificationConfig {
+                pricing_adapter: None,
+                epoch_secs: 1_000,
+                epoch_cap: 1,
+            },
         );
 
-        assert_eq!(client.get_reputation(&contributor), 70);
+        // 900 / 100 = 9 steps, capped at 5; deductions ignore the epoch cap.
+        notify(
+            &s,
+            &vault,
+            EVENT_REFUND,
+            (user.clone(), 1u64, 900i128, token).to_xdr(&s.env),
+        );
+        assert_eq!(client.get_reputation(&user), 15);
     }
 
     #[test]
-    fn test_apply_penalty_floors_at_zero() {
+    fn test_invalid_notification_rule_rejected() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
 
-        let contributor = Address::generate(&s.env);
-        let handle = soroban_sdk::String::from_str(&s.env, "floor_dev");
-        client.register_contributor(&contributor, &handle);
-
-        // Reputation is 0; penalty should not underflow.
-        let pid = client.propose(&s.alice, &ProposalAction::ApplyPenalty);
-        client.sign(&s.bob, &pid);
-        let reason = soroban_sdk::String::from_str(&s.env, "severe violation");
-        client.apply_reputation_penalty(
-            &s.alice,
-            &pid,
-            &contributor,
-            &2u64,
-            &PenaltySeverity::Severe,
-            &999u64,
-            &reason,
+        let id = client.propose(&s.alice, &ProposalAction::ConfigureNotifications);
+        client.sign(&s.bob, &id);
+        assert_eq!(
+            client.try_set_notification_rule(
+                &s.alice,
+                &id,
+                &Symbol::new(&s.env, EVENT_DEPOSIT),
+                &Some(NotificationRule {
+                    curve: notifications::ScoringCurve::Linear,
+                    points: 1,
+                    unit: 0,
+                    max_points: 0,
+                }),
+            ),
+            Err(Ok(ContributorError::InvalidNotificationRule))
         );
+    }
 
-        assert_eq!(client.get_reputation(&contributor), 0);
+    // ── GitHub attestation ────────────────────────────────────
+
+    fn verifier_key() -> ed25519_dalek::SigningKey {
+        ed25519_dalek::SigningKey::from_bytes(&[7u8; 32])
+    }
+
+    fn configure_verifier(s: &Setup) {
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let public = BytesN::from_array(&s.env, &verifier_key().verifying_key().to_bytes());
+        let id = client.propose(&s.alice, &ProposalAction::SetVerifier);
+        client.sign(&s.bob, &id);
+        client.set_verifier_key(&s.alice, &id, &Some(public));
+    }
+
+    fn attest(s: &Setup, address: &Address, handle: &str, expires_at: u64) -> BytesN<64> {
+        use ed25519_dalek::Signer as _;
+
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let message = client.get_attestation_message(
+            address,
+            &soroban_sdk::String::from_str(&s.env, handle),
+            &expires_at,
+        );
+        let mut buf = [0u8; 256];
+        let len = message.len() as usize;
+        message.copy_into_slice(&mut buf[..len]);
+        BytesN::from_array(&s.env, &verifier_key().sign(&buf[..len]).to_bytes())
     }
 
     #[test]
-    fn test_apply_penalty_stores_record() {
+    fn test_verify_github_reclaims_squatted_handle() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        configure_verifier(&s);
+
+        let squatter = Address::generate(&s.env);
+        let maintainer = Address::generate(&s.env);
+        let handle = soroban_sdk::String::from_str(&s.env, "maintainer");
+        client.register_contributor(&squatter, &handle);
+        client.register_contributor(
+            &maintainer,
+            &soroban_sdk::String::from_str(&s.env, "maintainer_tmp"),
+        );
+        assert!(!client.is_handle_verified(&squatter));
 
-        let contributor = Address::generate(&s.env);
-        let handle = soroban_sdk::String::from_str(&s.env, "record_dev");
-        client.register_contributor(&contributor, &handle);
+        let signature = attest(&s, &maintainer, "maintainer", 1_000);
+        client.verify_github(&maintainer, &handle, &1_000, &signature);
 
-        let pid = client.propose(&s.alice, &ProposalAction::ApplyPenalty);
-        client.sign(&s.bob, &pid);
-        let reason = soroban_sdk::String::from_str(&s.env, "dispute resolved against");
-        client.apply_reputation_penalty(
-            &s.alice,
-            &pid,
-            &contributor,
-            &42u64,
-            &PenaltySeverity::Minor,
-            &10u64,
-            &reason,
+        let data = client.get_contributor(&maintainer);
+        assert!(client.is_handle_verified(&maintainer));
+        assert_eq!(data.github_handle, handle);
+        assert_eq!(
+            client.get_contributor_by_github(&handle).address,
+            maintainer
         );
+        assert!(client
+            .try_get_contributor_by_github(&soroban_sdk::String::from_str(&s.env, "maintainer_tmp"))
+            .is_err());
 
-        let record = client.get_penalty_record(&contributor).unwrap();
-        assert_eq!(record.dispute_id, 42);
-        assert_eq!(record.severity, PenaltySeverity::Minor);
-        assert_eq!(record.points_deducted, 10);
+        let squatter_data = client.get_contributor(&squatter);
+        assert!(squatter_data.github_handle.is_empty());
+        assert!(!client.is_handle_verified(&squatter));
+
+        // Changing the handle drops the verified flag.
+        client.update_contributor(
+            &maintainer,
+            &soroban_sdk::String::from_str(&s.env, "renamed"),
+        );
+        assert!(!client.is_handle_verified(&maintainer));
     }
 
     #[test]
-    fn test_apply_penalty_requires_multisig() {
+    fn test_verify_github_rejects_bad_attestations() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
 
-        let contributor = Address::generate(&s.env);
-        let handle = soroban_sdk::String::from_str(&s.env, "noauth_dev");
-        client.register_contributor(&contributor, &handle);
+        let user = Address::generate(&s.env);
+        let other = Address::generate(&s.env);
+        let handle = soroban_sdk::String::from_str(&s.env, "user_gh");
+        client.register_contributor(&user, &handle);
 
-        let reason = soroban_sdk::String::from_str(&s.env, "test");
-        let fake_id = 999u64;
+        let signature = attest(&s, &user, "user_gh", 1_000);
+        assert_eq!(
+            client.try_verify_github(&user, &handle, &1_000, &signature),
+            Err(Ok(ContributorError::VerifierNotConfigured))
+        );
+
+        configure_verifier(&s);
+
+        // Signed for a different address.
+        let foreign = attest(&s, &other, "user_gh", 1_000);
         assert!(client
-            .try_apply_reputation_penalty(
-                &s.alice,
-                &fake_id,
-                &contributor,
-                &1u64,
-                &PenaltySeverity::Minor,
-                &5u64,
-                &reason,
-            )
+            .try_verify_github(&user, &handle, &1_000, &foreign)
             .is_err());
+
+        s.env.ledger().set_timestamp(1_000);
+        assert_eq!(
+            client.try_verify_github(&user, &handle, &1_000, &signature),
+            Err(Ok(ContributorError::AttestationExpired))
+        );
+        assert!(!client.is_handle_verified(&user));
     }
 
     #[test]
-    fn test_get_penalty_record_returns_none_when_absent() {
+    fn test_verified_handle_dispute_flow() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        configure_verifier(&s);
+
+        let holder = Address::generate(&s.env);
+        let claimant = Address::generate(&s.env);
+        let handle = soroban_sdk::String::from_str(&s.env, "renamed_org");
+        client.register_contributor(&holder, &handle);
+        client.register_contributor(
+            &claimant,
+            &soroban_sdk::String::from_str(&s.env, "new_owner"),
+        );
+        client.verify_github(
+            &holder,
+            &handle,
+            &1_000,
+            &attest(&s, &holder, "renamed_org", 1_000),
+        );
 
-        let contributor = Address::generate(&s.env);
-        let handle = soroban_sdk::String::from_str(&s.env, "no_penalty_dev");
-        client.register_contributor(&contributor, &handle);
+        let signature = attest(&s, &claimant, "renamed_org", 2_000);
+        assert_eq!(
+            client.try_verify_github(&claimant, &handle, &2_000, &signature),
+            Err(Ok(ContributorError::GitHubHandleVerified))
+        );
 
-        assert!(client.get_penalty_record(&contributor).is_none());
+        client.dispute_github_handle(&claimant, &handle, &2_000, &signature);
+        let dispute = client.get_handle_dispute(&handle).unwrap();
+        assert_eq!(dispute.holder, holder);
+        assert_eq!(
+            client.try_dispute_github_handle(&claimant, &handle, &2_000, &signature),
+            Err(Ok(ContributorError::HandleDisputeOpen))
+        );
+
+        let id = client.propose(&s.alice, &ProposalAction::ResolveHandleDispute);
+        client.sign(&s.bob, &id);
+        client.resolve_handle_dispute(&s.alice, &id, &handle, &true);
+
+        assert!(client.get_handle_dispute(&handle).is_none());
+        assert_eq!(client.get_contributor_by_github(&handle).address, claimant);
+        assert!(client.is_handle_verified(&claimant));
+        assert!(client.get_contributor(&holder).github_handle.is_empty());
     }
 }
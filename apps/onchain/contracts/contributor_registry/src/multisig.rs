@@ -42,9 +42,17 @@ pub enum ProposalAction {
     Upgrade,
     SetAdmin,
     UpdateReputation,
+    DefineBadge,
     GrantBadge,
     RevokeBadge,
     ApplyPenalty,
+    SetDecayConfig,
+    SetTierThresholds,
+    SetPermission,
+    ConfigureNotifications,
+    SetVerifier,
+    ResolveHandleDispute,
+    SetReregistrationCooldown,
 }
 
 #[contracttype]
@@ -0,0 +1,244 @@
+use soroban_sdk::xdr::FromXdr;
+use soroban_sdk::{
+    contracttype, vec, Address, Bytes, Env, IntoVal, String, Symbol, TryFromVal, Val, Vec,
+};
+
+use crate::errors::ContributorError;
+use crate::storage::{DataKey, LEDGER_BUMP, LEDGER_THRESHOLD};
+
+// ── Well-known event types ───────────────────────────────────
+//
+// Emitted by crowdfund_vault and (review outcomes) community curation.
+// Rules can be configured for any `Symbol`, so other source contracts may
+// introduce their own.
+
+pub const EVENT_DEPOSIT: &str = "deposit";
+pub const EVENT_WITHDRAW: &str = "withdraw";
+pub const EVENT_MILESTONE_APPROVED: &str = "milestone_approved";
+pub const EVENT_REFUND: &str = "refund";
+/// A curation vote matched the proposal's outcome; `amount` is the voting
+/// power used.
+pub const EVENT_REVIEW_ALIGNED: &str = "review_aligned";
+/// A curation vote went against the outcome; pair with a negative rule.
+pub const EVENT_REVIEW_MISALIGNED: &str = "review_misaligned";
+
+// ── Types ────────────────────────────────────────────────────
+
+/// How the (normalised) amount of a notification maps to scoring steps.
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum ScoringCurve {
+    /// One step per notification, regardless of amount.
+    Flat = 1,
+    /// One step per full `unit` of normalised amount.
+    Linear = 2,
+    /// `1 + floor(log2(amount / unit))` steps; nothing below one `unit`.
+    Log2 = 3,
+}
+
+/// Scoring for one notification event type.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NotificationRule {
+    pub curve: ScoringCurve,
+    /// Signed points per step; negative rules deduct (e.g. refunds).
+    pub points: i64,
+    /// Normalised amount per step.  Ignored by `Flat`.
+    pub unit: i128,
+    /// Cap on the absolute points of a single notification.  0 = uncapped.
+    pub max_points: u64,
+}
+
+/// Settings shared by all notification rules.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NotificationConfig {
+    /// pricing_adapter used to normalise amounts across tokens.  When unset,
+    /// raw token amounts are scored as-is.
+    pub pricing_adapter: Option<Address>,
+    /// Length of a capping epoch in seconds.
+    pub epoch_secs: u64,
+    /// Maximum positive points a contributor can earn from notifications per
+    /// epoch.  0 = uncapped.
+    pub epoch_cap: u64,
+}
+
+/// Positive notification points a contributor earned in `epoch`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EpochUsage {
+    pub epoch: u64,
+    pub earned: u64,
+}
+
+/// Decoded notification payload.
+pub(crate) struct Activity {
+    pub account: Address,
+    pub amount: i128,
+    /// `None` for the legacy `(account, project_id, amount)` payload.
+    pub token: Option<Address>,
+}
+
+// ── Internal helpers ─────────────────────────────────────────
+
+pub(crate) fn validate_rule(rule: &NotificationRule) -> Result<(), ContributorError> {
+    if rule.points == 0 || (rule.curve != ScoringCurve::Flat && rule.unit <= 0) {
+        return Err(ContributorError::InvalidNotificationRule);
+    }
+    Ok(())
+}
+
+pub(crate) fn validate_config(config: &NotificationConfig) -> Result<(), ContributorError> {
+    if config.epoch_cap > 0 && config.epoch_secs == 0 {
+        return Err(ContributorError::InvalidNotificationConfig);
+    }
+    Ok(())
+}
+
+pub(crate) fn default_config() -> NotificationConfig {
+    NotificationConfig {
+        pricing_adapter: None,
+        epoch_secs: 0,
+        epoch_cap: 0,
+    }
+}
+
+pub(crate) fn get_config(env: &Env) -> NotificationConfig {
+    env.storage()
+        .instance()
+        .get(&DataKey::NotificationConfig)
+        .unwrap_or(default_config())
+}
+
+pub(crate) fn is_trusted(env: &Env, source: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TrustedSource(source.clone()))
+        .unwrap_or(false)
+}
+
+pub(crate) fn get_rule(env: &Env, event_type: &Symbol) -> Option<NotificationRule> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::NotificationRule(event_type.clone()))
+}
+
+/// Human-readable reason recorded in the reputation history.
+pub(crate) fn reason(env: &Env, event_type: &Symbol) -> String {
+    for name in [
+        EVENT_DEPOSIT,
+        EVENT_WITHDRAW,
+        EVENT_MILESTONE_APPROVED,
+        EVENT_REFUND,
+        EVENT_REVIEW_ALIGNED,
+        EVENT_REVIEW_MISALIGNED,
+    ] {
+        if *event_type == Symbol::new(env, name) {
+            return String::from_str(env, name);
+        }
+    }
+    String::from_str(env, "notification")
+}
+
+/// Decode `(account, project_id, amount, token)`, also accepting the legacy
+/// `(account, project_id, amount)` deposit payload.
+pub(crate) fn decode(env: &Env, data: &Bytes) -> Option<Activity> {
+    // Decoding straight into a tuple of the wrong arity traps, so check the
+    // field count first.
+    let fields = Vec::<Val>::from_xdr(env, data).ok()?;
+    let token = match fields.len() {
+        3 => None,
+        4 => Some(Address::try_from_val(env, &fields.get(3)?).ok()?),
+        _ => return None,
+    };
+    Some(Activity {
+        account: Address::try_from_val(env, &fields.get(0)?).ok()?,
+        amount: i128::try_from_val(env, &fields.get(2)?).ok()?,
+        token,
+    })
+}
+
+/// Normalise `amount` through the configured pricing adapter.  Returns
+/// `None` when the adapter cannot price the token, so unpriced assets never
+/// earn reputation.
+pub(crate) fn normalize(
+    env: &Env,
+    config: &NotificationConfig,
+    token: &Option<Address>,
+    amount: i128,
+) -> Option<i128> {
+    let (adapter, token) = match (&config.pricing_adapter, token) {
+        (Some(adapter), Some(token)) => (adapter, token),
+        _ => return Some(amount),
+    };
+    match env.try_invoke_contract::<i128, soroban_sdk::Error>(
+        adapter,
+        &Symbol::new(env, "normalize_amount"),
+        vec![env, token.into_val(env), amount.into_val(env)],
+    ) {
+        Ok(Ok(normalized)) => Some(normalized),
+        _ => None,
+    }
+}
+
+/// Signed points for a notification of `normalized` value under `rule`.
+pub(crate) fn score(rule: &NotificationRule, normalized: i128) -> i64 {
+    let steps: i128 = match rule.curve {
+        ScoringCurve::Flat => 1,
+        ScoringCurve::Linear => normalized.max(0) / rule.unit,
+        ScoringCurve::Log2 => {
+            let units = normalized.max(0) / rule.unit;
+            if units == 0 {
+                0
+            } else {
+                units.ilog2() as i128 + 1
+            }
+        }
+    };
+    let mut points = steps.saturating_mul(rule.points as i128);
+    if rule.max_points > 0 {
+        let cap = rule.max_points.min(i64::MAX as u64) as i128;
+        points = points.clamp(-cap, cap);
+    }
+    points.clamp(i64::MIN as i128 + 1, i64::MAX as i128) as i64
+}
+
+/// Trim a positive `delta` to what is left of the contributor's epoch cap
+/// and record the usage.  Deductions are never capped.
+pub(crate) fn apply_epoch_cap(
+    env: &Env,
+    config: &NotificationConfig,
+    contributor: &Address,
+    delta: i64,
+) -> i64 {
+    if delta <= 0 || config.epoch_cap == 0 {
+        return delta;
+    }
+    let epoch = env.ledger().timestamp() / config.epoch_secs;
+    let key = DataKey::NotificationEpoch(contributor.clone());
+    let earned = match env.storage().persistent().get::<_, EpochUsage>(&key) {
+        Some(usage) if usage.epoch == epoch => usage.earned,
+        _ => 0,
+    };
+    let allowed = (delta as u64).min(config.epoch_cap.saturating_sub(earned));
+    if allowed > 0 {
+        env.storage().persistent().set(
+            &key,
+            &EpochUsage {
+                epoch,
+                earned: earned + allowed,
+            },
+        );
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+    }
+    allowed as i64
+}
+
+pub(crate) fn get_usage(env: &Env, contributor: &Address) -> Option<EpochUsage> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::NotificationEpoch(contributor.clone()))
+}
@@ -0,0 +1,83 @@
+use soroban_sdk::{contracttype, Env, Symbol, Vec};
+
+use crate::errors::ContributorError;
+use crate::storage::{ContributorTier, DataKey};
+
+// ── Well-known permission names ──────────────────────────────
+
+pub use permission_interface::{
+    PERMISSION_DISPUTE_MILESTONE, PERMISSION_PROPOSE_PROJECT, PERMISSION_REVIEW,
+};
+
+// ── Types ────────────────────────────────────────────────────
+
+/// Minimum reputation required to reach each tier above `Novice`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TierThresholds {
+    pub builder: u64,
+    pub architect: u64,
+    pub core: u64,
+}
+
+/// Requirement for a permission: the holder must reach `min_tier`, or hold
+/// an unexpired grant of at least one of the `badges` ids.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PermissionRule {
+    pub min_tier: ContributorTier,
+    pub badges: Vec<u32>,
+}
+
+// ── Internal helpers ─────────────────────────────────────────
+
+pub(crate) fn default_thresholds() -> TierThresholds {
+    TierThresholds {
+        builder: 10,
+        architect: 50,
+        core: 100,
+    }
+}
+
+pub(crate) fn validate_thresholds(thresholds: &TierThresholds) -> Result<(), ContributorError> {
+    if thresholds.builder == 0
+        || thresholds.architect <= thresholds.builder
+        || thresholds.core <= thresholds.architect
+    {
+        return Err(ContributorError::InvalidTierThresholds);
+    }
+    Ok(())
+}
+
+pub(crate) fn get_thresholds(env: &Env) -> TierThresholds {
+    env.storage()
+        .instance()
+        .get(&DataKey::TierThresholds)
+        .unwrap_or(default_thresholds())
+}
+
+pub(crate) fn tier_for(thresholds: &TierThresholds, reputation: u64) -> ContributorTier {
+    if reputation >= thresholds.core {
+        ContributorTier::Core
+    } else if reputation >= thresholds.architect {
+        ContributorTier::Architect
+    } else if reputation >= thresholds.builder {
+        ContributorTier::Builder
+    } else {
+        ContributorTier::Novice
+    }
+}
+
+pub(crate) fn get_rule(env: &Env, permission: &Symbol) -> Option<PermissionRule> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Permission(permission.clone()))
+}
+
+/// Whether a contributor with `tier` and `badges` satisfies `rule`.
+pub(crate) fn satisfies(rule: &PermissionRule, tier: ContributorTier, badges: &Vec<u32>) -> bool {
+    if tier as u32 >= rule.min_tier as u32 {
+        return true;
+    }
+    rule.badges.iter().any(|badge| badges.contains(badge))
+}
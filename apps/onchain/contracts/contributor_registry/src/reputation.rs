@@ -0,0 +1,341 @@
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
+
+use crate::errors::ContributorError;
+use crate::storage::{ContributorData, DataKey, LEDGER_BUMP, LEDGER_THRESHOLD};
+
+// ── Constants ────────────────────────────────────────────────
+
+/// Maximum number of reputation changes retained per contributor.  Once the
+/// cap is reached the oldest entry is dropped for every new one recorded.
+pub const MAX_REPUTATION_HISTORY: u32 = 50;
+
+// ── Types ────────────────────────────────────────────────────
+
+/// Where a reputation change originated.
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum ReputationSource {
+    /// Multisig-approved `update_reputation`.
+    Governance = 1,
+    /// Multisig-approved `apply_reputation_penalty`.
+    Penalty = 2,
+    /// Cross-contract notification (e.g. a crowdfund deposit).
+    Notification = 3,
+    /// Inactivity decay materialised on the next recorded change.
+    Decay = 4,
+    /// Reputation bonus of a granted (or revoked) badge.
+    Badge = 5,
+}
+
+/// A single entry in a contributor's reputation history.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReputationChange {
+    pub source: ReputationSource,
+    /// Signed change actually applied (after overflow/floor clamping).
+    pub delta: i64,
+    pub reason: String,
+    pub timestamp: u64,
+    /// Stored score immediately after this change.
+    pub score_after: u64,
+}
+
+/// Inactivity decay settings.  For every full `period_secs` without a
+/// recorded reputation change, `points_per_period` is deducted from the
+/// score, never taking it below `floor`.  Scores already at or below the
+/// floor are left untouched.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DecayConfig {
+    pub period_secs: u64,
+    pub points_per_period: u64,
+    pub floor: u64,
+}
+
+/// A decay setting together with the timestamp it took effect.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DecayEpoch {
+    /// Decay switched off.
+    Off(u64),
+    /// Decay applied under the given settings.
+    On(u64, DecayConfig),
+}
+
+impl DecayEpoch {
+    fn new(effective_from: u64, config: Option<DecayConfig>) -> Self {
+        match config {
+            Some(config) => DecayEpoch::On(effective_from, config),
+            None => DecayEpoch::Off(effective_from),
+        }
+    }
+
+    fn effective_from(&self) -> u64 {
+        match self {
+            DecayEpoch::Off(from) | DecayEpoch::On(from, _) => *from,
+        }
+    }
+}
+
+// ── Internal helpers ─────────────────────────────────────────
+
+pub(crate) fn validate_decay_config(config: &DecayConfig) -> Result<(), ContributorError> {
+    if config.period_secs == 0 || config.points_per_period == 0 {
+        return Err(ContributorError::InvalidDecayConfig);
+    }
+    Ok(())
+}
+
+pub(crate) fn get_decay_config(env: &Env) -> Option<DecayConfig> {
+    env.storage().instance().get(&DataKey::DecayConfig)
+}
+
+/// Every decay setting in force so far, oldest first.  Deployments that
+/// configured decay before changes were recorded get their current setting
+/// back-dated to the start.
+pub(crate) fn get_decay_epochs(env: &Env) -> Vec<DecayEpoch> {
+    if let Some(epochs) = env.storage().instance().get(&DataKey::DecayEpochs) {
+        return epochs;
+    }
+    let mut epochs = Vec::new(env);
+    if let Some(config) = get_decay_config(env) {
+        epochs.push_back(DecayEpoch::On(0, config));
+    }
+    epochs
+}
+
+/// Make `config` the decay setting from the current ledger on.  Time before
+/// now keeps decaying under whatever was in force then.
+pub(crate) fn set_decay_config(env: &Env, config: &Option<DecayConfig>) {
+    let now = env.ledger().timestamp();
+    let mut epochs = get_decay_epochs(env);
+    if epochs.last().map(|epoch| epoch.effective_from()) == Some(now) {
+        epochs.pop_back();
+    }
+    epochs.push_back(DecayEpoch::new(now, config.clone()));
+    env.storage().instance().set(&DataKey::DecayEpochs, &epochs);
+    match config {
+        Some(config) => env.storage().instance().set(&DataKey::DecayConfig, config),
+        None => env.storage().instance().remove(&DataKey::DecayConfig),
+    }
+}
+
+/// Decay `score` over the time from `since` to `now`, each stretch under
+/// the setting in force during it.  Full periods are counted per setting,
+/// so a period cut short by a config change never completes.
+///
+/// Returns the decayed score and the end of the last full period counted,
+/// from which decay keeps accruing.
+fn decay_between(epochs: &Vec<DecayEpoch>, score: u64, since: u64, now: u64) -> (u64, u64) {
+    let mut score = score;
+    let mut counted_to = since;
+    for i in 0..epochs.len() {
+        let epoch = epochs.get_unchecked(i);
+        let start = epoch.effective_from().max(since);
+        let end = match epochs.get(i + 1) {
+            Some(next) => next.effective_from().min(now),
+            None => now,
+        };
+        let config = match epoch {
+            DecayEpoch::On(_, config) if end > start => config,
+            _ => continue,
+        };
+        let periods = (end - start) / config.period_secs;
+        if periods > 0 && score > config.floor {
+            let decay = periods.saturating_mul(config.points_per_period);
+            score = score.saturating_sub(decay).max(config.floor);
+        }
+        counted_to = start + periods * config.period_secs;
+    }
+    (score, counted_to)
+}
+
+/// Score left after decaying `score` from `since` until `now`.
+pub(crate) fn decayed_score(epochs: &Vec<DecayEpoch>, score: u64, since: u64, now: u64) -> u64 {
+    decay_between(epochs, score, since, now).0
+}
+
+pub(crate) fn get_history(env: &Env, contributor: &Address) -> Vec<ReputationChange> {
+    let key = DataKey::ReputationHistory(contributor.clone());
+    let history: Vec<ReputationChange> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+    if !history.is_empty() {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+    }
+    history
+}
+
+/// Timestamp of the contributor's last recorded change, falling back to the
+/// registration time.  This is the reference point for inactivity decay.
+pub(crate) fn last_activity(history: &Vec<ReputationChange>, data: &ContributorData) -> u64 {
+    history
+        .last()
+        .map(|change| change.timestamp)
+        .unwrap_or(data.registered_timestamp)
+}
+
+/// The contributor's score as of the current ledger, with decay applied.
+pub(crate) fn current_score(env: &Env, data: &ContributorData) -> u64 {
+    let history = get_history(env, &data.address);
+    decayed_score(
+        &get_decay_epochs(env),
+        data.reputation_score,
+        last_activity(&history, data),
+        env.ledger().timestamp(),
+    )
+}
+
+/// Reconstruct the contributor's score at `timestamp` from the retained
+/// history.  If `timestamp` predates the oldest retained entry, the score
+/// just before that entry is returned.
+pub(crate) fn score_at(env: &Env, data: &ContributorData, timestamp: u64) -> u64 {
+    if timestamp < data.registered_timestamp {
+        return 0;
+    }
+    let history = get_history(env, &data.address);
+    let mut latest: Option<ReputationChange> = None;
+    for change in history.iter() {
+        if change.timestamp > timestamp {
+            break;
+        }
+        latest = Some(change);
+    }
+    match latest {
+        Some(change) => decayed_score(
+            &get_decay_epochs(env),
+            change.score_after,
+            change.timestamp,
+            timestamp,
+        ),
+        None => match history.first() {
+            Some(oldest) => score_before(&oldest),
+            None => decayed_score(
+                &get_decay_epochs(env),
+                data.reputation_score,
+                data.registered_timestamp,
+                timestamp,
+            ),
+        },
+    }
+}
+
+fn score_before(change: &ReputationChange) -> u64 {
+    if change.delta >= 0 {
+        change.score_after.saturating_sub(change.delta as u64)
+    } else {
+        change
+            .score_after
+            .saturating_add(change.delta.unsigned_abs())
+    }
+}
+
+fn push_history(history: &mut Vec<ReputationChange>, change: ReputationChange) {
+    if history.len() >= MAX_REPUTATION_HISTORY {
+        history.pop_front();
+    }
+    history.push_back(change);
+}
+
+/// Fold pending inactivity decay into `data.reputation_score`, recording it
+/// in `history`.  The entry is stamped at the end of the last full decay
+/// period rather than now, so a partly elapsed period keeps counting and
+/// frequent syncing cannot stall decay.  Returns whether the score changed.
+fn materialize_decay(
+    env: &Env,
+    data: &mut ContributorData,
+    history: &mut Vec<ReputationChange>,
+) -> bool {
+    let since = last_activity(history, data);
+    let now = env.ledger().timestamp();
+    let (decayed, counted_to) =
+        decay_between(&get_decay_epochs(env), data.reputation_score, since, now);
+    if decayed >= data.reputation_score {
+        return false;
+    }
+    push_history(
+        history,
+        ReputationChange {
+            source: ReputationSource::Decay,
+            delta: -((data.reputation_score - decayed) as i64),
+            reason: String::from_str(env, "inactivity"),
+            timestamp: counted_to,
+            score_after: decayed,
+        },
+    );
+    data.reputation_score = decayed;
+    true
+}
+
+fn save_history(env: &Env, contributor: &Address, history: &Vec<ReputationChange>) {
+    let key = DataKey::ReputationHistory(contributor.clone());
+    env.storage().persistent().set(&key, history);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+}
+
+/// Materialise pending inactivity decay without recording any other change,
+/// so the decayed score can be carried into delegations.  The caller is
+/// responsible for persisting `data`.  Returns whether the score changed.
+pub(crate) fn sync_decay(env: &Env, data: &mut ContributorData) -> bool {
+    let mut history = get_history(env, &data.address);
+    if !materialize_decay(env, data, &mut history) {
+        return false;
+    }
+    save_history(env, &data.address, &history);
+    true
+}
+
+/// Apply `delta` to the contributor's stored score, first materialising any
+/// pending inactivity decay, and append both changes to the history.
+///
+/// Positive deltas fail with `ReputationOverflow` on overflow; negative
+/// deltas are floored at zero.  The caller is responsible for persisting
+/// `data` afterwards.  Returns the new score.
+pub(crate) fn apply_change(
+    env: &Env,
+    data: &mut ContributorData,
+    source: ReputationSource,
+    delta: i64,
+    reason: String,
+) -> Result<u64, ContributorError> {
+    let now = env.ledger().timestamp();
+    let mut history = get_history(env, &data.address);
+    materialize_decay(env, data, &mut history);
+
+    let old_score = data.reputation_score;
+    let new_score = if delta > 0 {
+        old_score
+            .checked_add(delta as u64)
+            .ok_or(ContributorError::ReputationOverflow)?
+    } else {
+        old_score.saturating_sub(delta.unsigned_abs())
+    };
+    data.reputation_score = new_score;
+
+    let applied = if new_score >= old_score {
+        (new_score - old_score) as i64
+    } else {
+        -((old_score - new_score) as i64)
+    };
+    push_history(
+        &mut history,
+        ReputationChange {
+            source,
+            delta: applied,
+            reason,
+            timestamp: now,
+            score_after: new_score,
+        },
+    );
+
+    save_history(env, &data.address, &history);
+
+    Ok(new_score)
+}
@@ -1,4 +1,6 @@
-use soroban_sdk::{contracttype, Address, String};
+use soroban_sdk::{contracttype, Address, String, Symbol};
+
+use crate::delegation::DelegationScope;
 
 // TTL constants for Soroban storage rent management.
 // LEDGER_THRESHOLD: if the remaining TTL falls below this value, extend it.
@@ -21,11 +23,79 @@ pub enum DataKey {
     NextProposalId,
 
     // ── Badge keys ────────────────────────────────────────────
+    /// Legacy `Vec<Badge>` enum storage; migrated into `BadgeGrants`.
     Badges(Address),
+    BadgeDefinition(u32),
+    NextBadgeId,
+    BadgeGrants(Address),
+    /// Certificate token id → owner and badge id.
+    BadgeToken(u32),
+    NextBadgeTokenId,
 
     // ── Penalty keys ──────────────────────────────────────────
     /// Latest penalty record for a contributor (keyed by contributor address).
     ReputationPenalty(Address),
+    /// Number of penalty records ever applied to a contributor.
+    PenaltyCount(Address),
+    /// Full penalty log, keyed by (contributor, index).
+    PenaltyAt(Address, u32),
+
+    // ── Reputation history keys ───────────────────────────────
+    /// Bounded log of reputation changes for a contributor.
+    ReputationHistory(Address),
+    /// Inactivity decay settings (instance storage).
+    DecayConfig,
+    /// Every decay setting with the time it took effect, so past scores
+    /// decay under the rules of their day (instance storage).
+    DecayEpochs,
+
+    // ── Tier & permission keys ────────────────────────────────
+    /// Governance-configured tier cutoffs (instance storage).
+    TierThresholds,
+    /// Requirement for a named permission.
+    Permission(Symbol),
+
+    // ── Notification scoring keys ─────────────────────────────
+    /// Whether a contract may deliver reputation notifications.
+    TrustedSource(Address),
+    /// Scoring rule for a notification event type.
+    NotificationRule(Symbol),
+    /// Pricing adapter and epoch cap settings (instance storage).
+    NotificationConfig,
+    /// Points a contributor earned from notifications in the current epoch.
+    NotificationEpoch(Address),
+
+    // ── GitHub attestation keys ───────────────────────────────
+    /// Ed25519 public key of the off-chain OAuth verifier (instance storage).
+    VerifierKey,
+    /// Open dispute over a verified handle.
+    HandleDispute(String),
+    /// Present while the address's handle is backed by a verifier
+    /// attestation; cleared whenever the handle changes.  Kept out of
+    /// `ContributorData` so records written before attestations still decode.
+    HandleVerified(Address),
+
+    // ── Account lifecycle keys ────────────────────────────────
+    /// Seconds before a deregistered address may register again (instance
+    /// storage).
+    ReregistrationCooldown,
+    /// Timestamp of an address's most recent deregistration.
+    DeregisteredAt(Address),
+    /// Timestamp at which a handle was last freed by a deregistration.
+    DeregisteredHandleAt(String),
+
+    // ── Delegation keys ───────────────────────────────────────
+    /// A delegator's current delegation in a scope.
+    Delegation(Address, DelegationScope),
+    /// Non-global scopes a delegator currently delegates in.
+    DelegationScopes(Address),
+    /// Checkpointed delegatee of a delegator in a scope.
+    DelegateHistory(Address, DelegationScope),
+    /// Checkpointed reputation delegated to an address in a scope.
+    DelegatedPower(Address, DelegationScope),
+    /// Checkpointed part of an address's global delegated power whose
+    /// delegators have overridden it in a narrower scope.
+    OverriddenPower(Address, DelegationScope),
 }
 
 #[contracttype]
@@ -47,16 +117,6 @@ pub enum ContributorTier {
     Core = 4,
 }
 
-#[contracttype]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-#[repr(u32)]
-pub enum Badge {
-    EarlyAdopter = 1,
-    BugHunter = 2,
-    TopContributor = 3,
-    SecurityAuditor = 4,
-}
-
 /// How severe the dispute outcome was.
 #[contracttype]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
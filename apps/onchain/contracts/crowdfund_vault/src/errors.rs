@@ -35,4 +35,7 @@ pub enum CrowdfundError {
     RefundWindowClosed = 29,
     RefundWindowNotOpen = 30,
     Reentrancy = 31,
+    PermissionDenied = 32,
+    InvalidRelayerFee = 33,
+    StreamCategoryNotSet = 34,
 }
@@ -36,6 +36,38 @@ pub struct MilestoneApprovedEvent {
     pub milestone_id: u32,
 }
 
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MilestoneStreamedEvent {
+    #[topic]
+    pub project_id: u64,
+    pub milestone_id: u32,
+    pub treasury: Address,
+    pub stream_id: u64,
+    pub amount: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MilestoneStreamCancelledEvent {
+    #[topic]
+    pub project_id: u64,
+    pub milestone_id: u32,
+    pub treasury: Address,
+    pub stream_id: u64,
+    pub refunded: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamCancelFailedEvent {
+    #[topic]
+    pub project_id: u64,
+    pub milestone_id: u32,
+    pub treasury: Address,
+    pub stream_id: u64,
+}
+
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct WithdrawEvent {
@@ -165,6 +197,40 @@ pub struct FeeConfigChangedEvent {
     pub treasury: Address,
 }
 
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeCategorySetEvent {
+    #[topic]
+    pub admin: Address,
+    pub category: Option<soroban_sdk::Symbol>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamCategorySetEvent {
+    #[topic]
+    pub admin: Address,
+    pub category: Option<soroban_sdk::Symbol>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeIncomeRecordFailedEvent {
+    #[topic]
+    pub project_id: u64,
+    pub treasury: Address,
+    pub category: soroban_sdk::Symbol,
+    pub amount: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PermissionRegistrySetEvent {
+    #[topic]
+    pub admin: Address,
+    pub registry: Option<Address>,
+}
+
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct VoteCastEvent {
@@ -11,12 +11,19 @@ mod yield_provider;
 use errors::CrowdfundError;
 use math::{sqrt_scaled, unscale};
 use notification_interface::{Notification, NotificationReceiverClient};
+use permission_interface::{
+    is_permitted, PERMISSION_DISPUTE_MILESTONE, PERMISSION_PROPOSE_PROJECT,
+};
 use reentrancy_guard::{acquire as acquire_reentrancy, release as release_reentrancy};
+use signed_intent::RelayerFee;
 use soroban_sdk::token::TokenClient;
 use soroban_sdk::xdr::ToXdr;
-use soroban_sdk::{contract, contractimpl, vec, Address, BytesN, Env, Symbol, Vec};
+use soroban_sdk::{
+    contract, contractimpl, vec, Address, BytesN, Env, IntoVal, Symbol, TryFromVal, Vec,
+};
 use storage::{
-    DataKey, MilestoneDispute, ProjectData, ProtocolStats, LEDGER_BUMP, LEDGER_THRESHOLD,
+    DataKey, MilestoneDispute, MilestoneStream, ProjectData, ProtocolStats, LEDGER_BUMP,
+    LEDGER_THRESHOLD,
 };
 
 const CURRENT_STORAGE_VERSION: u32 = 1;
@@ -171,6 +178,20 @@ impl CrowdfundVaultContract {
         Ok(())
     }
 
+    /// When a permission registry is configured, require `who` to hold
+    /// `permission` there.
+    fn require_permission(
+        env: &Env,
+        who: &Address,
+        permission: &str,
+    ) -> Result<(), CrowdfundError> {
+        let registry = env.storage().instance().get(&DataKey::PermissionRegistry);
+        if !is_permitted(env, registry, who, permission) {
+            return Err(CrowdfundError::PermissionDenied);
+        }
+        Ok(())
+    }
+
     fn with_reentrancy_guard<T, F>(env: &Env, f: F) -> Result<T, CrowdfundError>
     where
         F: FnOnce() -> Result<T, CrowdfundError>,
@@ -268,6 +289,7 @@ impl CrowdfundVaultContract {
 
         // Require owner authorization
         owner.require_auth();
+        Self::require_permission(&env, &owner, PERMISSION_PROPOSE_PROJECT)?;
 
         // Check Emergency Pause State (single read)
         let is_paused: bool = env
@@ -471,10 +493,22 @@ impl CrowdfundVaultContract {
 
                     events::ContributionRefundedEvent {
                         project_id,
-                        contributor,
+                        contributor: contributor.clone(),
                         amount,
                     }
                     .publish(&env);
+
+                    Self::notify_subscribers(
+                        &env,
+                        Symbol::new(&env, "refund"),
+                        (
+                            contributor,
+                            project_id,
+                            amount,
+                            project.token_address.clone(),
+                        )
+                            .to_xdr(&env),
+                    );
                 }
             }
 
@@ -585,12 +619,55 @@ impl CrowdfundVaultContract {
         user: Address,
         project_id: u64,
         amount: i128,
+    ) -> Result<(), CrowdfundError> {
+        user.require_auth();
+        Self::deposit_from(env, user, project_id, amount)
+    }
+
+    /// Gasless variant of `deposit`.  `user` signs
+    /// `("deposit_with_sig", user, project_id, amount, fee, nonce)`; the
+    /// optional relayer fee is paid in the project's token on top of the
+    /// deposit.
+    pub fn deposit_with_sig(
+        env: Env,
+        user: Address,
+        project_id: u64,
+        amount: i128,
+        fee: Option<RelayerFee>,
+    ) -> Result<(), CrowdfundError> {
+        let project: ProjectData = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Project(project_id))
+            .ok_or(CrowdfundError::ProjectNotFound)?;
+        signed_intent::require_signed(
+            &env,
+            &DataKey::SignedNonce(user.clone()),
+            &user,
+            "deposit_with_sig",
+            vec![
+                &env,
+                user.into_val(&env),
+                project_id.into_val(&env),
+                amount.into_val(&env),
+                fee.into_val(&env),
+            ],
+        );
+        Self::deposit_from(env.clone(), user.clone(), project_id, amount)?;
+        signed_intent::pay_relayer_fee(&env, &project.token_address, &user, &fee)
+            .map_err(|_| CrowdfundError::InvalidRelayerFee)
+    }
+
+    /// Deposit on behalf of an already-authorized `user`.
+    fn deposit_from(
+        env: Env,
+        user: Address,
+        project_id: u64,
+        amount: i128,
     ) -> Result<(), CrowdfundError> {
         Self::with_reentrancy_guard(&env, || {
             Self::require_current_storage_version(&env)?;
 
-            user.require_auth();
-
             let is_paused: bool = env
                 .storage()
                 .instance()
@@ -712,7 +789,7 @@ impl CrowdfundVaultContract {
             Self::notify_subscribers(
                 &env,
                 Symbol::new(&env, "deposit"),
-                (user, project_id, amount).to_xdr(&env),
+                (user, project_id, amount, project.token_address).to_xdr(&env),
             );
 
             Ok(())
@@ -761,7 +838,13 @@ impl CrowdfundVaultContract {
         Ok(())
     }
 
-    /// Internal helper to notify all subscribers
+    /// Internal helper to notify all subscribers.
+    ///
+    /// Every notification carries `(account, project_id, amount, token)` as
+    /// XDR.  For `deposit` and `refund`, `account` is the depositor /
+    /// refunded contributor.  For `withdraw`, it is the project owner and
+    /// `amount` is what they received after the protocol fee.  For
+    /// `milestone_approved`, it is the project owner and `amount` is 0.
     fn notify_subscribers(env: &Env, event_type: Symbol, data: soroban_sdk::Bytes) {
         let subscribers: Vec<Address> = env
             .storage()
@@ -824,6 +907,12 @@ impl CrowdfundVaultContract {
         }
         .publish(&env);
 
+        Self::notify_subscribers(
+            &env,
+            Symbol::new(&env, "milestone_approved"),
+            (project.owner, project_id, 0i128, project.token_address).to_xdr(&env),
+        );
+
         Ok(())
     }
 
@@ -897,8 +986,58 @@ impl CrowdfundVaultContract {
         milestone_id: u32,
         support: bool,
     ) -> Result<(), CrowdfundError> {
-        Self::require_current_storage_version(&env)?;
         voter.require_auth();
+        Self::record_milestone_vote(&env, &voter, project_id, milestone_id, support)
+    }
+
+    /// Gasless variant of `vote_milestone`.  `voter` signs
+    /// `("vote_milestone_with_sig", voter, project_id, milestone_id, support,
+    /// fee, nonce)`; the optional relayer fee is paid in the project's token.
+    pub fn vote_milestone_with_sig(
+        env: Env,
+        voter: Address,
+        project_id: u64,
+        milestone_id: u32,
+        support: bool,
+        fee: Option<RelayerFee>,
+    ) -> Result<(), CrowdfundError> {
+        let project: ProjectData = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Project(project_id))
+            .ok_or(CrowdfundError::ProjectNotFound)?;
+        signed_intent::require_signed(
+            &env,
+            &DataKey::SignedNonce(voter.clone()),
+            &voter,
+            "vote_milestone_with_sig",
+            vec![
+                &env,
+                voter.into_val(&env),
+                project_id.into_val(&env),
+                milestone_id.into_val(&env),
+                support.into_val(&env),
+                fee.into_val(&env),
+            ],
+        );
+        Self::record_milestone_vote(&env, &voter, project_id, milestone_id, support)?;
+        signed_intent::pay_relayer_fee(&env, &project.token_address, &voter, &fee)
+            .map_err(|_| CrowdfundError::InvalidRelayerFee)
+    }
+
+    /// Next nonce `signer` must sign for the `*_with_sig` entrypoints.
+    pub fn get_signed_nonce(env: Env, signer: Address) -> u64 {
+        signed_intent::nonce_of(&env, &DataKey::SignedNonce(signer))
+    }
+
+    fn record_milestone_vote(
+        env: &Env,
+        voter: &Address,
+        project_id: u64,
+        milestone_id: u32,
+        support: bool,
+    ) -> Result<(), CrowdfundError> {
+        Self::require_current_storage_version(env)?;
 
         let mut project: ProjectData = env
             .storage()
@@ -906,7 +1045,7 @@ impl CrowdfundVaultContract {
             .get(&DataKey::Project(project_id))
             .ok_or(CrowdfundError::ProjectNotFound)?;
 
-        Self::fail_if_project_expired(&env, project_id, &mut project)?;
+        Self::fail_if_project_expired(env, project_id, &mut project)?;
 
         if !project.is_active {
             return Err(CrowdfundError::ProjectNotActive);
@@ -976,11 +1115,11 @@ impl CrowdfundVaultContract {
         events::VoteCastEvent {
             project_id,
             milestone_id,
-            voter,
+            voter: voter.clone(),
             weight,
             support,
         }
-        .publish(&env);
+        .publish(env);
 
         // Auto-approve if threshold met (> 50% of total deposited)
         let current_for: i128 = env
@@ -1001,7 +1140,7 @@ impl CrowdfundVaultContract {
                 project_id,
                 milestone_id,
             }
-            .publish(&env);
+            .publish(env);
         }
 
         Ok(())
@@ -1133,18 +1272,13 @@ impl CrowdfundVaultContract {
 
             let contract_address = env.current_contract_address();
             if fee_amount > 0 {
-                token::transfer(
+                Self::pay_protocol_fee(
                     &env,
+                    project_id,
                     &project.token_address,
-                    &contract_address,
                     &treasury.clone().unwrap(),
-                    &fee_amount,
+                    fee_amount,
                 );
-                events::ProtocolFeeDeductedEvent {
-                    project_id,
-                    amount: fee_amount,
-                }
-                .publish(&env);
             }
 
             token::transfer(
@@ -1156,18 +1290,72 @@ impl CrowdfundVaultContract {
             );
 
             events::WithdrawEvent {
-                owner: project.owner,
+                owner: project.owner.clone(),
                 project_id,
                 amount: withdraw_amount,
             }
             .publish(&env);
 
+            Self::notify_subscribers(
+                &env,
+                Symbol::new(&env, "withdraw"),
+                (
+                    project.owner,
+                    project_id,
+                    withdraw_amount,
+                    project.token_address,
+                )
+                    .to_xdr(&env),
+            );
+
             Ok(())
         })
     }
 
+    /// Send a protocol fee to the treasury and, if a fee category is
+    /// configured, book it there as income.  A treasury that refuses the
+    /// booking (e.g. this vault is not an income source) does not block the
+    /// payout; `FeeIncomeRecordFailedEvent` is emitted instead.
+    fn pay_protocol_fee(
+        env: &Env,
+        project_id: u64,
+        token_address: &Address,
+        treasury: &Address,
+        fee_amount: i128,
+    ) {
+        let contract_address = env.current_contract_address();
+        token::transfer(env, token_address, &contract_address, treasury, &fee_amount);
+
+        let category: Option<Symbol> = env.storage().instance().get(&DataKey::FeeCategory);
+        if let Some(category) = category {
+            let booked = treasury_interface::TreasuryClient::new(env, treasury).try_record_income(
+                &contract_address,
+                &category,
+                token_address,
+                &fee_amount,
+            );
+            if !matches!(booked, Ok(Ok(()))) {
+                events::FeeIncomeRecordFailedEvent {
+                    project_id,
+                    treasury: treasury.clone(),
+                    category,
+                    amount: fee_amount,
+                }
+                .publish(env);
+            }
+        }
+
+        events::ProtocolFeeDeductedEvent {
+            project_id,
+            amount: fee_amount,
+        }
+        .publish(env);
+    }
+
     /// Allocate approved milestone funds to a streaming treasury for gradual unlocking.
     /// This allows projects to have their budget streamed over time instead of receiving it all at once.
+    /// Each call opens a separate stream for the project owner, charged to
+    /// the treasury category set with `set_stream_category`; returns its id.
     pub fn allocate_to_streaming_treasury(
         env: Env,
         admin: Address,
@@ -1176,7 +1364,7 @@ impl CrowdfundVaultContract {
         treasury_contract: Address,
         amount: i128,
         duration: u64,
-    ) -> Result<(), CrowdfundError> {
+    ) -> Result<u64, CrowdfundError> {
         Self::with_reentrancy_guard(&env, || {
             Self::verify_admin(&env, &admin)?;
 
@@ -1195,6 +1383,11 @@ impl CrowdfundVaultContract {
             if !is_approved {
                 return Err(CrowdfundError::MilestoneNotApproved);
             }
+            let category: Symbol = env
+                .storage()
+                .instance()
+                .get(&DataKey::StreamCategory)
+                .ok_or(CrowdfundError::StreamCategoryNotSet)?;
 
             let balance_key = DataKey::ProjectBalance(project_id, project.token_address.clone());
             let total_balance: i128 = env.storage().persistent().get(&balance_key).unwrap_or(0);
@@ -1223,21 +1416,44 @@ impl CrowdfundVaultContract {
                 &amount,
             );
 
-            // Call treasury contract to start stream
+            // Call treasury contract to start a stream funded by the transfer above
             let treasury_client = treasury_interface::TreasuryClient::new(&env, &treasury_contract);
             let start_time = env.ledger().timestamp();
 
             // The treasury contract expects the admin to authorize the allocation.
             // We pass the admin address here.
-            treasury_client.allocate_budget(
+            let stream_id = treasury_client.allocate_from_reserves(
                 &admin,
+                &category,
+                &project.token_address,
                 &project.owner,
                 &amount,
                 &start_time,
                 &duration,
             );
 
-            Ok(())
+            let streams_key = DataKey::MilestoneStreams(project_id, milestone_id);
+            let mut streams: Vec<MilestoneStream> = env
+                .storage()
+                .persistent()
+                .get(&streams_key)
+                .unwrap_or(vec![&env]);
+            streams.push_back(MilestoneStream {
+                treasury: treasury_contract.clone(),
+                stream_id,
+            });
+            env.storage().persistent().set(&streams_key, &streams);
+
+            events::MilestoneStreamedEvent {
+                project_id,
+                milestone_id,
+                treasury: treasury_contract,
+                stream_id,
+                amount,
+            }
+            .publish(&env);
+
+            Ok(stream_id)
         })
     }
 
@@ -1250,6 +1466,7 @@ impl CrowdfundVaultContract {
         reason: Symbol,
     ) -> Result<(), CrowdfundError> {
         challenger.require_auth();
+        Self::require_permission(&env, &challenger, PERMISSION_DISPUTE_MILESTONE)?;
 
         env.storage()
             .persistent()
@@ -1311,6 +1528,8 @@ impl CrowdfundVaultContract {
     }
 
     /// Resolve a milestone dispute and either restore or revoke payout eligibility.
+    /// Revoking cancels any treasury streams funded from the milestone; their
+    /// unvested remainder returns to the project balance.
     pub fn resolve_milestone_dispute(
         env: Env,
         admin: Address,
@@ -1336,6 +1555,9 @@ impl CrowdfundVaultContract {
             &DataKey::MilestoneApproved(project_id, milestone_id),
             &upheld_completion,
         );
+        if !upheld_completion {
+            Self::cancel_milestone_streams(&env, &admin, project_id, milestone_id);
+        }
 
         events::MilestoneDisputeResolvedEvent {
             admin,
@@ -1348,6 +1570,105 @@ impl CrowdfundVaultContract {
         Ok(())
     }
 
+    /// Retry cancelling the streams of a revoked milestone that the treasury
+    /// refused earlier (see `StreamCancelFailedEvent`).
+    pub fn retry_milestone_stream_cancels(
+        env: Env,
+        admin: Address,
+        project_id: u64,
+        milestone_id: u32,
+    ) -> Result<(), CrowdfundError> {
+        Self::verify_admin(&env, &admin)?;
+        if env
+            .storage()
+            .persistent()
+            .get(&DataKey::MilestoneApproved(project_id, milestone_id))
+            .unwrap_or(false)
+        {
+            return Err(CrowdfundError::MilestoneAlreadyApproved);
+        }
+        Self::cancel_milestone_streams(&env, &admin, project_id, milestone_id);
+        Ok(())
+    }
+
+    /// Cancel the treasury streams funded from a revoked milestone and credit
+    /// what they return back to the project.  Streams that already finished
+    /// are dropped; streams the treasury fails to cancel stay listed for
+    /// `retry_milestone_stream_cancels`.
+    fn cancel_milestone_streams(env: &Env, admin: &Address, project_id: u64, milestone_id: u32) {
+        let streams_key = DataKey::MilestoneStreams(project_id, milestone_id);
+        let streams: Vec<MilestoneStream> = match env.storage().persistent().get(&streams_key) {
+            Some(streams) => streams,
+            None => return,
+        };
+
+        let mut project: ProjectData = match env
+            .storage()
+            .persistent()
+            .get(&DataKey::Project(project_id))
+        {
+            Some(project) => project,
+            None => return,
+        };
+        let contract_address = env.current_contract_address();
+        let finished =
+            soroban_sdk::Error::from_contract_error(treasury_interface::STREAM_NOT_FOUND);
+        let mut failed: Vec<MilestoneStream> = vec![env];
+        let mut refunded_total: i128 = 0;
+        for stream in streams.iter() {
+            let treasury_client = treasury_interface::TreasuryClient::new(env, &stream.treasury);
+            let refunded = match treasury_client.try_cancel_stream(
+                admin,
+                &stream.stream_id,
+                &contract_address,
+            ) {
+                Ok(Ok(refunded)) => refunded,
+                Err(Ok(error))
+                    if soroban_sdk::Error::try_from_val(env, &error).ok() == Some(finished) =>
+                {
+                    continue
+                }
+                _ => {
+                    events::StreamCancelFailedEvent {
+                        project_id,
+                        milestone_id,
+                        treasury: stream.treasury.clone(),
+                        stream_id: stream.stream_id,
+                    }
+                    .publish(env);
+                    failed.push_back(stream);
+                    continue;
+                }
+            };
+            refunded_total += refunded;
+            events::MilestoneStreamCancelledEvent {
+                project_id,
+                milestone_id,
+                treasury: stream.treasury.clone(),
+                stream_id: stream.stream_id,
+                refunded,
+            }
+            .publish(env);
+        }
+        if failed.is_empty() {
+            env.storage().persistent().remove(&streams_key);
+        } else {
+            env.storage().persistent().set(&streams_key, &failed);
+        }
+
+        if refunded_total > 0 {
+            let balance_key = DataKey::ProjectBalance(project_id, project.token_address.clone());
+            let balance: i128 = env.storage().persistent().get(&balance_key).unwrap_or(0);
+            env.storage()
+                .persistent()
+                .set(&balance_key, &(balance + refunded_total));
+            project.total_withdrawn -= refunded_total;
+            env.storage()
+                .persistent()
+                .set(&DataKey::Project(project_id), &project);
+        }
+    }
+
     /// Register a new contributor
     pub fn register_contributor(env: Env, contributor: Address) -> Result<(), CrowdfundError> {
         Self::require_current_storage_version(&env)?;
@@ -1740,19 +2061,13 @@ impl CrowdfundVaultContract {
                 .set(&DataKey::Project(project_id), &project);
 
             if fee_amount > 0 {
-                let contract_address = env.current_contract_address();
-                token::transfer(
+                Self::pay_protocol_fee(
                     &env,
+                    project_id,
                     &project.token_address,
-                    &contract_address,
                     &treasury.unwrap(),
-                    &fee_amount,
+                    fee_amount,
                 );
-                events::ProtocolFeeDeductedEvent {
-                    project_id,
-                    amount: fee_amount,
-                }
-                .publish(&env);
             }
 
             Ok(match_after_fee)
@@ -2015,6 +2330,89 @@ impl CrowdfundVaultContract {
         Ok(())
     }
 
+    /// Set (or clear) the treasury budget category that protocol fees are
+    /// booked to.  When set, the treasury must list this vault as an income
+    /// source.
+    pub fn set_fee_category(
+        env: Env,
+        admin: Address,
+        category: Option<Symbol>,
+    ) -> Result<(), CrowdfundError> {
+        Self::verify_admin(&env, &admin)?;
+
+        match category {
+            Some(ref category) => env
+                .storage()
+                .instance()
+                .set(&DataKey::FeeCategory, category),
+            None => env.storage().instance().remove(&DataKey::FeeCategory),
+        }
+
+        events::FeeCategorySetEvent { admin, category }.publish(&env);
+
+        Ok(())
+    }
+
+    pub fn get_fee_category(env: Env) -> Option<Symbol> {
+        env.storage().instance().get(&DataKey::FeeCategory)
+    }
+
+    /// Set (or clear) the treasury budget category that milestone streams
+    /// are charged to.  Streaming is unavailable until one is set, and the
+    /// treasury must define it.
+    pub fn set_stream_category(
+        env: Env,
+        admin: Address,
+        category: Option<Symbol>,
+    ) -> Result<(), CrowdfundError> {
+        Self::verify_admin(&env, &admin)?;
+
+        match category {
+            Some(ref category) => env
+                .storage()
+                .instance()
+                .set(&DataKey::StreamCategory, category),
+            None => env.storage().instance().remove(&DataKey::StreamCategory),
+        }
+
+        events::StreamCategorySetEvent { admin, category }.publish(&env);
+
+        Ok(())
+    }
+
+    pub fn get_stream_category(env: Env) -> Option<Symbol> {
+        env.storage().instance().get(&DataKey::StreamCategory)
+    }
+
+    /// Set (or clear) the contributor_registry consulted for tier/badge-gated
+    /// permissions on project creation and milestone disputes.
+    pub fn set_permission_registry(
+        env: Env,
+        admin: Address,
+        registry: Option<Address>,
+    ) -> Result<(), CrowdfundError> {
+        Self::verify_admin(&env, &admin)?;
+
+        match registry {
+            Some(ref registry) => env
+                .storage()
+                .instance()
+                .set(&DataKey::PermissionRegistry, registry),
+            None => env
+                .storage()
+                .instance()
+                .remove(&DataKey::PermissionRegistry),
+        }
+
+        events::PermissionRegistrySetEvent { admin, registry }.publish(&env);
+
+        Ok(())
+    }
+
+    pub fn get_permission_registry(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::PermissionRegistry)
+    }
+
     /// Get total contributions for a project
     pub fn get_total_contributions(env: Env, project_id: u64) -> Result<i128, CrowdfundError> {
         let project: ProjectData = env
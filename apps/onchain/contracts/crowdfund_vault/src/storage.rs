@@ -38,6 +38,11 @@ pub enum DataKey {
     FeeBps,                      // -> u32
     Treasury,                    // -> Address
     Subscribers,
+    PermissionRegistry, // -> Address (contributor_registry used for has_permission)
+    SignedNonce(Address), // signer -> next nonce for *_with_sig entrypoints
+    MilestoneStreams(u64, u32), // (project_id, milestone_id) -> Vec<MilestoneStream>
+    FeeCategory,        // -> Symbol (treasury budget category credited with fees)
+    StreamCategory,     // -> Symbol (treasury budget category charged for milestone streams)
 }
 
 #[contracttype]
@@ -60,6 +65,14 @@ pub struct ProjectData {
     pub is_active: bool,
 }
 
+/// A treasury stream funded from a milestone by `allocate_to_streaming_treasury`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MilestoneStream {
+    pub treasury: Address,
+    pub stream_id: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct MilestoneDispute {
@@ -1,11 +1,13 @@
 use crate::errors::CrowdfundError;
 use crate::storage::DataKey;
 use crate::{CrowdfundVaultContract, CrowdfundVaultContractClient};
+use permission_interface::testutils::{MockPermissionRegistry, MockPermissionRegistryClient};
+use permission_interface::{PERMISSION_DISPUTE_MILESTONE, PERMISSION_PROPOSE_PROJECT};
 use soroban_sdk::{
-    symbol_short,
+    contract, contractimpl, symbol_short,
     testutils::{Address as _, Events, Ledger},
     token::{StellarAssetClient, TokenClient},
-    vec, Address, Env,
+    vec, Address, Env, Symbol, TryFromVal,
 };
 fn create_token_contract<'a>(
     env: &Env,
@@ -771,6 +773,164 @@ fn test_dispute_resolution_can_revoke_approval() {
     assert_eq!(blocked, Err(Ok(CrowdfundError::MilestoneNotApproved)));
 }
 
+/// Stand-in for the streaming treasury: holds what it is sent and hands
+/// all of it back on cancellation, as if nothing had vested yet.
+#[contract]
+pub struct MockStreamingTreasury;
+
+#[contractimpl]
+impl MockStreamingTreasury {
+    pub fn set_token(env: Env, token: Address) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("token"), &token);
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn allocate_from_reserves(
+        env: Env,
+        _admin: Address,
+        _category: Symbol,
+        _token: Address,
+        _beneficiary: Address,
+        amount: i128,
+        _start_time: u64,
+        _duration: u64,
+    ) -> u64 {
+        let id: u64 = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("next"))
+            .unwrap_or(1);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("next"), &(id + 1));
+        env.storage().persistent().set(&id, &amount);
+        id
+    }
+
+    pub fn record_income(
+        env: Env,
+        source: Address,
+        category: Symbol,
+        _token: Address,
+        amount: i128,
+    ) {
+        source.require_auth();
+        let income: i128 = env.storage().persistent().get(&category).unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&category, &(income + amount));
+    }
+
+    pub fn get_income(env: Env, category: Symbol) -> i128 {
+        env.storage().persistent().get(&category).unwrap_or(0)
+    }
+
+    pub fn set_failing(env: Env, failing: bool) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("failing"), &failing);
+    }
+
+    pub fn cancel_stream(env: Env, _admin: Address, stream_id: u64, refund_to: Address) -> i128 {
+        if env
+            .storage()
+            .instance()
+            .get(&symbol_short!("failing"))
+            .unwrap_or(false)
+        {
+            panic!("treasury unavailable");
+        }
+        let amount: i128 = env.storage().persistent().get(&stream_id).unwrap();
+        env.storage().persistent().remove(&stream_id);
+        let token: Address = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("token"))
+            .unwrap();
+        TokenClient::new(&env, &token).transfer(
+            &env.current_contract_address(),
+            &refund_to,
+            &amount,
+        );
+        amount
+    }
+}
+
+#[test]
+fn test_revoked_milestone_cancels_its_treasury_streams() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, owner, user, token_client) = setup_test(&env);
+    client.initialize(&admin);
+    let treasury = env.register(MockStreamingTreasury, ());
+    MockStreamingTreasuryClient::new(&env, &treasury).set_token(&token_client.address);
+
+    let project_id = client.create_project(
+        &owner,
+        &symbol_short!("Stream"),
+        &1_000_000,
+        &token_client.address,
+    );
+    client.deposit(&user, &project_id, &500_000);
+    client.approve_milestone(&admin, &project_id, &0);
+    client.set_stream_category(&admin, &Some(symbol_short!("milestone")));
+
+    let first =
+        client.allocate_to_streaming_treasury(&admin, &project_id, &0, &treasury, &100_000, &1000);
+    let second =
+        client.allocate_to_streaming_treasury(&admin, &project_id, &0, &treasury, &50_000, &1000);
+    assert_ne!(first, second);
+    assert_eq!(client.get_balance(&project_id), 350_000);
+
+    client.dispute_milestone(&user, &project_id, &0, &symbol_short!("quality"));
+    client.resolve_milestone_dispute(&admin, &project_id, &0, &false);
+
+    assert_eq!(client.get_balance(&project_id), 500_000);
+    assert_eq!(token_client.balance(&treasury), 0);
+}
+
+#[test]
+fn test_failed_stream_cancellation_can_be_retried() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, owner, user, token_client) = setup_test(&env);
+    client.initialize(&admin);
+    let treasury = env.register(MockStreamingTreasury, ());
+    let treasury_client = MockStreamingTreasuryClient::new(&env, &treasury);
+    treasury_client.set_token(&token_client.address);
+
+    let project_id = client.create_project(
+        &owner,
+        &symbol_short!("Stream"),
+        &1_000_000,
+        &token_client.address,
+    );
+    client.deposit(&user, &project_id, &500_000);
+    client.approve_milestone(&admin, &project_id, &0);
+    client.set_stream_category(&admin, &Some(symbol_short!("milestone")));
+    client.allocate_to_streaming_treasury(&admin, &project_id, &0, &treasury, &100_000, &1000);
+
+    // The revocation still goes through, but the stream's funds stay out.
+    treasury_client.set_failing(&true);
+    client.dispute_milestone(&user, &project_id, &0, &symbol_short!("quality"));
+    client.resolve_milestone_dispute(&admin, &project_id, &0, &false);
+    assert_eq!(client.get_balance(&project_id), 400_000);
+    assert_eq!(token_client.balance(&treasury), 100_000);
+
+    treasury_client.set_failing(&false);
+    client.retry_milestone_stream_cancels(&admin, &project_id, &0);
+    assert_eq!(client.get_balance(&project_id), 500_000);
+    assert_eq!(token_client.balance(&treasury), 0);
+
+    // Nothing is left to cancel.
+    client.retry_milestone_stream_cancels(&admin, &project_id, &0);
+    assert_eq!(client.get_balance(&project_id), 500_000);
+}
+
 #[test]
 fn test_only_contributors_can_dispute_milestones() {
     let env = Env::default();
@@ -827,6 +987,46 @@ fn test_duplicate_dispute_is_rejected_and_metadata_is_readable() {
     assert_eq!(result, Err(Ok(CrowdfundError::MilestoneAlreadyDisputed)));
 }
 
+#[test]
+fn test_permission_registry_gates_project_creation_and_disputes() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, owner, user, token_client) = setup_test(&env);
+    client.initialize(&admin);
+
+    let perms_id = env.register(MockPermissionRegistry, ());
+    let perms = MockPermissionRegistryClient::new(&env, &perms_id);
+    client.set_permission_registry(&admin, &Some(perms_id.clone()));
+    assert_eq!(client.get_permission_registry(), Some(perms_id));
+
+    let blocked = client.try_create_project(
+        &owner,
+        &symbol_short!("Gated"),
+        &1_000_000,
+        &token_client.address,
+    );
+    assert_eq!(blocked, Err(Ok(CrowdfundError::PermissionDenied)));
+
+    perms.allow(&owner, &Symbol::new(&env, PERMISSION_PROPOSE_PROJECT));
+    let project_id = client.create_project(
+        &owner,
+        &symbol_short!("Gated"),
+        &1_000_000,
+        &token_client.address,
+    );
+
+    client.deposit(&user, &project_id, &500_000);
+    client.approve_milestone(&admin, &project_id, &0);
+
+    let result = client.try_dispute_milestone(&user, &project_id, &0, &symbol_short!("quality"));
+    assert_eq!(result, Err(Ok(CrowdfundError::PermissionDenied)));
+
+    perms.allow(&user, &Symbol::new(&env, PERMISSION_DISPUTE_MILESTONE));
+    client.dispute_milestone(&user, &project_id, &0, &symbol_short!("quality"));
+    assert!(client.is_milestone_disputed(&project_id, &0));
+}
+
 // ===== get_balance after operations =====
 #[test]
 fn test_balance_tracking() {
@@ -2340,6 +2540,153 @@ fn test_withdraw_with_fee() {
     assert_eq!(client.get_balance(&project_id), 400_000);
 }
 
+#[test]
+fn test_withdraw_fee_booked_to_treasury_category() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, owner, user, token_client) = setup_test(&env);
+    client.initialize(&admin);
+
+    let treasury = env.register(MockStreamingTreasury, ());
+    let treasury_client = MockStreamingTreasuryClient::new(&env, &treasury);
+    client.set_fee_config(&admin, &500, &treasury);
+    client.set_fee_category(&admin, &Some(symbol_short!("ops")));
+    assert_eq!(client.get_fee_category(), Some(symbol_short!("ops")));
+
+    let project_id = client.create_project(
+        &owner,
+        &symbol_short!("Test"),
+        &1_000_000,
+        &token_client.address,
+    );
+    client.deposit(&user, &project_id, &500_000);
+    client.approve_milestone(&admin, &project_id, &0);
+
+    client.withdraw(&project_id, &0, &100_000);
+    assert_eq!(token_client.balance(&treasury), 5_000);
+    assert_eq!(treasury_client.get_income(&symbol_short!("ops")), 5_000);
+
+    // Without a category the fee is still paid but not booked.
+    client.set_fee_category(&admin, &None);
+    client.withdraw(&project_id, &0, &100_000);
+    assert_eq!(token_client.balance(&treasury), 10_000);
+    assert_eq!(treasury_client.get_income(&symbol_short!("ops")), 5_000);
+}
+
+#[test]
+fn test_streams_and_fees_against_the_real_treasury() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, owner, user, token_client) = setup_test(&env);
+    client.initialize(&admin);
+    let treasury = env.register(treasury::TreasuryContract, ());
+    let treasury_client = treasury::TreasuryContractClient::new(&env, &treasury);
+    treasury_client.initialize(&admin, &token_client.address);
+    let milestones = symbol_short!("milestone");
+    treasury_client.set_category(&admin, &milestones, &1_000_000, &86_400);
+
+    let project_id = client.create_project(
+        &owner,
+        &symbol_short!("Stream"),
+        &1_000_000,
+        &token_client.address,
+    );
+    client.deposit(&user, &project_id, &500_000);
+    client.approve_milestone(&admin, &project_id, &0);
+
+    assert_eq!(
+        client.try_allocate_to_streaming_treasury(
+            &admin,
+            &project_id,
+            &0,
+            &treasury,
+            &100_000,
+            &1000
+        ),
+        Err(Ok(CrowdfundError::StreamCategoryNotSet))
+    );
+    client.set_stream_category(&admin, &Some(milestones.clone()));
+    let stream_id =
+        client.allocate_to_streaming_treasury(&admin, &project_id, &0, &treasury, &100_000, &1000);
+    assert_eq!(treasury_client.get_stream(&stream_id).beneficiary, owner);
+    let report = treasury_client.get_category_report(&milestones, &token_client.address);
+    assert_eq!(report.allocated, 100_000);
+    assert!(treasury_client
+        .try_get_category(&symbol_short!("grants"))
+        .is_err());
+
+    // A fee the treasury will not book is still paid out.
+    let ops = symbol_short!("ops");
+    client.set_fee_config(&admin, &500, &treasury);
+    client.set_fee_category(&admin, &Some(ops.clone()));
+    client.withdraw(&project_id, &0, &100_000);
+    let failed = Symbol::new(&env, "fee_income_record_failed_event");
+    assert!(env.events().all().iter().any(|(contract, topics, _)| {
+        contract == client.address
+            && Symbol::try_from_val(&env, &topics.get(0).unwrap()).ok() == Some(failed.clone())
+    }));
+    assert_eq!(token_client.balance(&owner), 95_000);
+    assert_eq!(token_client.balance(&treasury), 105_000);
+
+    treasury_client.set_category(&admin, &ops, &1_000_000, &86_400);
+    treasury_client.set_income_source(&admin, &client.address, &true);
+    client.withdraw(&project_id, &0, &100_000);
+    let report = treasury_client.get_category_report(&ops, &token_client.address);
+    assert_eq!(report.income, 5_000);
+}
+
+/// Subscriber that keeps the payload of the last notification per event type.
+#[contract]
+pub struct MockSubscriber;
+
+#[contractimpl]
+impl MockSubscriber {
+    pub fn on_notify(env: Env, notification: notification_interface::Notification) {
+        env.storage()
+            .persistent()
+            .set(&notification.event_type, &notification.data);
+    }
+
+    pub fn last(env: Env, event_type: Symbol) -> soroban_sdk::Bytes {
+        env.storage().persistent().get(&event_type).unwrap()
+    }
+}
+
+#[test]
+fn test_withdraw_notification_reports_net_amount() {
+    use soroban_sdk::xdr::FromXdr;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, owner, user, token_client) = setup_test(&env);
+    client.initialize(&admin);
+    client.set_fee_config(&admin, &500, &Address::generate(&env));
+
+    let subscriber = env.register(MockSubscriber, ());
+    client.add_subscriber(&admin, &subscriber);
+
+    let project_id = client.create_project(
+        &owner,
+        &symbol_short!("Test"),
+        &1_000_000,
+        &token_client.address,
+    );
+    client.deposit(&user, &project_id, &500_000);
+    client.approve_milestone(&admin, &project_id, &0);
+    client.withdraw(&project_id, &0, &100_000);
+
+    let data = MockSubscriberClient::new(&env, &subscriber).last(&Symbol::new(&env, "withdraw"));
+    let (account, notified_project, amount, token) =
+        <(Address, u64, i128, Address)>::from_xdr(&env, &data).unwrap();
+    assert_eq!(account, owner);
+    assert_eq!(notified_project, project_id);
+    assert_eq!(amount, 95_000);
+    assert_eq!(token, token_client.address);
+}
+
 // ---------------------------------------------------------------------------
 // TTL / storage-rent tests
 // ---------------------------------------------------------------------------
@@ -2527,3 +2874,71 @@ fn test_withdraw_cei_state_written_before_balance_assertion() {
     assert_eq!(client.get_balance(&project_id), 300_000);
     assert_eq!(token_client.balance(&owner), 200_000);
 }
+
+// ===== Gasless (signed intent) entrypoints =====
+
+#[test]
+fn test_deposit_with_sig_pays_relayer_fee_and_advances_nonce() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, owner, user, token_client) = setup_test(&env);
+    client.initialize(&admin);
+
+    let project_id = client.create_project(
+        &owner,
+        &symbol_short!("Gasless"),
+        &1_000_000,
+        &token_client.address,
+    );
+
+    let relayer = Address::generate(&env);
+    let fee = Some(signed_intent::RelayerFee {
+        relayer: relayer.clone(),
+        amount: 1_000,
+    });
+
+    assert_eq!(client.get_signed_nonce(&user), 0);
+    client.deposit_with_sig(&user, &project_id, &200_000, &fee);
+
+    assert_eq!(client.get_balance(&project_id), 200_000);
+    assert_eq!(token_client.balance(&relayer), 1_000);
+    assert_eq!(token_client.balance(&user), 10_000_000 - 200_000 - 1_000);
+    assert_eq!(client.get_signed_nonce(&user), 1);
+
+    // A negative fee is rejected and nothing is committed.
+    let bad_fee = Some(signed_intent::RelayerFee {
+        relayer,
+        amount: -5,
+    });
+    assert_eq!(
+        client.try_deposit_with_sig(&user, &project_id, &100, &bad_fee),
+        Err(Ok(CrowdfundError::InvalidRelayerFee))
+    );
+    assert_eq!(client.get_signed_nonce(&user), 1);
+    assert_eq!(client.get_balance(&project_id), 200_000);
+}
+
+#[test]
+fn test_vote_milestone_with_sig_records_vote() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, owner, user, token_client) = setup_test(&env);
+    client.initialize(&admin);
+
+    let project_id = client.create_project(
+        &owner,
+        &symbol_short!("Voting"),
+        &1_000_000,
+        &token_client.address,
+    );
+    client.deposit(&user, &project_id, &100_000);
+    client.start_milestone_vote(&project_id, &0, &3600);
+
+    client.vote_milestone_with_sig(&user, &project_id, &0, &true, &None);
+    assert_eq!(client.get_signed_nonce(&user), 1);
+
+    let result = client.try_vote_milestone(&user, &project_id, &0, &true);
+    assert_eq!(result, Err(Ok(CrowdfundError::AlreadyVoted)));
+}
@@ -1,14 +1,37 @@
-use soroban_sdk::{contractclient, Address, Env};
+#![allow(clippy::too_many_arguments)]
+
+use soroban_sdk::{contractclient, Address, Env, Symbol};
+
+/// `TreasuryError::StreamNotFound`: the stream was fully claimed or already
+/// cancelled.
+pub const STREAM_NOT_FOUND: u32 = 7;
 
 #[allow(dead_code)]
 #[contractclient(name = "TreasuryClient")]
 pub trait TreasuryTrait {
-    fn allocate_budget(
+    fn allocate_from_reserves(
         env: Env,
         admin: Address,
+        category: Symbol,
+        token: Address,
         beneficiary: Address,
         amount: i128,
         start_time: u64,
         duration: u64,
+    ) -> Result<u64, soroban_sdk::Val>;
+
+    fn cancel_stream(
+        env: Env,
+        admin: Address,
+        stream_id: u64,
+        refund_to: Address,
+    ) -> Result<i128, soroban_sdk::Val>;
+
+    fn record_income(
+        env: Env,
+        source: Address,
+        category: Symbol,
+        token: Address,
+        amount: i128,
     ) -> Result<(), soroban_sdk::Val>;
 }
@@ -14,4 +14,19 @@ pub enum CurationError {
     InsufficientReputation = 8,
     InvalidMetadata = 9,
     Unauthorized = 10,
+    PermissionDenied = 11,
+    InvalidRelayerFee = 12,
+    InvalidSlashSplit = 13,
+    NothingToClaim = 14,
+    InvalidParams = 15,
+    VotingStarted = 16,
+    AlreadyResubmitted = 17,
+    NotRejected = 18,
+    NotResolved = 19,
+    NotVoted = 20,
+    AlreadySettled = 21,
+    InvalidUrl = 22,
+    InvalidFundingAddress = 23,
+    DuplicateFundingAddress = 24,
+    AmendmentClosed = 25,
 }
@@ -1,6 +1,6 @@
 use soroban_sdk::{symbol_short, Address, Env};
 
-use crate::types::ProjectMetadata;
+use crate::types::{AmendmentPolicy, CurationParams, ProjectMetadata, SlashSplit};
 
 pub fn emit_project_proposed(
     env: &Env,
@@ -41,3 +41,74 @@ pub fn emit_proposal_expired(env: &Env, project_id: u64) {
     env.events()
         .publish((symbol_short!("expired"), project_id), ());
 }
+
+pub fn emit_permission_registry_set(env: &Env, registry: &Option<Address>) {
+    env.events()
+        .publish((symbol_short!("perm_reg"),), registry.clone());
+}
+
+pub fn emit_deposit_slashed(
+    env: &Env,
+    project_id: u64,
+    reviewer_pool: i128,
+    treasury_amount: i128,
+    burned: i128,
+) {
+    env.events().publish(
+        (symbol_short!("slashed"), project_id),
+        (reviewer_pool, treasury_amount, burned),
+    );
+}
+
+pub fn emit_curation_reward_claimed(env: &Env, project_id: u64, voter: &Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("reward"), project_id),
+        (voter.clone(), amount),
+    );
+}
+
+pub fn emit_slash_config_set(env: &Env, treasury: &Option<Address>, split: &SlashSplit) {
+    env.events().publish(
+        (symbol_short!("slash_cfg"),),
+        (treasury.clone(), split.clone()),
+    );
+}
+
+pub fn emit_params_updated(env: &Env, params: &CurationParams) {
+    env.events()
+        .publish((symbol_short!("params"),), params.clone());
+}
+
+pub fn emit_proposal_amended(env: &Env, project_id: u64, amendment: u32, votes_reset: bool) {
+    env.events().publish(
+        (symbol_short!("amended"), project_id),
+        (amendment, votes_reset),
+    );
+}
+
+pub fn emit_proposal_withdrawn(env: &Env, project_id: u64, refunded: i128) {
+    env.events()
+        .publish((symbol_short!("withdrawn"), project_id), refunded);
+}
+
+pub fn emit_proposal_resubmitted(env: &Env, project_id: u64, previous_id: u64) {
+    env.events()
+        .publish((symbol_short!("resubmit"), project_id), previous_id);
+}
+
+pub fn emit_amendment_policy_set(env: &Env, policy: &AmendmentPolicy) {
+    env.events()
+        .publish((symbol_short!("amend_pol"),), policy.clone());
+}
+
+pub fn emit_review_settled(env: &Env, project_id: u64, voter: &Address, aligned: bool) {
+    env.events().publish(
+        (symbol_short!("settled"), project_id),
+        (voter.clone(), aligned),
+    );
+}
+
+pub fn emit_reputation_receiver_set(env: &Env, receiver: &Option<Address>) {
+    env.events()
+        .publish((symbol_short!("rep_recv"),), receiver.clone());
+}
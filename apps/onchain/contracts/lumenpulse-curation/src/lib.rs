@@ -6,17 +6,26 @@ mod storage;
 mod types;
 
 pub use errors::CurationError;
-pub use types::{ProjectMetadata, ProjectStatus, ProposalState, VoteRecord};
+pub use types::{
+    AmendmentPolicy, CurationParams, DelegationScope, ProjectMetadata, ProjectStatus,
+    ProposalState, RelayerFee, ReviewerStats, SlashSplit, VoteRecord,
+};
 
-use soroban_sdk::{contract, contractimpl, token, Address, Env};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{contract, contractimpl, token, Address, Env, IntoVal, Symbol, Vec};
+use types::Notification;
 
 use events::*;
 use storage::*;
 
 // ─── Constants ───────────────────────────────────────────────────────────────
+//
+// Defaults for `CurationParams`, applied until governance calls
+// `update_params`.
 
 /// Minimum XLM deposit (in stroops) required to propose a project.
-/// Returned if the project is verified; burned/redistributed otherwise.
+/// Returned, less the YES voters' reward, if the project is verified; split
+/// between NO voters, the treasury and a burn per the `SlashSplit` otherwise.
 const PROPOSAL_DEPOSIT_STROOPS: i128 = 10_000_000; // 1 XLM
 
 /// Fraction of total reputation that must vote YES to auto-verify.
@@ -29,6 +38,43 @@ const VOTING_WINDOW_LEDGERS: u32 = 120_960;
 /// Minimum absolute YES votes before threshold math kicks in.
 const MIN_YES_VOTES: u32 = 5;
 
+/// Share of a verified proposal's deposit paid to its YES voters.
+const REVIEWER_REWARD_BPS: u32 = 1_000;
+
+/// Share of the deposit refunded when a proposal is withdrawn before any
+/// vote; the rest is forfeited like a rejected deposit's treasury share.
+const WITHDRAW_REFUND_BPS: u32 = 8_000;
+
+/// Amendments close once less than this share of the voting window is left,
+/// so voters see the final text before the deadline.
+const AMENDMENT_CUTOFF_BPS: u32 = 2_500;
+
+/// Longest resubmission chain returned by `get_proposal_history`.
+const MAX_HISTORY: u32 = 20;
+
+// Bounds enforced by `update_params`.
+
+/// Largest deposit governance may require (10_000 XLM).
+const MAX_DEPOSIT_STROOPS: i128 = 100_000_000_000;
+/// Shortest and longest voting windows (~1 day and ~30 days).
+const MIN_VOTING_WINDOW_LEDGERS: u32 = 17_280;
+const MAX_VOTING_WINDOW_LEDGERS: u32 = 518_400;
+/// Most of a verified deposit that may go to reviewers.
+const MAX_REVIEWER_REWARD_BPS: u32 = 5_000;
+
+/// Notification event types sent to the reputation receiver when a vote is
+/// settled; contributor_registry scores them per its notification rules.
+const EVENT_REVIEW_ALIGNED: &str = "review_aligned";
+const EVENT_REVIEW_MISALIGNED: &str = "review_misaligned";
+
+/// Permissions checked against the permission registry.  The names match
+/// `permission_interface`, which targets a newer SDK.
+const PERMISSION_PROPOSE_PROJECT: &str = "propose_project";
+const PERMISSION_REVIEW: &str = "review";
+
+/// Basis-point denominator for `SlashSplit`.
+const BPS_DENOMINATOR: u32 = 10_000;
+
 // ─── Contract ────────────────────────────────────────────────────────────────
 
 #[contract]
@@ -67,7 +113,7 @@ impl CommunityCurationContract {
     /// Propose a new project for community curation.
     ///
     /// Caller must:
-    ///   1. Approve `PROPOSAL_DEPOSIT_STROOPS` of the deposit token to this contract.
+    ///   1. Approve the current deposit (`get_deposit_amount`) of the deposit token to this contract.
     ///   2. Call this function — the deposit is pulled atomically.
     ///
     /// Returns the newly assigned `project_id`.
@@ -77,45 +123,152 @@ impl CommunityCurationContract {
         metadata: ProjectMetadata,
     ) -> Result<u64, CurationError> {
         proposer.require_auth();
+        Self::create_proposal(&env, proposer, metadata, None)
+    }
 
-        // Validate metadata
-        validate_metadata(&metadata)?;
+    /// Resubmit a rejected proposal with (possibly revised) metadata.  The
+    /// new proposal links back to `previous_id` so voters can review its
+    /// history, and a fresh deposit is pulled.  Each rejected proposal can
+    /// be resubmitted once.
+    pub fn resubmit_proposal(
+        env: Env,
+        proposer: Address,
+        previous_id: u64,
+        metadata: ProjectMetadata,
+    ) -> Result<u64, CurationError> {
+        proposer.require_auth();
+        let mut previous = get_proposal(&env, previous_id).ok_or(CurationError::ProjectNotFound)?;
+        if previous.proposer != proposer {
+            return Err(CurationError::Unauthorized);
+        }
+        if previous.status != ProjectStatus::Rejected {
+            return Err(CurationError::NotRejected);
+        }
+        if previous.resubmitted_as.is_some() {
+            return Err(CurationError::AlreadyResubmitted);
+        }
 
-        // Pull deposit
-        let token_client = token::Client::new(&env, &get_deposit_token(&env));
-        token_client.transfer(
-            &proposer,
-            &env.current_contract_address(),
-            &PROPOSAL_DEPOSIT_STROOPS,
-        );
+        let project_id = Self::create_proposal(&env, proposer, metadata, Some(previous_id))?;
+        previous.resubmitted_as = Some(project_id);
+        save_proposal(&env, previous_id, &previous);
+        emit_proposal_resubmitted(&env, project_id, previous_id);
+        Ok(project_id)
+    }
 
-        // Assign ID and persist
-        let project_id = get_next_project_id(&env);
-        set_next_project_id(&env, project_id + 1);
+    /// Amend a pending proposal's metadata.  Depending on the
+    /// `AmendmentPolicy`, votes already cast either stand or are discarded;
+    /// the voting window is never extended.  Amendments close once a NO vote
+    /// has been cast in the current round or the last
+    /// `AMENDMENT_CUTOFF_BPS` of the window has begun, so a losing proposal
+    /// cannot be reset to dodge rejection.
+    pub fn amend_proposal(
+        env: Env,
+        proposer: Address,
+        project_id: u64,
+        metadata: ProjectMetadata,
+    ) -> Result<(), CurationError> {
+        proposer.require_auth();
+        let mut proposal = get_proposal(&env, project_id).ok_or(CurationError::ProjectNotFound)?;
+        if proposal.proposer != proposer {
+            return Err(CurationError::Unauthorized);
+        }
+        if proposal.status != ProjectStatus::Pending {
+            return Err(CurationError::VotingClosed);
+        }
+        let now = env.ledger().sequence();
+        if now > proposal.voting_ends_ledger {
+            return Err(CurationError::VotingWindowExpired);
+        }
+        let remaining = (proposal.voting_ends_ledger - now) as u64;
+        let cutoff = proposal.params.voting_window_ledgers as u64 * AMENDMENT_CUTOFF_BPS as u64
+            / BPS_DENOMINATOR as u64;
+        if proposal.no_votes > 0 || remaining < cutoff {
+            return Err(CurationError::AmendmentClosed);
+        }
+        validate_metadata(&env, &metadata)?;
 
-        let proposal = ProposalState {
-            project_id,
-            proposer: proposer.clone(),
-            metadata: metadata.clone(),
-            status: ProjectStatus::Pending,
-            yes_votes: 0,
-            no_votes: 0,
-            total_voting_power_snapshot: 0, // filled lazily on first vote
-            deposit_returned: false,
-            created_ledger: env.ledger().sequence(),
-            voting_ends_ledger: env.ledger().sequence() + VOTING_WINDOW_LEDGERS,
+        let funding_changed = metadata.funding_address != proposal.metadata.funding_address;
+        if funding_changed {
+            claim_funding_address(&env, &metadata.funding_address, project_id)?;
+            remove_funding_address_owner(&env, &proposal.metadata.funding_address);
+        }
+        let material = funding_changed
+            || metadata.url != proposal.metadata.url
+            || metadata.content_hash != proposal.metadata.content_hash;
+        let reset = match get_amendment_policy(&env) {
+            AmendmentPolicy::PreserveVotes => false,
+            AmendmentPolicy::ResetVotes => true,
+            AmendmentPolicy::ResetOnMaterialChange => material,
         };
-
+        if reset {
+            proposal.round += 1;
+            proposal.yes_votes = 0;
+            proposal.no_votes = 0;
+        }
+        proposal.metadata = metadata;
+        proposal.amendments += 1;
         save_proposal(&env, project_id, &proposal);
 
-        emit_project_proposed(&env, project_id, &proposer, &metadata);
+        emit_proposal_amended(&env, project_id, proposal.amendments, reset);
+        Ok(())
+    }
 
-        Ok(project_id)
+    /// Withdraw a pending proposal nobody has voted on yet.  The proposer
+    /// gets `WITHDRAW_REFUND_BPS` of the deposit back; the rest goes to the
+    /// treasury, or is burned if none is configured.  Returns the refund.
+    pub fn withdraw_proposal(
+        env: Env,
+        proposer: Address,
+        project_id: u64,
+    ) -> Result<i128, CurationError> {
+        proposer.require_auth();
+        let mut proposal = get_proposal(&env, project_id).ok_or(CurationError::ProjectNotFound)?;
+        if proposal.proposer != proposer {
+            return Err(CurationError::Unauthorized);
+        }
+        if proposal.status != ProjectStatus::Pending {
+            return Err(CurationError::VotingClosed);
+        }
+        if proposal.votes_cast > 0 {
+            return Err(CurationError::VotingStarted);
+        }
+
+        let deposit = proposal.params.deposit;
+        let refund = deposit * WITHDRAW_REFUND_BPS as i128 / BPS_DENOMINATOR as i128;
+        let forfeited = deposit - refund;
+        let token_client = token::Client::new(&env, &get_deposit_token(&env));
+        token_client.transfer(&env.current_contract_address(), &proposer, &refund);
+        if forfeited > 0 {
+            match get_treasury(&env) {
+                Some(treasury) => {
+                    token_client.transfer(&env.current_contract_address(), &treasury, &forfeited)
+                }
+                None => token_client.burn(&env.current_contract_address(), &forfeited),
+            }
+        }
+
+        proposal.status = ProjectStatus::Withdrawn;
+        proposal.deposit_returned = true;
+        save_proposal(&env, project_id, &proposal);
+        emit_proposal_withdrawn(&env, project_id, refund);
+        Ok(refund)
+    }
+
+    /// Set how amendments treat votes already cast.
+    pub fn set_amendment_policy(env: Env, policy: AmendmentPolicy) -> Result<(), CurationError> {
+        if !has_admin(&env) {
+            return Err(CurationError::NotInitialized);
+        }
+        get_admin(&env).require_auth();
+        set_amendment_policy(&env, &policy);
+        emit_amendment_policy_set(&env, &policy);
+        Ok(())
     }
 
     /// Cast a vote on a pending project.
     ///
-    /// Voting power = the voter's reputation score from the contributor-registry.
+    /// Voting power = the voter's reputation plus any reputation delegated to
+    /// them, read from the contributor-registry as of the proposal's creation.
     /// Each address may vote exactly once per project.
     ///
     /// * `approve` – `true` = YES (verify), `false` = NO (reject).
@@ -126,73 +279,132 @@ impl CommunityCurationContract {
         approve: bool,
     ) -> Result<(), CurationError> {
         voter.require_auth();
+        Self::cast_vote(&env, voter, project_id, approve)
+    }
 
-        let mut proposal = get_proposal(&env, project_id).ok_or(CurationError::ProjectNotFound)?;
+    /// Relayer-submitted variant of [`vote_to_verify`].  `voter` signs
+    /// `("vote_to_verify_with_sig", voter, project_id, approve, fee, nonce)`
+    /// off-chain, where `nonce` is [`get_signed_nonce`]; the optional `fee`
+    /// is paid to the relayer in the deposit token once the vote is recorded.
+    ///
+    /// This mirrors the shared `signed-intent` helper used by the other
+    /// contracts, which targets a newer SDK than this one.
+    pub fn vote_to_verify_with_sig(
+        env: Env,
+        voter: Address,
+        project_id: u64,
+        approve: bool,
+        fee: Option<RelayerFee>,
+    ) -> Result<(), CurationError> {
+        let nonce = get_signed_nonce(&env, &voter);
+        voter.require_auth_for_args(
+            (
+                Symbol::new(&env, "vote_to_verify_with_sig"),
+                voter.clone(),
+                project_id,
+                approve,
+                fee.clone(),
+                nonce,
+            )
+                .into_val(&env),
+        );
+        set_signed_nonce(&env, &voter, nonce + 1);
 
-        // Only vote on pending proposals within the window
-        if proposal.status != ProjectStatus::Pending {
-            return Err(CurationError::VotingClosed);
-        }
-        if env.ledger().sequence() > proposal.voting_ends_ledger {
-            return Err(CurationError::VotingWindowExpired);
+        if fee.as_ref().is_some_and(|fee| fee.amount < 0) {
+            return Err(CurationError::InvalidRelayerFee);
         }
 
-        // Prevent double-voting
-        if has_voted(&env, project_id, &voter) {
-            return Err(CurationError::AlreadyVoted);
-        }
+        Self::cast_vote(&env, voter.clone(), project_id, approve)?;
 
-        // Fetch voting power from contributor-registry
-        let voting_power = Self::get_reputation(&env, &voter);
-        if voting_power == 0 {
-            return Err(CurationError::InsufficientReputation);
+        if let Some(fee) = fee.filter(|fee| fee.amount > 0) {
+            token::Client::new(&env, &get_deposit_token(&env)).transfer(
+                &voter,
+                &fee.relayer,
+                &fee.amount,
+            );
         }
+        Ok(())
+    }
 
-        // Snapshot total voting power on first vote (gas-efficient approximation)
-        if proposal.total_voting_power_snapshot == 0 {
-            proposal.total_voting_power_snapshot = Self::get_total_reputation(&env);
+    /// Claim a winning-side voter's share of a resolved proposal's reviewer
+    /// pool, pro-rata to the voting power they voted with: YES voters of a
+    /// verified proposal, NO voters of a rejected one.  Settles the vote
+    /// first if nobody has yet.  Returns the amount paid.
+    pub fn claim_curation_reward(
+        env: Env,
+        voter: Address,
+        project_id: u64,
+    ) -> Result<i128, CurationError> {
+        voter.require_auth();
+        if !is_review_settled(&env, project_id, &voter) {
+            Self::settle_review(env.clone(), voter.clone(), project_id)?;
+        }
+        let amount = Self::get_claimable_reward(env.clone(), project_id, voter.clone());
+        if amount == 0 {
+            return Err(CurationError::NothingToClaim);
         }
+        set_reward_claimed(&env, project_id, &voter);
+        token::Client::new(&env, &get_deposit_token(&env)).transfer(
+            &env.current_contract_address(),
+            &voter,
+            &amount,
+        );
+        emit_curation_reward_claimed(&env, project_id, &voter, amount);
+        Ok(amount)
+    }
 
-        // Record vote
-        record_vote(&env, project_id, &voter);
-        let vote_record = VoteRecord {
-            voter: voter.clone(),
-            project_id,
-            approve,
-            voting_power,
-            ledger: env.ledger().sequence(),
+    /// Record whether `voter` sided with a resolved proposal's outcome,
+    /// update their `ReviewerStats` and notify the reputation receiver.
+    /// Anyone may settle a vote, so misaligned voters cannot dodge it by
+    /// staying away.  Returns `true` if the vote was aligned.
+    pub fn settle_review(env: Env, voter: Address, project_id: u64) -> Result<bool, CurationError> {
+        let proposal = get_proposal(&env, project_id).ok_or(CurationError::ProjectNotFound)?;
+        let outcome = match proposal.status {
+            ProjectStatus::Verified => true,
+            ProjectStatus::Rejected => false,
+            _ => return Err(CurationError::NotResolved),
         };
-        save_vote_record(&env, project_id, &voter, &vote_record);
+        let record = get_vote_record(&env, project_id, proposal.round, &voter)
+            .ok_or(CurationError::NotVoted)?;
+        if is_review_settled(&env, project_id, &voter) {
+            return Err(CurationError::AlreadySettled);
+        }
+        set_review_settled(&env, project_id, &voter);
 
-        if approve {
-            proposal.yes_votes = proposal.yes_votes.saturating_add(voting_power);
+        let aligned = record.approve == outcome;
+        let mut stats = get_reviewer_stats(&env, &voter);
+        if aligned {
+            stats.aligned += 1;
         } else {
-            proposal.no_votes = proposal.no_votes.saturating_add(voting_power);
+            stats.misaligned += 1;
         }
-
-        // Check auto-verification threshold
-        let status_changed = Self::evaluate_threshold(&env, &mut proposal);
-
-        save_proposal(&env, project_id, &proposal);
-
-        emit_vote_cast(&env, project_id, &voter, approve, voting_power);
-
-        if status_changed {
-            match proposal.status {
-                ProjectStatus::Verified => {
-                    emit_project_verified(&env, project_id);
-                    // Return deposit to proposer
-                    Self::return_deposit_inner(&env, &mut proposal);
-                    save_proposal(&env, project_id, &proposal);
-                }
-                ProjectStatus::Rejected => {
-                    emit_project_rejected(&env, project_id);
-                }
-                _ => {}
-            }
+        set_reviewer_stats(&env, &voter, &stats);
+
+        if let Some(receiver) = get_reputation_receiver(&env) {
+            let event_type = if aligned {
+                EVENT_REVIEW_ALIGNED
+            } else {
+                EVENT_REVIEW_MISALIGNED
+            };
+            let notification = Notification {
+                source: env.current_contract_address(),
+                event_type: Symbol::new(&env, event_type),
+                data: (voter.clone(), project_id, record.voting_power as i128).to_xdr(&env),
+            };
+            env.invoke_contract::<()>(
+                &receiver,
+                &Symbol::new(&env, "on_notify"),
+                soroban_sdk::vec![&env, notification.into_val(&env)],
+            );
         }
 
-        Ok(())
+        emit_review_settled(&env, project_id, &voter, aligned);
+        Ok(aligned)
+    }
+
+    /// Next nonce `signer` must use for a signed intent.
+    pub fn get_signed_nonce(env: Env, signer: Address) -> u64 {
+        get_signed_nonce(&env, &signer)
     }
 
     /// Finalise a proposal whose voting window has expired without hitting a
@@ -208,8 +420,9 @@ impl CommunityCurationContract {
             return Err(CurationError::VotingWindowNotExpired);
         }
 
-        // Expired without threshold → Rejected; deposit burned (stays in contract)
+        // Expired without threshold → Rejected; deposit forfeited
         proposal.status = ProjectStatus::Rejected;
+        Self::slash_deposit_inner(&env, &mut proposal);
         save_proposal(&env, project_id, &proposal);
         emit_proposal_expired(&env, project_id);
 
@@ -229,14 +442,97 @@ impl CommunityCurationContract {
         }
 
         proposal.status = ProjectStatus::Rejected;
+        Self::slash_deposit_inner(&env, &mut proposal);
         save_proposal(&env, project_id, &proposal);
         emit_project_rejected(&env, project_id);
         Ok(())
     }
 
+    /// Update the curation parameters.  Only proposals created afterwards
+    /// use the new values; pending proposals keep the ones they were
+    /// created with.
+    pub fn update_params(env: Env, params: CurationParams) -> Result<(), CurationError> {
+        if !has_admin(&env) {
+            return Err(CurationError::NotInitialized);
+        }
+        get_admin(&env).require_auth();
+        if params.deposit <= 0
+            || params.deposit > MAX_DEPOSIT_STROOPS
+            || params.verify_threshold_bps == 0
+            || params.verify_threshold_bps > BPS_DENOMINATOR
+            || params.voting_window_ledgers < MIN_VOTING_WINDOW_LEDGERS
+            || params.voting_window_ledgers > MAX_VOTING_WINDOW_LEDGERS
+            || params.min_yes_votes == 0
+            || params.reviewer_reward_bps > MAX_REVIEWER_REWARD_BPS
+        {
+            return Err(CurationError::InvalidParams);
+        }
+        set_params(&env, &params);
+        emit_params_updated(&env, &params);
+        Ok(())
+    }
+
+    /// Configure where forfeited deposits of rejected proposals go.  The
+    /// split must sum to 10_000 bps.  Without a treasury its share is burned.
+    pub fn set_slash_config(
+        env: Env,
+        treasury: Option<Address>,
+        split: SlashSplit,
+    ) -> Result<(), CurationError> {
+        if !has_admin(&env) {
+            return Err(CurationError::NotInitialized);
+        }
+        get_admin(&env).require_auth();
+        let total = split.no_voters_bps as u64 + split.treasury_bps as u64 + split.burn_bps as u64;
+        if total != BPS_DENOMINATOR as u64 {
+            return Err(CurationError::InvalidSlashSplit);
+        }
+        set_treasury(&env, &treasury);
+        set_slash_split(&env, &split);
+        emit_slash_config_set(&env, &treasury, &split);
+        Ok(())
+    }
+
+    /// Set (or clear) the contract notified of settled votes, typically the
+    /// contributor-registry.  It must list this contract as a trusted
+    /// notification source and configure rules for `review_aligned` and
+    /// `review_misaligned`.
+    pub fn set_reputation_receiver(
+        env: Env,
+        receiver: Option<Address>,
+    ) -> Result<(), CurationError> {
+        if !has_admin(&env) {
+            return Err(CurationError::NotInitialized);
+        }
+        get_admin(&env).require_auth();
+        set_reputation_receiver(&env, &receiver);
+        emit_reputation_receiver_set(&env, &receiver);
+        Ok(())
+    }
+
+    /// Set (or clear) the contract consulted for tier/badge-gated permissions
+    /// (`propose_project` to propose, `review` to vote).  Typically the same
+    /// contributor-registry that supplies reputation.
+    pub fn set_permission_registry(
+        env: Env,
+        registry: Option<Address>,
+    ) -> Result<(), CurationError> {
+        if !has_admin(&env) {
+            return Err(CurationError::NotInitialized);
+        }
+        get_admin(&env).require_auth();
+        set_permission_registry(&env, &registry);
+        emit_permission_registry_set(&env, &registry);
+        Ok(())
+    }
+
     // ── Queries ──────────────────────────────────────────────────────────────
 
     /// Returns `true` if the project has Verified status (eligible for matching).
+    ///
+    /// Matches `verification_interface::VerificationSourceTrait`, so
+    /// `verification_aggregator` can query this contract alongside
+    /// project_registry.
     pub fn is_verified(env: Env, project_id: u64) -> bool {
         get_proposal(&env, project_id)
             .map(|p| p.status == ProjectStatus::Verified)
@@ -248,33 +544,273 @@ impl CommunityCurationContract {
         get_proposal(&env, project_id)
     }
 
-    /// Voter's record for a given project (None = not voted).
+    /// Voter's record in the proposal's current round (None = not voted).
     pub fn get_vote(env: Env, project_id: u64, voter: Address) -> Option<VoteRecord> {
-        get_vote_record(&env, project_id, &voter)
+        let round = get_proposal(&env, project_id)?.round;
+        get_vote_record(&env, project_id, round, &voter)
     }
 
-    pub fn get_deposit_amount(_env: Env) -> i128 {
-        PROPOSAL_DEPOSIT_STROOPS
+    /// Ids in the proposal's resubmission chain, oldest first, ending with
+    /// `project_id` (at most `MAX_HISTORY`).
+    pub fn get_proposal_history(env: Env, project_id: u64) -> Vec<u64> {
+        let mut history = Vec::new(&env);
+        let mut next = Some(project_id);
+        while let Some(id) = next {
+            if history.len() >= MAX_HISTORY {
+                break;
+            }
+            let Some(proposal) = get_proposal(&env, id) else {
+                break;
+            };
+            history.push_front(id);
+            next = proposal.previous_id;
+        }
+        history
     }
 
-    pub fn get_voting_window_ledgers(_env: Env) -> u32 {
-        VOTING_WINDOW_LEDGERS
+    pub fn get_amendment_policy(env: Env) -> AmendmentPolicy {
+        get_amendment_policy(&env)
     }
 
-    pub fn get_verify_threshold_bps(_env: Env) -> u32 {
-        VERIFY_THRESHOLD_BPS
+    pub fn get_permission_registry(env: Env) -> Option<Address> {
+        get_permission_registry(&env)
+    }
+
+    pub fn get_reputation_receiver(env: Env) -> Option<Address> {
+        get_reputation_receiver(&env)
+    }
+
+    /// Aligned / misaligned counts over `voter`'s settled votes.
+    pub fn get_reviewer_stats(env: Env, voter: Address) -> ReviewerStats {
+        get_reviewer_stats(&env, &voter)
+    }
+
+    /// Reward `voter` can still claim from a resolved proposal (0 if none).
+    pub fn get_claimable_reward(env: Env, project_id: u64, voter: Address) -> i128 {
+        let Some(proposal) = get_proposal(&env, project_id) else {
+            return 0;
+        };
+        let (winning_side, winning_votes) = match proposal.status {
+            ProjectStatus::Verified => (true, proposal.yes_votes),
+            ProjectStatus::Rejected => (false, proposal.no_votes),
+            _ => return 0,
+        };
+        if winning_votes == 0 || has_claimed_reward(&env, project_id, &voter) {
+            return 0;
+        }
+        match get_vote_record(&env, project_id, proposal.round, &voter) {
+            Some(record) if record.approve == winning_side => {
+                (proposal.reviewer_pool as u128 * record.voting_power as u128
+                    / winning_votes as u128) as i128
+            }
+            _ => 0,
+        }
+    }
+
+    pub fn get_slash_config(env: Env) -> (Option<Address>, SlashSplit) {
+        (get_treasury(&env), get_slash_split(&env))
+    }
+
+    /// Parameters that new proposals will be created with.
+    pub fn get_params(env: Env) -> CurationParams {
+        Self::current_params(&env)
+    }
+
+    pub fn get_deposit_amount(env: Env) -> i128 {
+        Self::current_params(&env).deposit
+    }
+
+    pub fn get_voting_window_ledgers(env: Env) -> u32 {
+        Self::current_params(&env).voting_window_ledgers
+    }
+
+    pub fn get_verify_threshold_bps(env: Env) -> u32 {
+        Self::current_params(&env).verify_threshold_bps
+    }
+
+    pub fn get_min_yes_votes(env: Env) -> u32 {
+        Self::current_params(&env).min_yes_votes
     }
 
     // ── Internal Helpers ─────────────────────────────────────────────────────
 
+    /// Validate, pull the current deposit and store a new pending proposal.
+    fn create_proposal(
+        env: &Env,
+        proposer: Address,
+        metadata: ProjectMetadata,
+        previous_id: Option<u64>,
+    ) -> Result<u64, CurationError> {
+        Self::require_permission(env, &proposer, PERMISSION_PROPOSE_PROJECT)?;
+
+        // Validate metadata
+        validate_metadata(env, &metadata)?;
+
+        // Pull deposit
+        let params = Self::current_params(env);
+        let token_client = token::Client::new(env, &get_deposit_token(env));
+        token_client.transfer(&proposer, &env.current_contract_address(), &params.deposit);
+
+        // Assign ID and persist
+        let project_id = get_next_project_id(env);
+        claim_funding_address(env, &metadata.funding_address, project_id)?;
+        set_next_project_id(env, project_id + 1);
+
+        let proposal = ProposalState {
+            project_id,
+            proposer: proposer.clone(),
+            metadata: metadata.clone(),
+            status: ProjectStatus::Pending,
+            yes_votes: 0,
+            no_votes: 0,
+            total_voting_power_snapshot: 0, // filled lazily on first vote
+            deposit_returned: false,
+            reviewer_pool: 0,
+            created_ledger: env.ledger().sequence(),
+            created_at: env.ledger().timestamp(),
+            voting_ends_ledger: env.ledger().sequence() + params.voting_window_ledgers,
+            params,
+            round: 0,
+            votes_cast: 0,
+            amendments: 0,
+            previous_id,
+            resubmitted_as: None,
+        };
+
+        save_proposal(env, project_id, &proposal);
+
+        emit_project_proposed(env, project_id, &proposer, &metadata);
+
+        Ok(project_id)
+    }
+
+    /// Parameters for new proposals: governance-set, else the defaults.
+    fn current_params(env: &Env) -> CurationParams {
+        get_params(env).unwrap_or(CurationParams {
+            deposit: PROPOSAL_DEPOSIT_STROOPS,
+            verify_threshold_bps: VERIFY_THRESHOLD_BPS,
+            voting_window_ledgers: VOTING_WINDOW_LEDGERS,
+            min_yes_votes: MIN_YES_VOTES,
+            reviewer_reward_bps: REVIEWER_REWARD_BPS,
+        })
+    }
+
+    fn cast_vote(
+        env: &Env,
+        voter: Address,
+        project_id: u64,
+        approve: bool,
+    ) -> Result<(), CurationError> {
+        Self::require_permission(env, &voter, PERMISSION_REVIEW)?;
+
+        let mut proposal = get_proposal(env, project_id).ok_or(CurationError::ProjectNotFound)?;
+
+        // Only vote on pending proposals within the window
+        if proposal.status != ProjectStatus::Pending {
+            return Err(CurationError::VotingClosed);
+        }
+        if env.ledger().sequence() > proposal.voting_ends_ledger {
+            return Err(CurationError::VotingWindowExpired);
+        }
+
+        // Prevent double-voting
+        if has_voted(env, project_id, proposal.round, &voter) {
+            return Err(CurationError::AlreadyVoted);
+        }
+
+        // Fetch voting power from contributor-registry
+        let voting_power = Self::get_voting_power(env, &voter, proposal.created_at);
+        if voting_power == 0 {
+            return Err(CurationError::InsufficientReputation);
+        }
+
+        // Snapshot total voting power on first vote (gas-efficient approximation)
+        if proposal.total_voting_power_snapshot == 0 {
+            proposal.total_voting_power_snapshot = Self::get_total_reputation(env);
+        }
+
+        // Record vote
+        record_vote(env, project_id, proposal.round, &voter);
+        let vote_record = VoteRecord {
+            voter: voter.clone(),
+            project_id,
+            approve,
+            voting_power,
+            ledger: env.ledger().sequence(),
+            round: proposal.round,
+        };
+        proposal.votes_cast += 1;
+        save_vote_record(env, project_id, &voter, &vote_record);
+
+        if approve {
+            proposal.yes_votes = proposal.yes_votes.saturating_add(voting_power);
+        } else {
+            proposal.no_votes = proposal.no_votes.saturating_add(voting_power);
+        }
+
+        // Check auto-verification threshold
+        let status_changed = Self::evaluate_threshold(env, &mut proposal);
+
+        save_proposal(env, project_id, &proposal);
+
+        emit_vote_cast(env, project_id, &voter, approve, voting_power);
+
+        if status_changed {
+            match proposal.status {
+                ProjectStatus::Verified => {
+                    emit_project_verified(env, project_id);
+                    // Return deposit to proposer
+                    Self::return_deposit_inner(env, &mut proposal);
+                    save_proposal(env, project_id, &proposal);
+                }
+                ProjectStatus::Rejected => {
+                    emit_project_rejected(env, project_id);
+                    Self::slash_deposit_inner(env, &mut proposal);
+                    save_proposal(env, project_id, &proposal);
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// When a permission registry is configured, require `who` to hold
+    /// `permission` there.
+    fn require_permission(env: &Env, who: &Address, permission: &str) -> Result<(), CurationError> {
+        if let Some(registry) = get_permission_registry(env) {
+            let allowed: bool = env.invoke_contract(
+                &registry,
+                &soroban_sdk::Symbol::new(env, "has_permission"),
+                soroban_sdk::vec![
+                    env,
+                    who.to_val(),
+                    soroban_sdk::Symbol::new(env, permission).to_val()
+                ],
+            );
+            if !allowed {
+                return Err(CurationError::PermissionDenied);
+            }
+        }
+        Ok(())
+    }
+
     /// Cross-contract call into contributor-registry to read a voter's reputation.
-    fn get_reputation(env: &Env, voter: &Address) -> u64 {
-        // contributor-registry exposes: get_reputation(address) -> u64
+    fn get_voting_power(env: &Env, voter: &Address, timestamp: u64) -> u64 {
+        // contributor-registry exposes:
+        // get_voting_power_in(address, scope, timestamp) -> u64
+        // (own reputation plus delegations, as of `timestamp`)
         let registry = get_contributor_registry(env);
+        let scope = DelegationScope::Contract(env.current_contract_address());
         env.invoke_contract(
             &registry,
-            &soroban_sdk::Symbol::new(env, "get_reputation"),
-            soroban_sdk::vec![env, voter.to_val()],
+            &soroban_sdk::Symbol::new(env, "get_voting_power_in"),
+            soroban_sdk::vec![
+                env,
+                voter.to_val(),
+                scope.into_val(env),
+                timestamp.into_val(env)
+            ],
         )
     }
 
@@ -305,7 +841,8 @@ impl CommunityCurationContract {
             .checked_div(total as u128)
             .unwrap_or(0) as u32;
 
-        if yes >= MIN_YES_VOTES as u64 && yes_bps >= VERIFY_THRESHOLD_BPS {
+        let params = &proposal.params;
+        if yes >= params.min_yes_votes as u64 && yes_bps >= params.verify_threshold_bps {
             proposal.status = ProjectStatus::Verified;
             return true;
         }
@@ -324,29 +861,134 @@ impl CommunityCurationContract {
         false
     }
 
-    /// Transfer the deposit back to the proposer (idempotent guard).
+    /// Split a rejected proposal's deposit: the treasury share is paid and
+    /// the burn share burned now, the NO voters' share is set aside for
+    /// `claim_curation_reward`.  With no NO votes (expiry or admin
+    /// rejection) the voters' share goes to the treasury instead.
+    fn slash_deposit_inner(env: &Env, proposal: &mut ProposalState) {
+        if proposal.deposit_returned {
+            return;
+        }
+        let split = get_slash_split(env);
+        let deposit = proposal.params.deposit;
+        let share = |bps: u32| deposit * bps as i128 / BPS_DENOMINATOR as i128;
+        let mut voter_pool = share(split.no_voters_bps);
+        let mut treasury_amount = share(split.treasury_bps);
+        if proposal.no_votes == 0 {
+            treasury_amount += voter_pool;
+            voter_pool = 0;
+        }
+        let treasury = get_treasury(env);
+        if treasury.is_none() {
+            treasury_amount = 0;
+        }
+        let burned = deposit - voter_pool - treasury_amount;
+
+        let token_client = token::Client::new(env, &get_deposit_token(env));
+        if let Some(treasury) = treasury {
+            if treasury_amount > 0 {
+                token_client.transfer(&env.current_contract_address(), &treasury, &treasury_amount);
+            }
+        }
+        if burned > 0 {
+            token_client.burn(&env.current_contract_address(), &burned);
+        }
+        proposal.reviewer_pool = voter_pool;
+        proposal.deposit_returned = true;
+        emit_deposit_slashed(
+            env,
+            proposal.project_id,
+            voter_pool,
+            treasury_amount,
+            burned,
+        );
+    }
+
+    /// Transfer the deposit back to the proposer, less the YES voters'
+    /// reviewer pool (idempotent guard).
     fn return_deposit_inner(env: &Env, proposal: &mut ProposalState) {
         if proposal.deposit_returned {
             return;
         }
+        let deposit = proposal.params.deposit;
+        let reviewer_pool = if proposal.yes_votes > 0 {
+            deposit * proposal.params.reviewer_reward_bps as i128 / BPS_DENOMINATOR as i128
+        } else {
+            0
+        };
         let token_client = token::Client::new(env, &get_deposit_token(env));
         token_client.transfer(
             &env.current_contract_address(),
             &proposal.proposer,
-            &PROPOSAL_DEPOSIT_STROOPS,
+            &(deposit - reviewer_pool),
         );
+        proposal.reviewer_pool = reviewer_pool;
         proposal.deposit_returned = true;
     }
 }
 
 // ─── Metadata Validation ─────────────────────────────────────────────────────
 
-fn validate_metadata(m: &ProjectMetadata) -> Result<(), CurationError> {
+/// Longest accepted `url`, in bytes.
+const MAX_URL_LEN: u32 = 256;
+
+/// Accepted `url` schemes; something must follow the scheme.
+const URL_SCHEMES: [&[u8]; 3] = [b"https://", b"ipfs://", b"ar://"];
+
+fn validate_metadata(env: &Env, m: &ProjectMetadata) -> Result<(), CurationError> {
     if m.name.is_empty() || m.name.len() > 100 {
         return Err(CurationError::InvalidMetadata);
     }
     if m.description.is_empty() || m.description.len() > 1000 {
         return Err(CurationError::InvalidMetadata);
     }
+    validate_url(&m.url)?;
+    if m.content_hash.as_ref().is_some_and(|hash| hash.len() != 32) {
+        return Err(CurationError::InvalidMetadata);
+    }
+    if m.funding_address == env.current_contract_address() {
+        return Err(CurationError::InvalidFundingAddress);
+    }
+    Ok(())
+}
+
+fn validate_url(url: &soroban_sdk::String) -> Result<(), CurationError> {
+    let len = url.len();
+    if len > MAX_URL_LEN {
+        return Err(CurationError::InvalidUrl);
+    }
+    let mut buf = [0u8; MAX_URL_LEN as usize];
+    let bytes = &mut buf[..len as usize];
+    url.copy_into_slice(bytes);
+    let valid = URL_SCHEMES
+        .iter()
+        .any(|scheme| bytes.len() > scheme.len() && bytes.starts_with(scheme))
+        && !bytes
+            .iter()
+            .any(|b| b.is_ascii_whitespace() || b.is_ascii_control());
+    if !valid {
+        return Err(CurationError::InvalidUrl);
+    }
+    Ok(())
+}
+
+/// Record `project_id` as the proposal using `funding_address`, unless
+/// another pending proposal already does.
+fn claim_funding_address(
+    env: &Env,
+    funding_address: &Address,
+    project_id: u64,
+) -> Result<(), CurationError> {
+    if let Some(owner) = get_funding_address_owner(env, funding_address) {
+        let pending = owner != project_id
+            && get_proposal(env, owner).is_some_and(|p| p.status == ProjectStatus::Pending);
+        if pending {
+            return Err(CurationError::DuplicateFundingAddress);
+        }
+    }
+    set_funding_address_owner(env, funding_address, project_id);
     Ok(())
 }
+
+#[cfg(test)]
+mod test;
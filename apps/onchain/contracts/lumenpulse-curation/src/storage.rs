@@ -1,6 +1,8 @@
 use soroban_sdk::{contracttype, Address, Env};
 
-use crate::types::{ProposalState, VoteRecord};
+use crate::types::{
+    AmendmentPolicy, CurationParams, ProposalState, ReviewerStats, SlashSplit, VoteRecord,
+};
 
 // ─── Storage Keys ─────────────────────────────────────────────────────────────
 
@@ -11,8 +13,19 @@ pub enum DataKey {
     ContributorRegistry,
     NextProjectId,
     Proposal(u64),
-    VotedFlag(u64, Address),  // (project_id, voter) → bool
-    VoteRecord(u64, Address), // (project_id, voter) → VoteRecord
+    VotedFlag(u64, u32, Address),  // (project_id, round, voter) → bool
+    VoteRecord(u64, u32, Address), // (project_id, round, voter) → VoteRecord
+    PermissionRegistry,            // → Address consulted for has_permission
+    SignedNonce(Address),          // signer → next nonce for signed intents
+    SlashSplit,                    // → SlashSplit for rejected deposits
+    Treasury,                      // → Address receiving the treasury share
+    RewardClaimed(u64, Address),   // (project_id, voter) → bool
+    Params,                        // → CurationParams for new proposals
+    AmendmentPolicy,               // → AmendmentPolicy
+    ReviewerStats(Address),        // voter → ReviewerStats
+    ReviewSettled(u64, Address),   // (project_id, voter) → bool
+    ReputationReceiver,            // → Address notified of review outcomes
+    FundingAddress(Address),       // funding address → project_id last proposing it
 }
 
 // ── Admin ─────────────────────────────────────────────────────────────────────
@@ -57,6 +70,158 @@ pub fn get_contributor_registry(env: &Env) -> Address {
         .unwrap()
 }
 
+// ── Permission Registry ───────────────────────────────────────────────────────
+
+pub fn set_permission_registry(env: &Env, registry: &Option<Address>) {
+    match registry {
+        Some(registry) => env
+            .storage()
+            .instance()
+            .set(&DataKey::PermissionRegistry, registry),
+        None => env
+            .storage()
+            .instance()
+            .remove(&DataKey::PermissionRegistry),
+    }
+}
+
+pub fn get_permission_registry(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::PermissionRegistry)
+}
+
+// ── Parameters ────────────────────────────────────────────────────────────────
+
+pub fn set_params(env: &Env, params: &CurationParams) {
+    env.storage().instance().set(&DataKey::Params, params);
+}
+
+pub fn get_params(env: &Env) -> Option<CurationParams> {
+    env.storage().instance().get(&DataKey::Params)
+}
+
+// ── Amendments ────────────────────────────────────────────────────────────────
+
+pub fn set_amendment_policy(env: &Env, policy: &AmendmentPolicy) {
+    env.storage()
+        .instance()
+        .set(&DataKey::AmendmentPolicy, policy);
+}
+
+pub fn get_amendment_policy(env: &Env) -> AmendmentPolicy {
+    env.storage()
+        .instance()
+        .get(&DataKey::AmendmentPolicy)
+        .unwrap_or(AmendmentPolicy::ResetOnMaterialChange)
+}
+
+// ── Deposit Slashing ──────────────────────────────────────────────────────────
+
+/// Used until the admin configures a split.
+const DEFAULT_SLASH_SPLIT: SlashSplit = SlashSplit {
+    no_voters_bps: 7_000,
+    treasury_bps: 2_000,
+    burn_bps: 1_000,
+};
+
+pub fn set_slash_split(env: &Env, split: &SlashSplit) {
+    env.storage().instance().set(&DataKey::SlashSplit, split);
+}
+
+pub fn get_slash_split(env: &Env) -> SlashSplit {
+    env.storage()
+        .instance()
+        .get(&DataKey::SlashSplit)
+        .unwrap_or(DEFAULT_SLASH_SPLIT)
+}
+
+pub fn set_treasury(env: &Env, treasury: &Option<Address>) {
+    match treasury {
+        Some(treasury) => env.storage().instance().set(&DataKey::Treasury, treasury),
+        None => env.storage().instance().remove(&DataKey::Treasury),
+    }
+}
+
+pub fn get_treasury(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Treasury)
+}
+
+pub fn has_claimed_reward(env: &Env, project_id: u64, voter: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::RewardClaimed(project_id, voter.clone()))
+}
+
+pub fn set_reward_claimed(env: &Env, project_id: u64, voter: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::RewardClaimed(project_id, voter.clone()), &true);
+}
+
+// ── Reviewer Accuracy ─────────────────────────────────────────────────────────
+
+pub fn get_reviewer_stats(env: &Env, voter: &Address) -> ReviewerStats {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ReviewerStats(voter.clone()))
+        .unwrap_or_default()
+}
+
+pub fn set_reviewer_stats(env: &Env, voter: &Address, stats: &ReviewerStats) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ReviewerStats(voter.clone()), stats);
+}
+
+pub fn is_review_settled(env: &Env, project_id: u64, voter: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::ReviewSettled(project_id, voter.clone()))
+}
+
+pub fn set_review_settled(env: &Env, project_id: u64, voter: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ReviewSettled(project_id, voter.clone()), &true);
+}
+
+pub fn set_reputation_receiver(env: &Env, receiver: &Option<Address>) {
+    match receiver {
+        Some(receiver) => env
+            .storage()
+            .instance()
+            .set(&DataKey::ReputationReceiver, receiver),
+        None => env
+            .storage()
+            .instance()
+            .remove(&DataKey::ReputationReceiver),
+    }
+}
+
+pub fn get_reputation_receiver(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::ReputationReceiver)
+}
+
+// ── Funding Addresses ─────────────────────────────────────────────────────────
+
+pub fn get_funding_address_owner(env: &Env, funding_address: &Address) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::FundingAddress(funding_address.clone()))
+}
+
+pub fn set_funding_address_owner(env: &Env, funding_address: &Address, project_id: u64) {
+    env.storage().persistent().set(
+        &DataKey::FundingAddress(funding_address.clone()),
+        &project_id,
+    );
+}
+
+pub fn remove_funding_address_owner(env: &Env, funding_address: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::FundingAddress(funding_address.clone()));
+}
+
 // ── Project ID Counter ────────────────────────────────────────────────────────
 
 pub fn set_next_project_id(env: &Env, id: u64) {
@@ -86,28 +251,60 @@ pub fn get_proposal(env: &Env, project_id: u64) -> Option<ProposalState> {
 
 // ── Votes ─────────────────────────────────────────────────────────────────────
 
-pub fn has_voted(env: &Env, project_id: u64, voter: &Address) -> bool {
+pub fn has_voted(env: &Env, project_id: u64, round: u32, voter: &Address) -> bool {
     env.storage()
         .temporary()
-        .has(&DataKey::VotedFlag(project_id, voter.clone()))
+        .has(&DataKey::VotedFlag(project_id, round, voter.clone()))
 }
 
-pub fn record_vote(env: &Env, project_id: u64, voter: &Address) {
+pub fn record_vote(env: &Env, project_id: u64, round: u32, voter: &Address) {
     // Store the flag in temporary storage; it can expire but serves its purpose
     // within any practical voting window.
     env.storage()
         .temporary()
-        .set(&DataKey::VotedFlag(project_id, voter.clone()), &true);
+        .set(&DataKey::VotedFlag(project_id, round, voter.clone()), &true);
 }
 
 pub fn save_vote_record(env: &Env, project_id: u64, voter: &Address, record: &VoteRecord) {
+    env.storage().persistent().set(
+        &DataKey::VoteRecord(project_id, record.round, voter.clone()),
+        record,
+    );
+}
+
+pub fn get_vote_record(
+    env: &Env,
+    project_id: u64,
+    round: u32,
+    voter: &Address,
+) -> Option<VoteRecord> {
     env.storage()
         .persistent()
-        .set(&DataKey::VoteRecord(project_id, voter.clone()), record);
+        .get(&DataKey::VoteRecord(project_id, round, voter.clone()))
+}
+
+// ── Signed Intents ────────────────────────────────────────────────────────────
+
+/// TTL bump applied to nonce entries, matching the `signed-intent` helper.
+/// An evicted nonce would reset to 0 and let old signed intents replay.
+const NONCE_TTL_THRESHOLD: u32 = 100_000;
+const NONCE_TTL_BUMP: u32 = 518_400;
+
+pub fn get_signed_nonce(env: &Env, signer: &Address) -> u64 {
+    let key = DataKey::SignedNonce(signer.clone());
+    let nonce = env.storage().persistent().get(&key);
+    if nonce.is_some() {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, NONCE_TTL_THRESHOLD, NONCE_TTL_BUMP);
+    }
+    nonce.unwrap_or(0u64)
 }
 
-pub fn get_vote_record(env: &Env, project_id: u64, voter: &Address) -> Option<VoteRecord> {
+pub fn set_signed_nonce(env: &Env, signer: &Address, nonce: u64) {
+    let key = DataKey::SignedNonce(signer.clone());
+    env.storage().persistent().set(&key, &nonce);
     env.storage()
         .persistent()
-        .get(&DataKey::VoteRecord(project_id, voter.clone()))
+        .extend_ttl(&key, NONCE_TTL_THRESHOLD, NONCE_TTL_BUMP);
 }
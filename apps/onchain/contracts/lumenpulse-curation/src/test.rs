@@ -0,0 +1,706 @@
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{contract, contractimpl, token, Address, Bytes, Env, String, Symbol};
+
+#[contract]
+pub struct MockContributorRegistry;
+
+#[contractimpl]
+impl MockContributorRegistry {
+    pub fn set_power(env: Env, who: Address, power: u64) {
+        env.storage().persistent().set(&who, &power);
+    }
+
+    pub fn get_voting_power_in(
+        env: Env,
+        who: Address,
+        _scope: DelegationScope,
+        _timestamp: u64,
+    ) -> u64 {
+        env.storage().persistent().get(&who).unwrap_or(0)
+    }
+
+    pub fn total_reputation(_env: Env) -> u64 {
+        100
+    }
+}
+
+/// Records how many notifications of each event type arrived.
+#[contract]
+pub struct MockReputationReceiver;
+
+#[contractimpl]
+impl MockReputationReceiver {
+    pub fn on_notify(env: Env, notification: Notification) {
+        let count: u32 = env
+            .storage()
+            .persistent()
+            .get(&notification.event_type)
+            .unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&notification.event_type, &(count + 1));
+    }
+
+    pub fn count(env: Env, event_type: Symbol) -> u32 {
+        env.storage().persistent().get(&event_type).unwrap_or(0)
+    }
+}
+
+/// Knows only the grants made through `allow`.
+#[contract]
+pub struct MockPermissionRegistry;
+
+#[contractimpl]
+impl MockPermissionRegistry {
+    pub fn allow(env: Env, who: Address, permission: Symbol) {
+        env.storage().persistent().set(&(who, permission), &true);
+    }
+
+    pub fn has_permission(env: Env, who: Address, permission: Symbol) -> bool {
+        env.storage()
+            .persistent()
+            .get(&(who, permission))
+            .unwrap_or(false)
+    }
+}
+
+struct Setup<'a> {
+    client: CommunityCurationContractClient<'a>,
+    contract: Address,
+    registry: MockContributorRegistryClient<'a>,
+    token: token::Client<'a>,
+    token_admin: token::StellarAssetClient<'a>,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    // Keep entries alive across a full voting window.
+    env.ledger().with_mut(|l| {
+        l.min_persistent_entry_ttl = 2 * VOTING_WINDOW_LEDGERS;
+        l.min_temp_entry_ttl = 2 * VOTING_WINDOW_LEDGERS;
+        l.max_entry_ttl = 4 * VOTING_WINDOW_LEDGERS;
+    });
+    let token_id = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let registry_id = env.register_contract(None, MockContributorRegistry);
+    let contract = env.register_contract(None, CommunityCurationContract);
+    let client = CommunityCurationContractClient::new(env, &contract);
+    client.initialize(&Address::generate(env), &token_id, &registry_id);
+    Setup {
+        client,
+        contract,
+        registry: MockContributorRegistryClient::new(env, &registry_id),
+        token: token::Client::new(env, &token_id),
+        token_admin: token::StellarAssetClient::new(env, &token_id),
+    }
+}
+
+fn metadata(env: &Env, description: &str) -> ProjectMetadata {
+    ProjectMetadata {
+        name: String::from_str(env, "Project"),
+        description: String::from_str(env, description),
+        url: String::from_str(env, "https://example.org"),
+        funding_address: Address::generate(env),
+        content_hash: None,
+    }
+}
+
+fn propose(env: &Env, s: &Setup) -> (Address, u64) {
+    let proposer = Address::generate(env);
+    s.token_admin.mint(&proposer, &PROPOSAL_DEPOSIT_STROOPS);
+    let project_id = s
+        .client
+        .propose_project(&proposer, &metadata(env, "A project"));
+    (proposer, project_id)
+}
+
+fn voter(env: &Env, s: &Setup, power: u64) -> Address {
+    let voter = Address::generate(env);
+    s.registry.set_power(&voter, &power);
+    voter
+}
+
+#[test]
+fn test_rejected_deposit_is_split_and_claimable() {
+    let env = Env::default();
+    let s = setup(&env);
+    let treasury = Address::generate(&env);
+    s.client.set_slash_config(
+        &Some(treasury.clone()),
+        &SlashSplit {
+            no_voters_bps: 6_000,
+            treasury_bps: 3_000,
+            burn_bps: 1_000,
+        },
+    );
+    let (_, project_id) = propose(&env, &s);
+
+    let yes = voter(&env, &s, 10);
+    let no_a = voter(&env, &s, 30);
+    let no_b = voter(&env, &s, 15);
+    let no_c = voter(&env, &s, 15);
+    s.client.vote_to_verify(&yes, &project_id, &true);
+    s.client.vote_to_verify(&no_a, &project_id, &false);
+    s.client.vote_to_verify(&no_b, &project_id, &false);
+    assert_eq!(
+        s.client.get_claimable_reward(&project_id, &no_a),
+        0,
+        "nothing is claimable while pending"
+    );
+    // 60% NO → auto-rejected
+    s.client.vote_to_verify(&no_c, &project_id, &false);
+    assert_eq!(
+        s.client.get_proposal_state(&project_id).unwrap().status,
+        ProjectStatus::Rejected
+    );
+
+    let deposit = PROPOSAL_DEPOSIT_STROOPS;
+    assert_eq!(s.token.balance(&treasury), deposit * 3 / 10);
+    let pool = deposit * 6 / 10;
+    assert_eq!(s.token.balance(&s.contract), pool);
+
+    assert_eq!(s.client.claim_curation_reward(&no_a, &project_id), pool / 2);
+    assert_eq!(s.client.claim_curation_reward(&no_b, &project_id), pool / 4);
+    assert_eq!(s.token.balance(&no_a), pool / 2);
+    assert_eq!(
+        s.client.try_claim_curation_reward(&no_a, &project_id),
+        Err(Ok(CurationError::NothingToClaim))
+    );
+    assert_eq!(
+        s.client.try_claim_curation_reward(&yes, &project_id),
+        Err(Ok(CurationError::NothingToClaim))
+    );
+    s.client.claim_curation_reward(&no_c, &project_id);
+    assert_eq!(s.token.balance(&s.contract), 0);
+}
+
+#[test]
+fn test_expired_without_no_votes_sends_voter_share_to_treasury() {
+    let env = Env::default();
+    let s = setup(&env);
+    let treasury = Address::generate(&env);
+    s.client.set_slash_config(
+        &Some(treasury.clone()),
+        &SlashSplit {
+            no_voters_bps: 5_000,
+            treasury_bps: 2_500,
+            burn_bps: 2_500,
+        },
+    );
+    let (_, project_id) = propose(&env, &s);
+
+    env.ledger()
+        .with_mut(|l| l.sequence_number += VOTING_WINDOW_LEDGERS + 1);
+    assert_eq!(
+        s.client.finalize_proposal(&project_id),
+        ProjectStatus::Rejected
+    );
+    assert_eq!(s.token.balance(&treasury), PROPOSAL_DEPOSIT_STROOPS * 3 / 4);
+    assert_eq!(s.token.balance(&s.contract), 0);
+}
+
+#[test]
+fn test_verified_deposit_is_returned_not_slashed() {
+    let env = Env::default();
+    let s = setup(&env);
+    let (proposer, project_id) = propose(&env, &s);
+    let yes = voter(&env, &s, 40);
+    s.client.vote_to_verify(&yes, &project_id, &true);
+    assert!(s.client.is_verified(&project_id));
+    let reward = PROPOSAL_DEPOSIT_STROOPS * REVIEWER_REWARD_BPS as i128 / 10_000;
+    assert_eq!(
+        s.token.balance(&proposer),
+        PROPOSAL_DEPOSIT_STROOPS - reward
+    );
+    assert_eq!(s.client.get_claimable_reward(&project_id, &yes), reward);
+}
+
+#[test]
+fn test_settled_votes_track_accuracy_and_notify_reputation() {
+    let env = Env::default();
+    let s = setup(&env);
+    let receiver_id = env.register_contract(None, MockReputationReceiver);
+    let receiver = MockReputationReceiverClient::new(&env, &receiver_id);
+    s.client.set_reputation_receiver(&Some(receiver_id.clone()));
+    let (_, project_id) = propose(&env, &s);
+
+    let no = voter(&env, &s, 10);
+    let yes = voter(&env, &s, 40);
+    s.client.vote_to_verify(&no, &project_id, &false);
+    assert_eq!(
+        s.client.try_settle_review(&no, &project_id),
+        Err(Ok(CurationError::NotResolved))
+    );
+    s.client.vote_to_verify(&yes, &project_id, &true);
+    assert!(s.client.is_verified(&project_id));
+
+    // Anyone can settle the losing vote; it earns nothing.
+    assert!(!s.client.settle_review(&no, &project_id));
+    assert_eq!(
+        s.client.try_settle_review(&no, &project_id),
+        Err(Ok(CurationError::AlreadySettled))
+    );
+    assert_eq!(s.client.get_claimable_reward(&project_id, &no), 0);
+
+    // Claiming settles the winning vote and pays the whole pool.
+    let reward = s.client.claim_curation_reward(&yes, &project_id);
+    assert_eq!(
+        reward,
+        PROPOSAL_DEPOSIT_STROOPS * REVIEWER_REWARD_BPS as i128 / 10_000
+    );
+    assert_eq!(s.token.balance(&yes), reward);
+    assert_eq!(s.token.balance(&s.contract), 0);
+
+    assert_eq!(
+        s.client.get_reviewer_stats(&yes),
+        ReviewerStats {
+            aligned: 1,
+            misaligned: 0
+        }
+    );
+    assert_eq!(s.client.get_reviewer_stats(&no).misaligned, 1);
+    assert_eq!(receiver.count(&Symbol::new(&env, EVENT_REVIEW_ALIGNED)), 1);
+    assert_eq!(
+        receiver.count(&Symbol::new(&env, EVENT_REVIEW_MISALIGNED)),
+        1
+    );
+}
+
+#[test]
+fn test_slash_split_must_sum_to_whole() {
+    let env = Env::default();
+    let s = setup(&env);
+    let split = SlashSplit {
+        no_voters_bps: 5_000,
+        treasury_bps: 5_000,
+        burn_bps: 1,
+    };
+    assert_eq!(
+        s.client.try_set_slash_config(&None, &split),
+        Err(Ok(CurationError::InvalidSlashSplit))
+    );
+    let (treasury, default_split) = s.client.get_slash_config();
+    assert_eq!(treasury, None);
+    assert_eq!(
+        default_split.no_voters_bps + default_split.treasury_bps + default_split.burn_bps,
+        10_000
+    );
+}
+
+#[test]
+fn test_update_params_applies_to_new_proposals_only() {
+    let env = Env::default();
+    let s = setup(&env);
+    let (_, first) = propose(&env, &s);
+
+    let params = CurationParams {
+        deposit: 2 * PROPOSAL_DEPOSIT_STROOPS,
+        verify_threshold_bps: 5_000,
+        voting_window_ledgers: MIN_VOTING_WINDOW_LEDGERS,
+        min_yes_votes: 1,
+        reviewer_reward_bps: 0,
+    };
+    s.client.update_params(&params);
+    assert_eq!(s.client.get_params(), params);
+    assert_eq!(s.client.get_deposit_amount(), 2 * PROPOSAL_DEPOSIT_STROOPS);
+
+    let proposer = Address::generate(&env);
+    s.token_admin
+        .mint(&proposer, &(2 * PROPOSAL_DEPOSIT_STROOPS));
+    let second = s.client.propose_project(
+        &proposer,
+        &ProjectMetadata {
+            name: String::from_str(&env, "Second"),
+            description: String::from_str(&env, "Another project"),
+            url: String::from_str(&env, "https://example.org"),
+            funding_address: Address::generate(&env),
+            content_hash: None,
+        },
+    );
+    assert_eq!(s.token.balance(&proposer), 0);
+
+    // 40% YES verifies the first proposal (30% rule) but not the second.
+    let yes = voter(&env, &s, 40);
+    s.client.vote_to_verify(&yes, &first, &true);
+    s.client.vote_to_verify(&yes, &second, &true);
+    assert!(s.client.is_verified(&first));
+    assert!(!s.client.is_verified(&second));
+
+    let state = s.client.get_proposal_state(&second).unwrap();
+    assert_eq!(state.params, params);
+    assert_eq!(
+        state.voting_ends_ledger,
+        state.created_ledger + MIN_VOTING_WINDOW_LEDGERS
+    );
+}
+
+#[test]
+fn test_update_params_enforces_bounds() {
+    let env = Env::default();
+    let s = setup(&env);
+    let valid = s.client.get_params();
+    for params in [
+        CurationParams {
+            deposit: 0,
+            ..valid.clone()
+        },
+        CurationParams {
+            verify_threshold_bps: 10_001,
+            ..valid.clone()
+        },
+        CurationParams {
+            voting_window_ledgers: MIN_VOTING_WINDOW_LEDGERS - 1,
+            ..valid.clone()
+        },
+        CurationParams {
+            voting_window_ledgers: MAX_VOTING_WINDOW_LEDGERS + 1,
+            ..valid.clone()
+        },
+        CurationParams {
+            min_yes_votes: 0,
+            ..valid.clone()
+        },
+        CurationParams {
+            reviewer_reward_bps: MAX_REVIEWER_REWARD_BPS + 1,
+            ..valid.clone()
+        },
+    ] {
+        assert_eq!(
+            s.client.try_update_params(&params),
+            Err(Ok(CurationError::InvalidParams))
+        );
+    }
+}
+
+#[test]
+fn test_amend_preserves_or_resets_votes_per_policy() {
+    let env = Env::default();
+    let s = setup(&env);
+    let (proposer, project_id) = propose(&env, &s);
+    let yes = voter(&env, &s, 10);
+    s.client.vote_to_verify(&yes, &project_id, &true);
+
+    // Default policy: a description fix keeps votes...
+    let mut fixed = s.client.get_proposal_state(&project_id).unwrap().metadata;
+    fixed.description = String::from_str(&env, "A project, typo fixed");
+    s.client.amend_proposal(&proposer, &project_id, &fixed);
+    let state = s.client.get_proposal_state(&project_id).unwrap();
+    assert_eq!(state.metadata, fixed);
+    assert_eq!(state.yes_votes, 10);
+    assert_eq!(state.amendments, 1);
+
+    // ...but a new funding address discards them.
+    fixed.funding_address = Address::generate(&env);
+    s.client.amend_proposal(&proposer, &project_id, &fixed);
+    let state = s.client.get_proposal_state(&project_id).unwrap();
+    assert_eq!(state.yes_votes, 0);
+    assert_eq!(state.round, 1);
+    assert!(s.client.get_vote(&project_id, &yes).is_none());
+    s.client.vote_to_verify(&yes, &project_id, &true);
+    assert_eq!(s.client.get_vote(&project_id, &yes).unwrap().round, 1);
+
+    s.client.set_amendment_policy(&AmendmentPolicy::ResetVotes);
+    s.client
+        .amend_proposal(&proposer, &project_id, &metadata(&env, "Again"));
+    assert_eq!(s.client.get_proposal_state(&project_id).unwrap().round, 2);
+
+    assert_eq!(
+        s.client
+            .try_amend_proposal(&yes, &project_id, &metadata(&env, "Hijack")),
+        Err(Ok(CurationError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_amendments_close_after_no_vote_or_near_deadline() {
+    let env = Env::default();
+    let s = setup(&env);
+    let (proposer, project_id) = propose(&env, &s);
+    let ends = s
+        .client
+        .get_proposal_state(&project_id)
+        .unwrap()
+        .voting_ends_ledger;
+
+    // A material change resets votes but keeps the deadline.
+    env.ledger().with_mut(|l| l.sequence_number += 1_000);
+    let mut m = s.client.get_proposal_state(&project_id).unwrap().metadata;
+    m.funding_address = Address::generate(&env);
+    s.client.amend_proposal(&proposer, &project_id, &m);
+    let state = s.client.get_proposal_state(&project_id).unwrap();
+    assert_eq!(state.round, 1);
+    assert_eq!(state.voting_ends_ledger, ends);
+
+    // Once the last quarter of the window starts, the text is final.
+    env.ledger()
+        .with_mut(|l| l.sequence_number = ends - VOTING_WINDOW_LEDGERS / 4 + 1);
+    assert_eq!(
+        s.client
+            .try_amend_proposal(&proposer, &project_id, &metadata(&env, "Late")),
+        Err(Ok(CurationError::AmendmentClosed))
+    );
+
+    // A NO vote also locks the proposal, whatever the policy.
+    let (proposer, project_id) = propose(&env, &s);
+    s.client
+        .set_amendment_policy(&AmendmentPolicy::PreserveVotes);
+    s.client
+        .vote_to_verify(&voter(&env, &s, 10), &project_id, &false);
+    assert_eq!(
+        s.client
+            .try_amend_proposal(&proposer, &project_id, &metadata(&env, "Dodge")),
+        Err(Ok(CurationError::AmendmentClosed))
+    );
+}
+
+#[test]
+fn test_withdraw_refunds_part_of_deposit_before_first_vote() {
+    let env = Env::default();
+    let s = setup(&env);
+    let treasury = Address::generate(&env);
+    s.client
+        .set_slash_config(&Some(treasury.clone()), &s.client.get_slash_config().1);
+
+    let (proposer, project_id) = propose(&env, &s);
+    let refund = s.client.withdraw_proposal(&proposer, &project_id);
+    assert_eq!(refund, PROPOSAL_DEPOSIT_STROOPS * 8 / 10);
+    assert_eq!(s.token.balance(&proposer), refund);
+    assert_eq!(
+        s.token.balance(&treasury),
+        PROPOSAL_DEPOSIT_STROOPS - refund
+    );
+    assert_eq!(
+        s.client.get_proposal_state(&project_id).unwrap().status,
+        ProjectStatus::Withdrawn
+    );
+
+    let (proposer, project_id) = propose(&env, &s);
+    s.client
+        .vote_to_verify(&voter(&env, &s, 10), &project_id, &true);
+    assert_eq!(
+        s.client.try_withdraw_proposal(&proposer, &project_id),
+        Err(Ok(CurationError::VotingStarted))
+    );
+}
+
+#[test]
+fn test_resubmission_links_history() {
+    let env = Env::default();
+    let s = setup(&env);
+    let (proposer, first) = propose(&env, &s);
+    assert_eq!(
+        s.client
+            .try_resubmit_proposal(&proposer, &first, &metadata(&env, "Retry")),
+        Err(Ok(CurationError::NotRejected))
+    );
+    s.client.admin_reject(&first);
+
+    s.token_admin.mint(&proposer, &PROPOSAL_DEPOSIT_STROOPS);
+    let second = s
+        .client
+        .resubmit_proposal(&proposer, &first, &metadata(&env, "Retry"));
+    let state = s.client.get_proposal_state(&second).unwrap();
+    assert_eq!(state.previous_id, Some(first));
+    assert_eq!(state.status, ProjectStatus::Pending);
+    assert_eq!(
+        s.client.get_proposal_state(&first).unwrap().resubmitted_as,
+        Some(second)
+    );
+    assert_eq!(
+        s.client.get_proposal_history(&second),
+        soroban_sdk::vec![&env, first, second]
+    );
+    assert_eq!(
+        s.client
+            .try_resubmit_proposal(&proposer, &first, &metadata(&env, "Twice")),
+        Err(Ok(CurationError::AlreadyResubmitted))
+    );
+}
+
+#[test]
+fn test_metadata_url_and_funding_address_are_validated() {
+    let env = Env::default();
+    let s = setup(&env);
+    let proposer = Address::generate(&env);
+    s.token_admin
+        .mint(&proposer, &(2 * PROPOSAL_DEPOSIT_STROOPS));
+
+    for url in [
+        "http://example.org",
+        "javascript:alert(1)",
+        "ipfs://",
+        "ar://tx id",
+        &["https://", &"a".repeat(249)].concat(),
+    ] {
+        let mut m = metadata(&env, "A project");
+        m.url = String::from_str(&env, url);
+        assert_eq!(
+            s.client.try_propose_project(&proposer, &m),
+            Err(Ok(CurationError::InvalidUrl)),
+            "{url}"
+        );
+    }
+    for url in [
+        "ipfs://bafybeigdyrzt",
+        "ar://bNbA3TEQVL60xlgCcqdz4ZPHFZ711cZ3hmkpGttDt_U",
+    ] {
+        let mut m = metadata(&env, "A project");
+        m.url = String::from_str(&env, url);
+        m.funding_address = Address::generate(&env);
+        let project_id = s.client.propose_project(&proposer, &m);
+        s.client.admin_reject(&project_id);
+    }
+
+    let mut m = metadata(&env, "A project");
+    m.funding_address = s.contract.clone();
+    assert_eq!(
+        s.client.try_propose_project(&proposer, &m),
+        Err(Ok(CurationError::InvalidFundingAddress))
+    );
+}
+
+#[test]
+fn test_funding_address_is_unique_among_pending_proposals() {
+    let env = Env::default();
+    let s = setup(&env);
+    let (proposer, first) = propose(&env, &s);
+    let funding = s
+        .client
+        .get_proposal_state(&first)
+        .unwrap()
+        .metadata
+        .funding_address;
+
+    let mut m = metadata(&env, "Copycat");
+    m.funding_address = funding.clone();
+    s.token_admin.mint(&proposer, &PROPOSAL_DEPOSIT_STROOPS);
+    assert_eq!(
+        s.client.try_propose_project(&proposer, &m),
+        Err(Ok(CurationError::DuplicateFundingAddress))
+    );
+    let (other_proposer, second) = propose(&env, &s);
+    assert_eq!(
+        s.client.try_amend_proposal(&other_proposer, &second, &m),
+        Err(Ok(CurationError::DuplicateFundingAddress))
+    );
+
+    // Free again once the first proposal is no longer pending.
+    s.client.admin_reject(&first);
+    s.client.amend_proposal(&other_proposer, &second, &m);
+}
+
+#[test]
+fn test_content_hash_change_resets_votes() {
+    let env = Env::default();
+    let s = setup(&env);
+    let (proposer, project_id) = propose(&env, &s);
+    let mut m = s.client.get_proposal_state(&project_id).unwrap().metadata;
+    m.content_hash = Some(Bytes::from_array(&env, &[1; 31]));
+    assert_eq!(
+        s.client.try_amend_proposal(&proposer, &project_id, &m),
+        Err(Ok(CurationError::InvalidMetadata))
+    );
+    m.content_hash = Some(Bytes::from_array(&env, &[1; 32]));
+    s.client.amend_proposal(&proposer, &project_id, &m);
+
+    s.client
+        .vote_to_verify(&voter(&env, &s, 10), &project_id, &true);
+    m.content_hash = Some(Bytes::from_array(&env, &[2; 32]));
+    s.client.amend_proposal(&proposer, &project_id, &m);
+    let state = s.client.get_proposal_state(&project_id).unwrap();
+    assert_eq!(state.yes_votes, 0);
+    assert_eq!(state.metadata.content_hash, m.content_hash);
+}
+
+#[test]
+fn test_signed_vote_pays_relayer_and_rejects_replay() {
+    use soroban_sdk::testutils::{MockAuth, MockAuthInvoke};
+
+    let env = Env::default();
+    let s = setup(&env);
+    let (_, project_id) = propose(&env, &s);
+    let voter = voter(&env, &s, 10);
+    let relayer = Address::generate(&env);
+    s.token_admin.mint(&voter, &100);
+    let fee = Some(RelayerFee {
+        relayer: relayer.clone(),
+        amount: 40,
+    });
+
+    let signed_args = (
+        Symbol::new(&env, "vote_to_verify_with_sig"),
+        voter.clone(),
+        project_id,
+        true,
+        fee.clone(),
+        0u64,
+    )
+        .into_val(&env);
+    let fee_transfer = [MockAuthInvoke {
+        contract: &s.token.address,
+        fn_name: "transfer",
+        args: (voter.clone(), relayer.clone(), 40i128).into_val(&env),
+        sub_invokes: &[],
+    }];
+    let signed = [MockAuth {
+        address: &voter,
+        invoke: &MockAuthInvoke {
+            contract: &s.contract,
+            fn_name: "vote_to_verify_with_sig",
+            args: signed_args,
+            sub_invokes: &fee_transfer,
+        },
+    }];
+
+    s.client
+        .mock_auths(&signed)
+        .vote_to_verify_with_sig(&voter, &project_id, &true, &fee);
+    assert_eq!(s.client.get_signed_nonce(&voter), 1);
+    assert_eq!(s.token.balance(&relayer), 40);
+    assert!(s.client.get_vote(&project_id, &voter).unwrap().approve);
+
+    // The same signature no longer matches the advanced nonce.
+    assert!(s
+        .client
+        .mock_auths(&signed)
+        .try_vote_to_verify_with_sig(&voter, &project_id, &true, &fee)
+        .is_err());
+    assert_eq!(s.client.get_signed_nonce(&voter), 1);
+    assert_eq!(s.token.balance(&relayer), 40);
+}
+
+#[test]
+fn test_permission_registry_gates_proposals_and_votes() {
+    let env = Env::default();
+    let s = setup(&env);
+    let perms_id = env.register_contract(None, MockPermissionRegistry);
+    let perms = MockPermissionRegistryClient::new(&env, &perms_id);
+    s.client.set_permission_registry(&Some(perms_id.clone()));
+    assert_eq!(s.client.get_permission_registry(), Some(perms_id));
+
+    let proposer = Address::generate(&env);
+    s.token_admin.mint(&proposer, &PROPOSAL_DEPOSIT_STROOPS);
+    assert_eq!(
+        s.client
+            .try_propose_project(&proposer, &metadata(&env, "Gated")),
+        Err(Ok(CurationError::PermissionDenied))
+    );
+    perms.allow(&proposer, &Symbol::new(&env, PERMISSION_PROPOSE_PROJECT));
+    let project_id = s
+        .client
+        .propose_project(&proposer, &metadata(&env, "Gated"));
+
+    let reviewer = voter(&env, &s, 10);
+    assert_eq!(
+        s.client.try_vote_to_verify(&reviewer, &project_id, &true),
+        Err(Ok(CurationError::PermissionDenied))
+    );
+    perms.allow(&reviewer, &Symbol::new(&env, PERMISSION_REVIEW));
+    s.client.vote_to_verify(&reviewer, &project_id, &true);
+    assert_eq!(
+        s.client.get_proposal_state(&project_id).unwrap().yes_votes,
+        10
+    );
+}
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, String};
+use soroban_sdk::{contracttype, Address, Bytes, String, Symbol};
 
 /// Metadata a proposer provides about their project.
 #[contracttype]
@@ -8,10 +8,16 @@ pub struct ProjectMetadata {
     pub name: String,
     /// Project description / pitch (max 1000 chars).
     pub description: String,
-    /// Link to a IPFS/Arweave doc or website.
+    /// Link to a IPFS/Arweave doc or website: `https://`, `ipfs://` or
+    /// `ar://` (max 256 chars).
     pub url: String,
-    /// On-chain address that will receive matching funds.
+    /// On-chain address that will receive matching funds.  Must not be this
+    /// contract, nor the funding address of another pending proposal.
     pub funding_address: Address,
+    /// SHA-256 of the linked document (32 bytes), so reviewers can check it
+    /// has not changed since they voted.  `Bytes` rather than `BytesN<32>`,
+    /// which this SDK cannot wrap in an `Option` field.
+    pub content_hash: Option<Bytes>,
 }
 
 /// Lifecycle status of a proposed project.
@@ -24,6 +30,41 @@ pub enum ProjectStatus {
     Verified,
     /// Rejected by community vote, admin, or window expiry.
     Rejected,
+    /// Withdrawn by the proposer before any vote was cast.
+    Withdrawn,
+}
+
+/// What happens to votes already cast when a proposer amends a pending
+/// proposal.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum AmendmentPolicy {
+    /// Votes stand whatever changed.
+    PreserveVotes,
+    /// Every amendment discards votes cast so far.  The voting window keeps
+    /// its original deadline.
+    ResetVotes,
+    /// Votes are discarded only if the `url`, `funding_address` or
+    /// `content_hash` changed; name and description edits keep them.
+    ResetOnMaterialChange,
+}
+
+/// Governance-tunable curation parameters.  Each proposal keeps a copy of
+/// the values in effect when it was created.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CurationParams {
+    /// Deposit (in stroops of the deposit token) required to propose.
+    pub deposit: i128,
+    /// Share of total reputation that must vote YES to auto-verify, in bps.
+    pub verify_threshold_bps: u32,
+    /// Voting window in ledgers.
+    pub voting_window_ledgers: u32,
+    /// Minimum absolute YES votes before threshold math kicks in.
+    pub min_yes_votes: u32,
+    /// Share of a verified proposal's deposit kept back for YES voters, in
+    /// bps; the proposer gets the rest back.
+    pub reviewer_reward_bps: u32,
 }
 
 /// Full on-chain state for a proposal.
@@ -40,10 +81,28 @@ pub struct ProposalState {
     pub no_votes: u64,
     /// Snapshot of total reputation at time of first vote.
     pub total_voting_power_snapshot: u64,
-    /// Whether the deposit has been returned (Verified path only).
+    /// Whether the deposit has been settled: returned to the proposer on
+    /// Verified, slashed on Rejected.
     pub deposit_returned: bool,
+    /// Deposit set aside for voters on the winning side: YES voters once
+    /// Verified, NO voters once Rejected.
+    pub reviewer_pool: i128,
     pub created_ledger: u32,
+    /// Ledger timestamp at creation; voting power is read as of this time.
+    pub created_at: u64,
     pub voting_ends_ledger: u32,
+    /// Parameters in effect when the proposal was created.
+    pub params: CurationParams,
+    /// Voting round; incremented whenever an amendment resets votes.
+    pub round: u32,
+    /// Votes cast over all rounds.
+    pub votes_cast: u32,
+    /// Number of amendments made.
+    pub amendments: u32,
+    /// Rejected proposal this one resubmits, if any.
+    pub previous_id: Option<u64>,
+    /// Resubmission of this proposal, once rejected and resubmitted.
+    pub resubmitted_as: Option<u64>,
 }
 
 /// Individual vote record stored per (project_id, voter).
@@ -57,4 +116,61 @@ pub struct VoteRecord {
     /// Voting power used (reputation score at vote time).
     pub voting_power: u64,
     pub ledger: u32,
+    /// Proposal round the vote was cast in.
+    pub round: u32,
+}
+
+/// Reimbursement a signer agrees to pay the relayer of a signed vote, in the
+/// deposit token.  Mirrors `signed_intent::RelayerFee`; this contract is
+/// built against an older SDK and cannot share the crate.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RelayerFee {
+    pub relayer: Address,
+    pub amount: i128,
+}
+
+/// How a rejected proposal's forfeited deposit is divided, in basis points
+/// summing to 10_000.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SlashSplit {
+    /// Share claimable by NO voters, pro-rata to their voting power.
+    pub no_voters_bps: u32,
+    /// Share sent to the protocol treasury.
+    pub treasury_bps: u32,
+    /// Share burned.
+    pub burn_bps: u32,
+}
+
+/// Scope of a contributor-registry delegation.  Mirrors
+/// `contributor_registry::DelegationScope`; votes here query
+/// `Contract(self)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum DelegationScope {
+    Global,
+    Contract(Address),
+    Topic(Symbol),
+}
+
+/// How often a voter sided with the final outcome of the proposals they
+/// reviewed.  Updated when a vote is settled.
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ReviewerStats {
+    /// Votes matching the outcome (YES on Verified, NO on Rejected).
+    pub aligned: u32,
+    /// Votes against the outcome.
+    pub misaligned: u32,
+}
+
+/// Payload pushed to the reputation receiver.  Mirrors
+/// `notification_interface::Notification`, which targets a newer SDK.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct Notification {
+    pub source: Address,
+    pub event_type: Symbol,
+    pub data: Bytes,
 }
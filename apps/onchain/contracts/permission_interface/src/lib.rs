@@ -0,0 +1,52 @@
+#![no_std]
+
+use soroban_sdk::{contractclient, Address, Env, Symbol};
+
+// Permissions are open-ended `Symbol`s so governance can introduce new ones
+// without an upgrade.  These are the names the LumenPulse contracts check.
+
+/// May submit projects (project_registry, lumenpulse-curation, crowdfund_vault).
+pub const PERMISSION_PROPOSE_PROJECT: &str = "propose_project";
+/// May challenge a completed milestone (crowdfund_vault).
+pub const PERMISSION_DISPUTE_MILESTONE: &str = "dispute_milestone";
+/// May cast verification / curation votes (project_registry, lumenpulse-curation).
+pub const PERMISSION_REVIEW: &str = "review";
+
+#[contractclient(name = "PermissionRegistryClient")]
+pub trait PermissionRegistryTrait {
+    fn has_permission(env: Env, contributor: Address, permission: Symbol) -> bool;
+}
+
+/// Whether `who` holds `permission` in `registry`.  Without a registry
+/// every caller is permitted.
+pub fn is_permitted(env: &Env, registry: Option<Address>, who: &Address, permission: &str) -> bool {
+    match registry {
+        Some(registry) => PermissionRegistryClient::new(env, &registry)
+            .has_permission(who, &Symbol::new(env, permission)),
+        None => true,
+    }
+}
+
+#[cfg(feature = "testutils")]
+pub mod testutils {
+    use soroban_sdk::{contract, contractimpl, Address, Env, Symbol};
+
+    /// Permission registry that grants exactly the (address, permission)
+    /// pairs passed to `allow`.
+    #[contract]
+    pub struct MockPermissionRegistry;
+
+    #[contractimpl]
+    impl MockPermissionRegistry {
+        pub fn allow(env: Env, who: Address, permission: Symbol) {
+            env.storage().persistent().set(&(who, permission), &true);
+        }
+
+        pub fn has_permission(env: Env, who: Address, permission: Symbol) -> bool {
+            env.storage()
+                .persistent()
+                .get(&(who, permission))
+                .unwrap_or(false)
+        }
+    }
+}
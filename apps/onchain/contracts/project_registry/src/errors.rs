@@ -16,4 +16,19 @@ pub enum RegistryError {
     ContractPaused = 10,
     ProjectAlreadyVerified = 11,
     ProjectAlreadyRejected = 12,
+    PermissionDenied = 13,
+    InvalidRelayerFee = 14,
+    InvalidMetadata = 15,
+    MetadataVersionNotFound = 16,
+    VotingPeriodEnded = 17,
+    VotingPeriodActive = 18,
+    ReapplyCooldownActive = 19,
+    AppealNotAllowed = 20,
+    NotVerified = 21,
+    VerificationNotExpired = 22,
+    WrongWeightMode = 23,
+    InsufficientCredits = 24,
+    InsufficientLockedBalance = 25,
+    InvalidAmount = 26,
+    ChallengeCooldownActive = 27,
 }
@@ -1,4 +1,4 @@
-use soroban_sdk::{contractevent, Address, Symbol};
+use soroban_sdk::{contractevent, Address, BytesN, Symbol, Vec};
 
 #[contractevent]
 pub struct InitializedEvent {
@@ -38,6 +38,13 @@ pub struct ProjectRejectedEvent {
     pub votes_against: i128,
 }
 
+#[contractevent]
+pub struct PermissionRegistrySetEvent {
+    #[topic]
+    pub admin: Address,
+    pub registry: Option<Address>,
+}
+
 #[contractevent]
 pub struct VerificationOverriddenEvent {
     #[topic]
@@ -45,3 +52,86 @@ pub struct VerificationOverriddenEvent {
     pub admin: Address,
     pub verified: bool,
 }
+
+#[contractevent]
+pub struct ProjectMetadataUpdatedEvent {
+    #[topic]
+    pub project_id: u64,
+    pub version: u32,
+    pub owner: Address,
+}
+
+#[contractevent]
+pub struct ProgressPostedEvent {
+    #[topic]
+    pub project_id: u64,
+    pub index: u32,
+    pub content_hash: BytesN<32>,
+}
+
+#[contractevent]
+pub struct VotingRoundOpenedEvent {
+    #[topic]
+    pub project_id: u64,
+    pub round: u32,
+    pub appeal: bool,
+    pub quorum: i128,
+    pub voting_ends_at: u64,
+}
+
+#[contractevent]
+pub struct VotingRulesUpdatedEvent {
+    #[topic]
+    pub admin: Address,
+    pub voting_period_secs: u64,
+    pub reapply_cooldown_secs: u64,
+    pub appeal_quorum_bps: u32,
+}
+
+#[contractevent]
+pub struct VerificationChallengedEvent {
+    #[topic]
+    pub project_id: u64,
+    pub challenger: Address,
+    pub round: u32,
+    pub voting_ends_at: u64,
+}
+
+#[contractevent]
+pub struct VerificationValiditySetEvent {
+    #[topic]
+    pub admin: Address,
+    pub validity_secs: u64,
+}
+
+#[contractevent]
+pub struct WeightRulesUpdatedEvent {
+    #[topic]
+    pub admin: Address,
+    pub max_weight_bps: u32,
+    pub tier_multipliers_bps: Vec<u32>,
+}
+
+#[contractevent]
+pub struct TokensLockedEvent {
+    #[topic]
+    pub voter: Address,
+    pub amount: i128,
+    pub locked: i128,
+}
+
+#[contractevent]
+pub struct TokensUnlockedEvent {
+    #[topic]
+    pub voter: Address,
+    pub amount: i128,
+    pub locked: i128,
+}
+
+#[contractevent]
+pub struct VoteCreditsGrantedEvent {
+    #[topic]
+    pub voter: Address,
+    pub amount: i128,
+    pub credits: i128,
+}
@@ -5,9 +5,35 @@ mod events;
 mod storage;
 
 use errors::RegistryError;
+use permission_interface::{is_permitted, PERMISSION_PROPOSE_PROJECT, PERMISSION_REVIEW};
+use signed_intent::RelayerFee;
 use soroban_sdk::token::TokenClient;
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, IntoVal, Symbol};
-use storage::{DataKey, ProjectEntry, RegistryConfig, VerificationStatus, WeightMode};
+use soroban_sdk::{contract, contractimpl, vec, Address, BytesN, Env, IntoVal, Symbol, Vec};
+use storage::{
+    DataKey, DelegationScope, LegacyProjectEntry, LegacyRegistryConfig, LockCheckpoint,
+    MetadataVersion, ProgressUpdate, ProjectEntry, ProjectMetadata, RegistryConfig,
+    VerificationStatus, WeightMode,
+};
+use verification_interface::VerificationSourceTrait;
+
+/// Limits on owner-supplied metadata.
+const MAX_DESCRIPTION_LEN: u32 = 1_000;
+const MAX_URL_LEN: u32 = 256;
+const MAX_TAGS: u32 = 8;
+const MAX_TEAM_MEMBERS: u32 = 20;
+
+/// Voting-round defaults applied at initialisation.
+const DEFAULT_VOTING_PERIOD_SECS: u64 = 7 * 24 * 60 * 60;
+const DEFAULT_REAPPLY_COOLDOWN_SECS: u64 = 30 * 24 * 60 * 60;
+const DEFAULT_APPEAL_QUORUM_BPS: u32 = 15_000;
+const BPS_DENOMINATOR: u32 = 10_000;
+
+/// SqrtTokenTier multipliers applied at initialisation (Novice, Builder,
+/// Architect, Core).
+const DEFAULT_TIER_MULTIPLIERS_BPS: [u32; 4] = [10_000, 15_000, 20_000, 30_000];
+
+/// Lock checkpoints retained per voter; the oldest is dropped beyond this.
+const MAX_LOCK_CHECKPOINTS: u32 = 50;
 
 #[contract]
 pub struct ProjectRegistryContract;
@@ -41,32 +67,344 @@ impl ProjectRegistryContract {
         Ok(())
     }
 
+    /// When a permission registry is configured, require `who` to hold
+    /// `permission` there.
+    fn require_permission(env: &Env, who: &Address, permission: &str) -> Result<(), RegistryError> {
+        let registry = env.storage().instance().get(&DataKey::PermissionRegistry);
+        if !is_permitted(env, registry, who, permission) {
+            return Err(RegistryError::PermissionDenied);
+        }
+        Ok(())
+    }
+
+    /// Load a project, upgrading an entry written before voting rounds
+    /// existed.  A legacy pending project votes at the configured quorum
+    /// until one voting period after its registration, after which
+    /// `finalize_vote` settles it.
+    fn load_project(env: &Env, project_id: u64) -> Result<ProjectEntry, RegistryError> {
+        if let Some(entry) = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ProjectV2(project_id))
+        {
+            return Ok(entry);
+        }
+        let legacy: LegacyProjectEntry = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Project(project_id))
+            .ok_or(RegistryError::ProjectNotFound)?;
+        let config = Self::load_config(env)?;
+        let voting_ends_at = if legacy.status == VerificationStatus::Pending {
+            legacy
+                .registered_at
+                .saturating_add(config.voting_period_secs)
+        } else {
+            legacy.resolved_at
+        };
+        Ok(ProjectEntry {
+            project_id: legacy.project_id,
+            owner: legacy.owner,
+            name: legacy.name,
+            status: legacy.status,
+            votes_for: legacy.votes_for,
+            votes_against: legacy.votes_against,
+            registered_at: legacy.registered_at,
+            resolved_at: legacy.resolved_at,
+            voting_starts_at: legacy.registered_at,
+            voting_ends_at,
+            quorum: config.quorum_threshold,
+            round: 0,
+            appeal: false,
+            verified_until: 0,
+        })
+    }
+
+    fn save_project(env: &Env, entry: &ProjectEntry) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::ProjectV2(entry.project_id), entry);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Project(entry.project_id));
+    }
+
+    fn project_exists(env: &Env, project_id: u64) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::ProjectV2(project_id))
+            || env
+                .storage()
+                .persistent()
+                .has(&DataKey::Project(project_id))
+    }
+
+    /// Load the config, filling in defaults for fields added after a
+    /// deployment was initialised.
+    fn load_config(env: &Env) -> Result<RegistryConfig, RegistryError> {
+        if let Some(config) = env.storage().instance().get(&DataKey::ConfigV2) {
+            return Ok(config);
+        }
+        let legacy: LegacyRegistryConfig = env
+            .storage()
+            .instance()
+            .get(&DataKey::Config)
+            .ok_or(RegistryError::NotInitialized)?;
+        Ok(RegistryConfig {
+            quorum_threshold: legacy.quorum_threshold,
+            weight_mode: legacy.weight_mode,
+            governance_token: legacy.governance_token,
+            contributor_registry: legacy.contributor_registry,
+            min_voter_weight: legacy.min_voter_weight,
+            voting_period_secs: DEFAULT_VOTING_PERIOD_SECS,
+            reapply_cooldown_secs: DEFAULT_REAPPLY_COOLDOWN_SECS,
+            appeal_quorum_bps: DEFAULT_APPEAL_QUORUM_BPS,
+            verification_validity_secs: 0,
+            max_weight_bps: 0,
+            tier_multipliers_bps: Vec::from_array(env, DEFAULT_TIER_MULTIPLIERS_BPS),
+        })
+    }
+
+    fn save_config(env: &Env, config: &RegistryConfig) {
+        env.storage().instance().set(&DataKey::ConfigV2, config);
+        env.storage().instance().remove(&DataKey::Config);
+    }
+
+    /// Round 0 votes live under the original keys so votes recorded before
+    /// voting rounds existed still count.
+    fn vote_cast_key(project_id: u64, round: u32, voter: Address) -> DataKey {
+        if round == 0 {
+            DataKey::VoteCast(project_id, voter)
+        } else {
+            DataKey::RoundVoteCast(project_id, round, voter)
+        }
+    }
+
+    fn voter_weight_key(project_id: u64, round: u32, voter: Address) -> DataKey {
+        if round == 0 {
+            DataKey::VoterWeight(project_id, voter)
+        } else {
+            DataKey::RoundVoterWeight(project_id, round, voter)
+        }
+    }
+
+    /// Reset `entry` to Pending and open a fresh voting round.  Appeals need
+    /// `appeal_quorum_bps` of the base quorum (rounded up).
+    /// Result the open round has settled on, if any: a side must reach
+    /// quorum and lead the other.  A tie rejects a pending project but never
+    /// revokes a suspended verification.
+    fn round_outcome(entry: &ProjectEntry) -> Option<VerificationStatus> {
+        let against_wins = if entry.status == VerificationStatus::Suspended {
+            entry.votes_against > entry.votes_for
+        } else {
+            entry.votes_against >= entry.votes_for
+        };
+        if entry.votes_for >= entry.quorum && entry.votes_for > entry.votes_against {
+            Some(VerificationStatus::Verified)
+        } else if entry.votes_against >= entry.quorum && against_wins {
+            Some(VerificationStatus::Rejected)
+        } else {
+            None
+        }
+    }
+
+    fn open_round(env: &Env, entry: &mut ProjectEntry, config: &RegistryConfig, appeal: bool) {
+        let now = env.ledger().timestamp();
+        entry.quorum = if appeal {
+            let scaled = config
+                .quorum_threshold
+                .saturating_mul(config.appeal_quorum_bps as i128);
+            (scaled + BPS_DENOMINATOR as i128 - 1) / BPS_DENOMINATOR as i128
+        } else {
+            config.quorum_threshold
+        };
+        entry.status = VerificationStatus::Pending;
+        entry.votes_for = 0;
+        entry.votes_against = 0;
+        entry.resolved_at = 0;
+        entry.voting_starts_at = now;
+        entry.voting_ends_at = now.saturating_add(config.voting_period_secs);
+        entry.appeal = appeal;
+
+        events::VotingRoundOpenedEvent {
+            project_id: entry.project_id,
+            round: entry.round,
+            appeal,
+            quorum: entry.quorum,
+            voting_ends_at: entry.voting_ends_at,
+        }
+        .publish(env);
+    }
+
+    /// Move `entry` into a terminal status, stamping `resolved_at`.  A fresh
+    /// verification starts a new validity period; one restored after a
+    /// failed challenge keeps its original expiry and starts the challenge
+    /// cooldown.
+    fn resolve(env: &Env, entry: &mut ProjectEntry, status: VerificationStatus) {
+        let now = env.ledger().timestamp();
+        if status == VerificationStatus::Verified && entry.status == VerificationStatus::Suspended {
+            env.storage()
+                .persistent()
+                .set(&DataKey::ChallengeFailedAt(entry.project_id), &now);
+        } else if status == VerificationStatus::Verified {
+            let validity = Self::load_config(env)
+                .map(|config| config.verification_validity_secs)
+                .unwrap_or(0);
+            entry.verified_until = if validity == 0 {
+                0
+            } else {
+                now.saturating_add(validity)
+            };
+        }
+        entry.status = status;
+        entry.resolved_at = now;
+        if entry.status == VerificationStatus::Verified {
+            events::ProjectVerifiedEvent {
+                project_id: entry.project_id,
+                votes_for: entry.votes_for,
+                votes_against: entry.votes_against,
+            }
+            .publish(env);
+        } else {
+            events::ProjectRejectedEvent {
+                project_id: entry.project_id,
+                votes_for: entry.votes_for,
+                votes_against: entry.votes_against,
+            }
+            .publish(env);
+        }
+    }
+
+    fn is_current(env: &Env, entry: &ProjectEntry) -> bool {
+        entry.status == VerificationStatus::Verified
+            && (entry.verified_until == 0 || env.ledger().timestamp() < entry.verified_until)
+    }
+
+    fn validate_metadata(metadata: &ProjectMetadata) -> Result<(), RegistryError> {
+        if metadata.description.is_empty()
+            || metadata.description.len() > MAX_DESCRIPTION_LEN
+            || metadata.url.len() > MAX_URL_LEN
+            || metadata.tags.len() > MAX_TAGS
+            || metadata.team.len() > MAX_TEAM_MEMBERS
+        {
+            return Err(RegistryError::InvalidMetadata);
+        }
+        Ok(())
+    }
+
+    /// Integer square root (floor) of a non-negative amount.
+    fn isqrt(n: i128) -> i128 {
+        if n < 2 {
+            return n.max(0);
+        }
+        let mut x = n;
+        let mut y = (x + 1) / 2;
+        while y < x {
+            x = y;
+            y = (x + n / x) / 2;
+        }
+        x
+    }
+
+    fn lock_history(env: &Env, voter: &Address) -> Vec<LockCheckpoint> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::LockedTokens(voter.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Governance tokens `voter` had locked before `timestamp`.  Locks
+    /// written at `timestamp` itself are excluded, so tokens cannot be
+    /// unlocked and re-locked in another wallet within the snapshot ledger.
+    fn locked_at(env: &Env, voter: &Address, timestamp: u64) -> i128 {
+        let mut amount = 0;
+        for checkpoint in Self::lock_history(env, voter).iter() {
+            if checkpoint.timestamp >= timestamp {
+                break;
+            }
+            amount = checkpoint.amount;
+        }
+        amount
+    }
+
+    /// Record `voter`'s new locked balance, coalescing changes within a
+    /// ledger.
+    fn write_lock(env: &Env, voter: &Address, amount: i128) {
+        let mut history = Self::lock_history(env, voter);
+        let now = env.ledger().timestamp();
+        if history.last().is_some_and(|last| last.timestamp == now) {
+            history.pop_back();
+        } else if history.len() >= MAX_LOCK_CHECKPOINTS {
+            history.pop_front();
+        }
+        history.push_back(LockCheckpoint {
+            timestamp: now,
+            amount,
+        });
+        env.storage()
+            .persistent()
+            .set(&DataKey::LockedTokens(voter.clone()), &history);
+    }
+
+    /// Tier multiplier (bps) for `voter`, or 0 when they are not a
+    /// registered contributor.  Tiers are read live; contributor_registry
+    /// keeps no tier history.
+    fn tier_multiplier(env: &Env, config: &RegistryConfig, voter: &Address) -> i128 {
+        let Some(ref registry) = config.contributor_registry else {
+            return 0;
+        };
+        // contributor_registry exposes get_tier(contributor) -> ContributorTier
+        // (1 = Novice .. 4 = Core) and errors for unregistered addresses.
+        let tier = match env.try_invoke_contract::<u32, soroban_sdk::Error>(
+            registry,
+            &Symbol::new(env, "get_tier"),
+            soroban_sdk::vec![env, voter.into_val(env)],
+        ) {
+            Ok(Ok(tier)) => tier,
+            _ => return 0,
+        };
+        tier.checked_sub(1)
+            .and_then(|index| config.tier_multipliers_bps.get(index))
+            .unwrap_or(0) as i128
+    }
+
     /// Resolve voter weight based on the configured WeightMode.
     /// Returns 0 if the voter does not meet the minimum weight requirement.
-    fn resolve_weight(env: &Env, config: &RegistryConfig, voter: &Address) -> i128 {
-        let weight = match config.weight_mode {
+    ///
+    /// Reputation and token weights are snapshotted just before `snapshot`
+    /// (the start of the voting round) so delegations or tokens moved
+    /// mid-vote, even within the opening ledger, cannot be counted twice.  Quadratic weight depends on the credits spent and
+    /// is computed by the caller.
+    fn resolve_weight(env: &Env, config: &RegistryConfig, voter: &Address, snapshot: u64) -> i128 {
+        match config.weight_mode {
             WeightMode::Reputation => {
-                // Read reputation_score from contributor_registry via cross-contract call.
-                // The contributor_registry exposes get_reputation(contributor) -> u64.
-                // We call it generically via invoke_contract.
+                // contributor_registry exposes
+                // get_voting_power_in(contributor, scope, timestamp) -> u64,
+                // which includes reputation delegated to the voter.
                 if let Some(ref registry) = config.contributor_registry {
+                    let scope = DelegationScope::Contract(env.current_contract_address());
                     let score: u64 = env.invoke_contract(
                         registry,
-                        &Symbol::new(env, "get_reputation"),
-                        soroban_sdk::vec![env, voter.into_val(env)],
+                        &Symbol::new(env, "get_voting_power_in"),
+                        soroban_sdk::vec![
+                            env,
+                            voter.into_val(env),
+                            scope.into_val(env),
+                            snapshot.into_val(env)
+                        ],
                     );
                     score as i128
                 } else {
                     0
                 }
             }
-            WeightMode::TokenBalance => {
-                if let Some(ref token) = config.governance_token {
-                    TokenClient::new(env, token).balance(voter)
-                } else {
-                    0
-                }
+            WeightMode::TokenBalance => Self::locked_at(env, voter, snapshot),
+            WeightMode::SqrtTokenTier => {
+                let locked = Self::locked_at(env, voter, snapshot);
+                Self::isqrt(locked).saturating_mul(Self::tier_multiplier(env, config, voter))
+                    / BPS_DENOMINATOR as i128
             }
+            WeightMode::Quadratic => 0,
             WeightMode::Flat => {
                 // Any registered contributor gets weight 1.
                 // We check registration via contributor_registry if configured,
@@ -86,8 +424,7 @@ impl ProjectRegistryContract {
                     1
                 }
             }
-        };
-        weight
+        }
     }
 
     // ── Initialisation ────────────────────────────────────────────────────────
@@ -95,9 +432,12 @@ impl ProjectRegistryContract {
     /// Deploy and configure the registry.
     ///
     /// `quorum_threshold` — total weight-for votes needed to auto-verify.
-    /// `weight_mode`      — Reputation | TokenBalance | Flat.
-    /// `governance_token` — required when weight_mode = TokenBalance.
-    /// `contributor_registry` — required when weight_mode = Reputation | Flat.
+    /// `weight_mode`      — Reputation | TokenBalance | Flat | SqrtTokenTier |
+    ///                      Quadratic.
+    /// `governance_token` — required when weight_mode = TokenBalance |
+    ///                      SqrtTokenTier.
+    /// `contributor_registry` — required when weight_mode = Reputation |
+    ///                      SqrtTokenTier; optional for Flat.
     /// `min_voter_weight` — minimum weight a voter must hold to participate.
     pub fn initialize(
         env: Env,
@@ -122,11 +462,17 @@ impl ProjectRegistryContract {
             governance_token,
             contributor_registry,
             min_voter_weight,
+            voting_period_secs: DEFAULT_VOTING_PERIOD_SECS,
+            reapply_cooldown_secs: DEFAULT_REAPPLY_COOLDOWN_SECS,
+            appeal_quorum_bps: DEFAULT_APPEAL_QUORUM_BPS,
+            verification_validity_secs: 0,
+            max_weight_bps: 0,
+            tier_multipliers_bps: Vec::from_array(&env, DEFAULT_TIER_MULTIPLIERS_BPS),
         };
 
         env.storage().instance().set(&DataKey::Admin, &admin);
         env.storage().instance().set(&DataKey::Paused, &false);
-        env.storage().instance().set(&DataKey::Config, &config);
+        Self::save_config(&env, &config);
 
         events::InitializedEvent { admin }.publish(&env);
         Ok(())
@@ -135,7 +481,8 @@ impl ProjectRegistryContract {
     // ── Project registration ──────────────────────────────────────────────────
 
     /// Register a project for community verification.
-    /// Anyone can register a project they own.
+    /// Anyone can register a project they own, unless a permission registry
+    /// is configured, in which case the owner needs `propose_project`.
     pub fn register_project(
         env: Env,
         owner: Address,
@@ -144,16 +491,14 @@ impl ProjectRegistryContract {
     ) -> Result<(), RegistryError> {
         Self::require_not_paused(&env)?;
         owner.require_auth();
+        Self::require_permission(&env, &owner, PERMISSION_PROPOSE_PROJECT)?;
 
-        if env
-            .storage()
-            .persistent()
-            .has(&DataKey::Project(project_id))
-        {
+        if Self::project_exists(&env, project_id) {
             return Err(RegistryError::ProjectAlreadyRegistered);
         }
 
-        let entry = ProjectEntry {
+        let config = Self::load_config(&env)?;
+        let mut entry = ProjectEntry {
             project_id,
             owner: owner.clone(),
             name: name.clone(),
@@ -162,12 +507,14 @@ impl ProjectRegistryContract {
             votes_against: 0,
             registered_at: env.ledger().timestamp(),
             resolved_at: 0,
+            voting_starts_at: 0,
+            voting_ends_at: 0,
+            quorum: 0,
+            round: 0,
+            appeal: false,
+            verified_until: 0,
         };
 
-        env.storage()
-            .persistent()
-            .set(&DataKey::Project(project_id), &entry);
-
         events::ProjectRegisteredEvent {
             project_id,
             owner,
@@ -175,63 +522,265 @@ impl ProjectRegistryContract {
         }
         .publish(&env);
 
+        Self::open_round(&env, &mut entry, &config, false);
+        Self::save_project(&env, &entry);
+
         Ok(())
     }
 
+    // ── Project metadata ──────────────────────────────────────────────────────
+
+    /// Publish a new version of the project's metadata.  Only the owner may
+    /// update it; every version is kept and readable via
+    /// `get_metadata_version`.  Returns the new version number.
+    pub fn update_metadata(
+        env: Env,
+        owner: Address,
+        project_id: u64,
+        metadata: ProjectMetadata,
+    ) -> Result<u32, RegistryError> {
+        Self::require_not_paused(&env)?;
+        owner.require_auth();
+        let entry = Self::load_project(&env, project_id)?;
+        if entry.owner != owner {
+            return Err(RegistryError::Unauthorized);
+        }
+        Self::validate_metadata(&metadata)?;
+
+        let count: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::MetadataCount(project_id))
+            .unwrap_or(0);
+        let version = count + 1;
+        env.storage().persistent().set(
+            &DataKey::MetadataAt(project_id, version),
+            &MetadataVersion {
+                version,
+                metadata,
+                updated_at: env.ledger().timestamp(),
+            },
+        );
+        env.storage()
+            .persistent()
+            .set(&DataKey::MetadataCount(project_id), &version);
+
+        events::ProjectMetadataUpdatedEvent {
+            project_id,
+            version,
+            owner,
+        }
+        .publish(&env);
+
+        Ok(version)
+    }
+
+    /// Post the hash of a progress report for the project.  Returns the
+    /// update's index (starting at 0).
+    pub fn post_progress_update(
+        env: Env,
+        owner: Address,
+        project_id: u64,
+        content_hash: BytesN<32>,
+    ) -> Result<u32, RegistryError> {
+        Self::require_not_paused(&env)?;
+        owner.require_auth();
+        let entry = Self::load_project(&env, project_id)?;
+        if entry.owner != owner {
+            return Err(RegistryError::Unauthorized);
+        }
+
+        let index: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ProgressCount(project_id))
+            .unwrap_or(0);
+        env.storage().persistent().set(
+            &DataKey::ProgressAt(project_id, index),
+            &ProgressUpdate {
+                index,
+                content_hash: content_hash.clone(),
+                posted_at: env.ledger().timestamp(),
+            },
+        );
+        env.storage()
+            .persistent()
+            .set(&DataKey::ProgressCount(project_id), &(index + 1));
+
+        events::ProgressPostedEvent {
+            project_id,
+            index,
+            content_hash,
+        }
+        .publish(&env);
+
+        Ok(index)
+    }
+
     // ── Community voting ──────────────────────────────────────────────────────
 
     /// Cast a verification vote for a project.
     ///
     /// Weight is determined by the configured WeightMode:
-    ///   - Reputation: contributor_registry.get_reputation(voter)
-    ///   - TokenBalance: governance_token.balance(voter)
+    ///   - Reputation: contributor_registry voting power (incl. delegations)
+    ///     at the start of the round
+    ///   - TokenBalance: governance tokens locked at the start of the round
     ///   - Flat: 1 per registered contributor
+    ///   - SqrtTokenTier: sqrt(locked tokens) × tier multiplier
+    ///   - Quadratic: not available here; use `cast_quadratic_vote`
+    ///
+    /// A single vote never carries more than `max_weight_bps` of the
+    /// round's quorum.
+    ///
+    /// If votes_for reaches the round's quorum while ahead of votes_against
+    /// the project is auto-verified; if votes_against reaches it without
+    /// trailing, the project is auto-rejected.  Votes close
+    /// at `voting_ends_at`; after that the round is settled by
+    /// `finalize_vote`.
     ///
-    /// If votes_for reaches quorum_threshold the project is auto-verified.
-    /// If votes_against reaches quorum_threshold the project is auto-rejected.
+    /// When a permission registry is configured the voter needs `review`.
     pub fn cast_vote(
         env: Env,
         voter: Address,
         project_id: u64,
         support: bool,
     ) -> Result<VerificationStatus, RegistryError> {
-        Self::require_not_paused(&env)?;
         voter.require_auth();
+        Self::record_vote(&env, voter, project_id, support, 0)
+    }
 
-        let mut entry: ProjectEntry = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Project(project_id))
-            .ok_or(RegistryError::ProjectNotFound)?;
+    /// Cast a vote in `Quadratic` mode by spending `credits` of the voter's
+    /// vote credits for sqrt(`credits`) weight.  When the weight cap
+    /// applies only the credits the capped weight costs are spent.
+    pub fn cast_quadratic_vote(
+        env: Env,
+        voter: Address,
+        project_id: u64,
+        support: bool,
+        credits: i128,
+    ) -> Result<VerificationStatus, RegistryError> {
+        voter.require_auth();
+        if credits <= 0 {
+            return Err(RegistryError::InvalidAmount);
+        }
+        Self::record_vote(&env, voter, project_id, support, credits)
+    }
 
-        // Only pending projects accept votes
-        if entry.status != VerificationStatus::Pending {
+    /// Gasless variant of `cast_vote`.  `voter` signs
+    /// `("cast_vote_with_sig", voter, project_id, support, fee, nonce)`.  A
+    /// relayer fee is paid in the configured governance token, so it is only
+    /// available when one is set.
+    pub fn cast_vote_with_sig(
+        env: Env,
+        voter: Address,
+        project_id: u64,
+        support: bool,
+        fee: Option<RelayerFee>,
+    ) -> Result<VerificationStatus, RegistryError> {
+        let config = Self::load_config(&env)?;
+        signed_intent::require_signed(
+            &env,
+            &DataKey::SignedNonce(voter.clone()),
+            &voter,
+            "cast_vote_with_sig",
+            vec![
+                &env,
+                voter.into_val(&env),
+                project_id.into_val(&env),
+                support.into_val(&env),
+                fee.into_val(&env),
+            ],
+        );
+        let status = Self::record_vote(&env, voter.clone(), project_id, support, 0)?;
+        if fee.is_some() {
+            let token = config
+                .governance_token
+                .ok_or(RegistryError::InvalidRelayerFee)?;
+            signed_intent::pay_relayer_fee(&env, &token, &voter, &fee)
+                .map_err(|_| RegistryError::InvalidRelayerFee)?;
+        }
+        Ok(status)
+    }
+
+    /// Next nonce `signer` must sign for `cast_vote_with_sig`.
+    pub fn get_signed_nonce(env: Env, signer: Address) -> u64 {
+        signed_intent::nonce_of(&env, &DataKey::SignedNonce(signer))
+    }
+
+    /// `credits` is the number of vote credits to spend; it must be
+    /// positive in `Quadratic` mode and zero otherwise.
+    fn record_vote(
+        env: &Env,
+        voter: Address,
+        project_id: u64,
+        support: bool,
+        credits: i128,
+    ) -> Result<VerificationStatus, RegistryError> {
+        Self::require_not_paused(env)?;
+        Self::require_permission(env, &voter, PERMISSION_REVIEW)?;
+
+        let mut entry = Self::load_project(env, project_id)?;
+
+        // Only pending or suspended projects accept votes, and only until
+        // the deadline.  In a revocation vote `support` keeps the
+        // verification.
+        if entry.status != VerificationStatus::Pending
+            && entry.status != VerificationStatus::Suspended
+        {
             return Err(RegistryError::VotingClosed);
         }
+        if env.ledger().timestamp() > entry.voting_ends_at {
+            return Err(RegistryError::VotingPeriodEnded);
+        }
 
-        // Prevent double voting
-        let vote_key = DataKey::VoteCast(project_id, voter.clone());
+        // Prevent double voting (per round)
+        let vote_key = Self::vote_cast_key(project_id, entry.round, voter.clone());
         if env.storage().persistent().has(&vote_key) {
             return Err(RegistryError::AlreadyVoted);
         }
 
-        let config: RegistryConfig = env
-            .storage()
-            .instance()
-            .get(&DataKey::Config)
-            .ok_or(RegistryError::NotInitialized)?;
+        let config = Self::load_config(env)?;
 
-        let weight = Self::resolve_weight(&env, &config, &voter);
+        let quadratic = config.weight_mode == WeightMode::Quadratic;
+        if quadratic != (credits > 0) {
+            return Err(RegistryError::WrongWeightMode);
+        }
+        let mut weight = if quadratic {
+            if credits > Self::get_vote_credits(env.clone(), voter.clone()) {
+                return Err(RegistryError::InsufficientCredits);
+            }
+            Self::isqrt(credits)
+        } else {
+            Self::resolve_weight(env, &config, &voter, entry.voting_starts_at)
+        };
 
         if weight < config.min_voter_weight {
             return Err(RegistryError::InsufficientWeight);
         }
+        // A capped quadratic vote only spends the credits its capped weight
+        // costs.
+        let mut spent = credits;
+        if config.max_weight_bps > 0 {
+            let cap = entry.quorum.saturating_mul(config.max_weight_bps as i128)
+                / BPS_DENOMINATOR as i128;
+            if weight > cap {
+                weight = cap;
+                spent = cap.saturating_mul(cap);
+            }
+        }
+        if quadratic {
+            let remaining = Self::get_vote_credits(env.clone(), voter.clone()) - spent;
+            env.storage()
+                .persistent()
+                .set(&DataKey::VoteCredits(voter.clone()), &remaining);
+        }
 
         // Record vote
         env.storage().persistent().set(&vote_key, &true);
-        env.storage()
-            .persistent()
-            .set(&DataKey::VoterWeight(project_id, voter.clone()), &weight);
+        env.storage().persistent().set(
+            &Self::voter_weight_key(project_id, entry.round, voter.clone()),
+            &weight,
+        );
 
         if support {
             entry.votes_for = entry.votes_for.saturating_add(weight);
@@ -245,37 +794,273 @@ impl ProjectRegistryContract {
             weight,
             support,
         }
-        .publish(&env);
+        .publish(env);
 
-        // Auto-resolve if quorum reached
-        if entry.votes_for >= config.quorum_threshold {
-            entry.status = VerificationStatus::Verified;
-            entry.resolved_at = env.ledger().timestamp();
-            events::ProjectVerifiedEvent {
-                project_id,
-                votes_for: entry.votes_for,
-                votes_against: entry.votes_against,
-            }
-            .publish(&env);
-        } else if entry.votes_against >= config.quorum_threshold {
-            entry.status = VerificationStatus::Rejected;
-            entry.resolved_at = env.ledger().timestamp();
-            events::ProjectRejectedEvent {
-                project_id,
-                votes_for: entry.votes_for,
-                votes_against: entry.votes_against,
-            }
-            .publish(&env);
+        if let Some(status) = Self::round_outcome(&entry) {
+            Self::resolve(env, &mut entry, status);
         }
 
         let status = entry.status.clone();
+        Self::save_project(env, &entry);
+
+        Ok(status)
+    }
+
+    // ── Vote weight ───────────────────────────────────────────────────────────
+
+    /// Lock governance tokens in the registry.  TokenBalance and
+    /// SqrtTokenTier weights count tokens locked when a round opens, so the
+    /// same tokens cannot vote twice from different wallets.
+    pub fn lock_tokens(env: Env, voter: Address, amount: i128) -> Result<i128, RegistryError> {
+        Self::require_not_paused(&env)?;
+        voter.require_auth();
+        if amount <= 0 {
+            return Err(RegistryError::InvalidAmount);
+        }
+        let token = Self::load_config(&env)?
+            .governance_token
+            .ok_or(RegistryError::WrongWeightMode)?;
+        TokenClient::new(&env, &token).transfer(&voter, env.current_contract_address(), &amount);
+
+        let locked = Self::get_locked_tokens(env.clone(), voter.clone()).saturating_add(amount);
+        Self::write_lock(&env, &voter, locked);
+        events::TokensLockedEvent {
+            voter,
+            amount,
+            locked,
+        }
+        .publish(&env);
+        Ok(locked)
+    }
+
+    /// Withdraw locked governance tokens.  Votes already cast, and rounds
+    /// that opened while the tokens were locked, are unaffected.
+    pub fn unlock_tokens(env: Env, voter: Address, amount: i128) -> Result<i128, RegistryError> {
+        voter.require_auth();
+        if amount <= 0 {
+            return Err(RegistryError::InvalidAmount);
+        }
+        let current = Self::get_locked_tokens(env.clone(), voter.clone());
+        if amount > current {
+            return Err(RegistryError::InsufficientLockedBalance);
+        }
+        let token = Self::load_config(&env)?
+            .governance_token
+            .ok_or(RegistryError::WrongWeightMode)?;
+
+        let locked = current - amount;
+        Self::write_lock(&env, &voter, locked);
+        TokenClient::new(&env, &token).transfer(&env.current_contract_address(), &voter, &amount);
+        events::TokensUnlockedEvent {
+            voter,
+            amount,
+            locked,
+        }
+        .publish(&env);
+        Ok(locked)
+    }
+
+    /// Grant quadratic vote credits to `voter`.
+    pub fn grant_vote_credits(
+        env: Env,
+        admin: Address,
+        voter: Address,
+        amount: i128,
+    ) -> Result<i128, RegistryError> {
+        Self::require_admin(&env, &admin)?;
+        if amount <= 0 {
+            return Err(RegistryError::InvalidAmount);
+        }
+        let credits = Self::get_vote_credits(env.clone(), voter.clone()).saturating_add(amount);
         env.storage()
             .persistent()
-            .set(&DataKey::Project(project_id), &entry);
+            .set(&DataKey::VoteCredits(voter.clone()), &credits);
+        events::VoteCreditsGrantedEvent {
+            voter,
+            amount,
+            credits,
+        }
+        .publish(&env);
+        Ok(credits)
+    }
+
+    pub fn get_locked_tokens(env: Env, voter: Address) -> i128 {
+        Self::lock_history(&env, &voter)
+            .last()
+            .map(|checkpoint| checkpoint.amount)
+            .unwrap_or(0)
+    }
+
+    /// Governance tokens `voter` had locked before `timestamp`.
+    pub fn get_locked_tokens_at(env: Env, voter: Address, timestamp: u64) -> i128 {
+        Self::locked_at(&env, &voter, timestamp)
+    }
+
+    pub fn get_vote_credits(env: Env, voter: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::VoteCredits(voter))
+            .unwrap_or(0)
+    }
+
+    // ── Voting rounds ─────────────────────────────────────────────────────────
+
+    /// Close a round whose deadline has passed without an automatic result.
+    /// Anyone may call this.
+    ///
+    /// A verification vote verifies only if weight-for reached the round's
+    /// quorum and exceeds weight-against; otherwise the project is rejected.
+    /// A revocation vote revokes only if weight-against reached quorum and
+    /// exceeds weight-for; otherwise the verification is restored.
+    pub fn finalize_vote(env: Env, project_id: u64) -> Result<VerificationStatus, RegistryError> {
+        let mut entry = Self::load_project(&env, project_id)?;
+        if entry.status != VerificationStatus::Pending
+            && entry.status != VerificationStatus::Suspended
+        {
+            return Err(RegistryError::VotingClosed);
+        }
+        if env.ledger().timestamp() <= entry.voting_ends_at {
+            return Err(RegistryError::VotingPeriodActive);
+        }
+
+        let status = Self::round_outcome(&entry).unwrap_or(
+            if entry.status == VerificationStatus::Suspended {
+                VerificationStatus::Verified
+            } else {
+                VerificationStatus::Rejected
+            },
+        );
+        Self::resolve(&env, &mut entry, status.clone());
+        Self::save_project(&env, &entry);
 
         Ok(status)
     }
 
+    /// Re-submit a rejected project for a fresh vote at the base quorum once
+    /// `reapply_cooldown_secs` have passed since the rejection.
+    pub fn reapply(env: Env, owner: Address, project_id: u64) -> Result<(), RegistryError> {
+        Self::require_not_paused(&env)?;
+        owner.require_auth();
+        let mut entry = Self::load_project(&env, project_id)?;
+        if entry.owner != owner {
+            return Err(RegistryError::Unauthorized);
+        }
+        if entry.status != VerificationStatus::Rejected {
+            return Err(RegistryError::VotingClosed);
+        }
+        let config = Self::load_config(&env)?;
+        if env.ledger().timestamp()
+            < entry
+                .resolved_at
+                .saturating_add(config.reapply_cooldown_secs)
+        {
+            return Err(RegistryError::ReapplyCooldownActive);
+        }
+
+        entry.round += 1;
+        Self::open_round(&env, &mut entry, &config, false);
+        Self::save_project(&env, &entry);
+        Ok(())
+    }
+
+    /// Appeal a rejection: opens a fresh vote immediately, but at the raised
+    /// appeal quorum.  An appeal round cannot itself be appealed; the owner
+    /// has to wait out the cooldown and `reapply`.
+    pub fn appeal(env: Env, owner: Address, project_id: u64) -> Result<(), RegistryError> {
+        Self::require_not_paused(&env)?;
+        owner.require_auth();
+        let mut entry = Self::load_project(&env, project_id)?;
+        if entry.owner != owner {
+            return Err(RegistryError::Unauthorized);
+        }
+        if entry.status != VerificationStatus::Rejected || entry.appeal {
+            return Err(RegistryError::AppealNotAllowed);
+        }
+        let config = Self::load_config(&env)?;
+
+        entry.round += 1;
+        Self::open_round(&env, &mut entry, &config, true);
+        Self::save_project(&env, &entry);
+        Ok(())
+    }
+
+    // ── Verification upkeep ───────────────────────────────────────────────────
+
+    /// Challenge a current verification.  The project is `Suspended` (so
+    /// `is_verified` is false) while a revocation vote runs at the base
+    /// quorum; the challenger's weight counts as the first vote to revoke.
+    /// Only addresses that could vote may challenge, and after a challenge
+    /// is voted down the project cannot be challenged again for
+    /// `reapply_cooldown_secs`.
+    pub fn challenge_verification(
+        env: Env,
+        challenger: Address,
+        project_id: u64,
+    ) -> Result<(), RegistryError> {
+        Self::require_not_paused(&env)?;
+        challenger.require_auth();
+        let entry = Self::load_project(&env, project_id)?;
+        if !Self::is_current(&env, &entry) {
+            return Err(RegistryError::NotVerified);
+        }
+        let config = Self::load_config(&env)?;
+        if let Some(failed_at) = env
+            .storage()
+            .persistent()
+            .get::<_, u64>(&DataKey::ChallengeFailedAt(project_id))
+        {
+            if env.ledger().timestamp() < failed_at.saturating_add(config.reapply_cooldown_secs) {
+                return Err(RegistryError::ChallengeCooldownActive);
+            }
+        }
+
+        let mut entry = entry;
+        let verified_until = entry.verified_until;
+        entry.round += 1;
+        Self::open_round(&env, &mut entry, &config, false);
+        entry.status = VerificationStatus::Suspended;
+        entry.verified_until = verified_until;
+        Self::save_project(&env, &entry);
+
+        events::VerificationChallengedEvent {
+            project_id,
+            challenger: challenger.clone(),
+            round: entry.round,
+            voting_ends_at: entry.voting_ends_at,
+        }
+        .publish(&env);
+
+        Self::record_vote(&env, challenger, project_id, false, 0)?;
+        Ok(())
+    }
+
+    /// Put a project whose verification has lapsed back to a vote.  No
+    /// cooldown applies.
+    pub fn request_reverification(
+        env: Env,
+        owner: Address,
+        project_id: u64,
+    ) -> Result<(), RegistryError> {
+        Self::require_not_paused(&env)?;
+        owner.require_auth();
+        let mut entry = Self::load_project(&env, project_id)?;
+        if entry.owner != owner {
+            return Err(RegistryError::Unauthorized);
+        }
+        if entry.status != VerificationStatus::Verified {
+            return Err(RegistryError::NotVerified);
+        }
+        if Self::is_current(&env, &entry) {
+            return Err(RegistryError::VerificationNotExpired);
+        }
+        let config = Self::load_config(&env)?;
+
+        entry.round += 1;
+        Self::open_round(&env, &mut entry, &config, false);
+        Self::save_project(&env, &entry);
+        Ok(())
+    }
+
     // ── Admin override ────────────────────────────────────────────────────────
 
     /// Admin can override verification status (e.g. emergency revocation).
@@ -287,11 +1072,7 @@ impl ProjectRegistryContract {
     ) -> Result<(), RegistryError> {
         Self::require_admin(&env, &admin)?;
 
-        let mut entry: ProjectEntry = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Project(project_id))
-            .ok_or(RegistryError::ProjectNotFound)?;
+        let mut entry = Self::load_project(&env, project_id)?;
 
         entry.status = if verified {
             VerificationStatus::Verified
@@ -299,10 +1080,16 @@ impl ProjectRegistryContract {
             VerificationStatus::Rejected
         };
         entry.resolved_at = env.ledger().timestamp();
+        if verified {
+            let validity = Self::load_config(&env)?.verification_validity_secs;
+            entry.verified_until = if validity == 0 {
+                0
+            } else {
+                entry.resolved_at.saturating_add(validity)
+            };
+        }
 
-        env.storage()
-            .persistent()
-            .set(&DataKey::Project(project_id), &entry);
+        Self::save_project(&env, &entry);
 
         events::VerificationOverriddenEvent {
             project_id,
@@ -317,38 +1104,99 @@ impl ProjectRegistryContract {
     // ── Queries ───────────────────────────────────────────────────────────────
 
     pub fn get_project(env: Env, project_id: u64) -> Result<ProjectEntry, RegistryError> {
+        Self::load_project(&env, project_id)
+    }
+
+    /// Latest metadata version, if the owner has published any.
+    pub fn get_metadata(env: Env, project_id: u64) -> Option<MetadataVersion> {
+        let count: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::MetadataCount(project_id))
+            .unwrap_or(0);
+        if count == 0 {
+            return None;
+        }
         env.storage()
             .persistent()
-            .get(&DataKey::Project(project_id))
-            .ok_or(RegistryError::ProjectNotFound)
+            .get(&DataKey::MetadataAt(project_id, count))
     }
 
-    pub fn is_verified(env: Env, project_id: u64) -> bool {
+    pub fn get_metadata_version(
+        env: Env,
+        project_id: u64,
+        version: u32,
+    ) -> Result<MetadataVersion, RegistryError> {
         env.storage()
             .persistent()
-            .get::<_, ProjectEntry>(&DataKey::Project(project_id))
-            .map(|e| e.status == VerificationStatus::Verified)
-            .unwrap_or(false)
+            .get(&DataKey::MetadataAt(project_id, version))
+            .ok_or(RegistryError::MetadataVersionNotFound)
+    }
+
+    /// Where the project's funds should go: the payout address from the
+    /// latest metadata, falling back to the owner.
+    pub fn get_payout_address(env: Env, project_id: u64) -> Result<Address, RegistryError> {
+        let entry = Self::load_project(&env, project_id)?;
+        Ok(Self::get_metadata(env, project_id)
+            .map(|v| v.metadata.payout_address)
+            .unwrap_or(entry.owner))
     }
 
+    pub fn get_progress_update_count(env: Env, project_id: u64) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ProgressCount(project_id))
+            .unwrap_or(0)
+    }
+
+    /// Up to `limit` progress updates starting at index `start`, oldest
+    /// first.
+    pub fn get_progress_updates(
+        env: Env,
+        project_id: u64,
+        start: u32,
+        limit: u32,
+    ) -> Vec<ProgressUpdate> {
+        let count = Self::get_progress_update_count(env.clone(), project_id);
+        let end = start.saturating_add(limit).min(count);
+        let mut updates = Vec::new(&env);
+        for index in start..end {
+            if let Some(update) = env
+                .storage()
+                .persistent()
+                .get(&DataKey::ProgressAt(project_id, index))
+            {
+                updates.push_back(update);
+            }
+        }
+        updates
+    }
+
+    /// Whether `voter` has voted in the project's current round.
     pub fn has_voted(env: Env, project_id: u64, voter: Address) -> bool {
+        let round = match Self::load_project(&env, project_id) {
+            Ok(entry) => entry.round,
+            Err(_) => return false,
+        };
         env.storage()
             .persistent()
-            .has(&DataKey::VoteCast(project_id, voter))
+            .has(&Self::vote_cast_key(project_id, round, voter))
     }
 
+    /// Weight `voter` cast in the project's current round.
     pub fn get_voter_weight(env: Env, project_id: u64, voter: Address) -> i128 {
+        let round = match Self::load_project(&env, project_id) {
+            Ok(entry) => entry.round,
+            Err(_) => return 0,
+        };
         env.storage()
             .persistent()
-            .get(&DataKey::VoterWeight(project_id, voter))
+            .get(&Self::voter_weight_key(project_id, round, voter))
             .unwrap_or(0)
     }
 
     pub fn get_config(env: Env) -> Result<RegistryConfig, RegistryError> {
-        env.storage()
-            .instance()
-            .get(&DataKey::Config)
-            .ok_or(RegistryError::NotInitialized)
+        Self::load_config(&env)
     }
 
     pub fn get_admin(env: Env) -> Result<Address, RegistryError> {
@@ -370,17 +1218,114 @@ impl ProjectRegistryContract {
         if quorum_threshold <= 0 {
             return Err(RegistryError::InvalidThreshold);
         }
-        let mut config: RegistryConfig = env
-            .storage()
-            .instance()
-            .get(&DataKey::Config)
-            .ok_or(RegistryError::NotInitialized)?;
+        let mut config = Self::load_config(&env)?;
         config.quorum_threshold = quorum_threshold;
         config.min_voter_weight = min_voter_weight;
-        env.storage().instance().set(&DataKey::Config, &config);
+        Self::save_config(&env, &config);
+        Ok(())
+    }
+
+    /// Update the voting-round rules.  Changes apply to rounds opened
+    /// afterwards; running rounds keep their deadline and quorum.
+    pub fn set_voting_rules(
+        env: Env,
+        admin: Address,
+        voting_period_secs: u64,
+        reapply_cooldown_secs: u64,
+        appeal_quorum_bps: u32,
+    ) -> Result<(), RegistryError> {
+        Self::require_admin(&env, &admin)?;
+        if voting_period_secs == 0 || appeal_quorum_bps < BPS_DENOMINATOR {
+            return Err(RegistryError::InvalidThreshold);
+        }
+        let mut config = Self::load_config(&env)?;
+        config.voting_period_secs = voting_period_secs;
+        config.reapply_cooldown_secs = reapply_cooldown_secs;
+        config.appeal_quorum_bps = appeal_quorum_bps;
+        Self::save_config(&env, &config);
+
+        events::VotingRulesUpdatedEvent {
+            admin,
+            voting_period_secs,
+            reapply_cooldown_secs,
+            appeal_quorum_bps,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Set the per-vote weight cap (bps of quorum, 0 = uncapped) and the
+    /// SqrtTokenTier multipliers, one per tier from Novice to Core.
+    pub fn set_weight_rules(
+        env: Env,
+        admin: Address,
+        max_weight_bps: u32,
+        tier_multipliers_bps: Vec<u32>,
+    ) -> Result<(), RegistryError> {
+        Self::require_admin(&env, &admin)?;
+        if tier_multipliers_bps.len() != DEFAULT_TIER_MULTIPLIERS_BPS.len() as u32 {
+            return Err(RegistryError::InvalidThreshold);
+        }
+        let mut config = Self::load_config(&env)?;
+        config.max_weight_bps = max_weight_bps;
+        config.tier_multipliers_bps = tier_multipliers_bps.clone();
+        Self::save_config(&env, &config);
+
+        events::WeightRulesUpdatedEvent {
+            admin,
+            max_weight_bps,
+            tier_multipliers_bps,
+        }
+        .publish(&env);
         Ok(())
     }
 
+    /// Set how long new verifications stay valid (0 = indefinitely).
+    /// Existing verifications keep their expiry.
+    pub fn set_verification_validity(
+        env: Env,
+        admin: Address,
+        validity_secs: u64,
+    ) -> Result<(), RegistryError> {
+        Self::require_admin(&env, &admin)?;
+        let mut config = Self::load_config(&env)?;
+        config.verification_validity_secs = validity_secs;
+        Self::save_config(&env, &config);
+
+        events::VerificationValiditySetEvent {
+            admin,
+            validity_secs,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Set (or clear) the contributor_registry consulted for tier/badge-gated
+    /// permissions on registration and voting.
+    pub fn set_permission_registry(
+        env: Env,
+        admin: Address,
+        registry: Option<Address>,
+    ) -> Result<(), RegistryError> {
+        Self::require_admin(&env, &admin)?;
+        match registry {
+            Some(ref registry) => env
+                .storage()
+                .instance()
+                .set(&DataKey::PermissionRegistry, registry),
+            None => env
+                .storage()
+                .instance()
+                .remove(&DataKey::PermissionRegistry),
+        }
+        events::PermissionRegistrySetEvent { admin, registry }.publish(&env);
+        Ok(())
+    }
+
+    pub fn get_permission_registry(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::PermissionRegistry)
+    }
+
     pub fn pause(env: Env, admin: Address) -> Result<(), RegistryError> {
         Self::require_admin(&env, &admin)?;
         env.storage().instance().set(&DataKey::Paused, &true);
@@ -414,5 +1359,16 @@ impl ProjectRegistryContract {
     }
 }
 
+#[contractimpl]
+impl VerificationSourceTrait for ProjectRegistryContract {
+    /// Whether the project is verified right now: status `Verified` and
+    /// the verification has not lapsed.  Suspended projects are not.
+    fn is_verified(env: Env, project_id: u64) -> bool {
+        Self::load_project(&env, project_id)
+            .map(|e| Self::is_current(&env, &e))
+            .unwrap_or(false)
+    }
+}
+
 #[cfg(test)]
 mod test;
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, Symbol};
+use soroban_sdk::{contracttype, Address, BytesN, String, Symbol, Vec};
 
 /// Verification status of a project
 #[contracttype]
@@ -7,18 +7,45 @@ pub enum VerificationStatus {
     Pending,
     Verified,
     Rejected,
+    /// Verification challenged; a revocation vote is running.
+    Suspended,
 }
 
 /// How voter weight is determined
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum WeightMode {
-    /// Weight = contributor reputation_score read from contributor_registry
+    /// Weight = contributor voting power (own reputation plus delegations)
+    /// read from contributor_registry as of the project's registration
     Reputation,
     /// Weight = token balance of the governance token
     TokenBalance,
     /// Weight = flat 1 per registered contributor (one-address-one-vote)
     Flat,
+    /// Weight = sqrt(locked governance tokens) scaled by the voter's
+    /// contributor tier multiplier
+    SqrtTokenTier,
+    /// Weight = sqrt(credits spent); voters spend vote credits granted by
+    /// the admin via `cast_quadratic_vote`
+    Quadratic,
+}
+
+/// Scope of a contributor_registry delegation.  Mirrors
+/// `contributor_registry::DelegationScope`; votes here query `Contract(self)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DelegationScope {
+    Global,
+    Contract(Address),
+    Topic(Symbol),
+}
+
+/// A voter's locked governance-token balance from `timestamp` on.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LockCheckpoint {
+    pub timestamp: u64,
+    pub amount: i128,
 }
 
 /// Per-project registry entry
@@ -33,6 +60,73 @@ pub struct ProjectEntry {
     pub votes_against: i128,
     pub registered_at: u64,
     pub resolved_at: u64, // 0 = unresolved
+    /// Start of the current voting round; reputation weight is read as of
+    /// this time.
+    pub voting_starts_at: u64,
+    /// Votes are accepted until (and including) this timestamp.
+    pub voting_ends_at: u64,
+    /// Weight-for needed to verify in the current round.
+    pub quorum: i128,
+    /// 0 for the first vote; incremented by each re-application or appeal.
+    pub round: u32,
+    /// Whether the current round is an appeal.
+    pub appeal: bool,
+    /// When the current verification lapses (0 = never).
+    pub verified_until: u64,
+}
+
+/// `ProjectEntry` as stored before voting rounds existed, under
+/// `DataKey::Project`.  Upgraded to a `ProjectEntry` under
+/// `DataKey::ProjectV2` the next time the project is written.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyProjectEntry {
+    pub project_id: u64,
+    pub owner: Address,
+    pub name: Symbol,
+    pub status: VerificationStatus,
+    pub votes_for: i128,
+    pub votes_against: i128,
+    pub registered_at: u64,
+    pub resolved_at: u64,
+}
+
+/// Descriptive project metadata, maintained by the owner alongside the
+/// verification entry.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectMetadata {
+    /// Project description / pitch (max `MAX_DESCRIPTION_LEN` bytes).
+    pub description: String,
+    /// Website or `ipfs://` link (max `MAX_URL_LEN` bytes).
+    pub url: String,
+    /// Optional hash of the off-chain document behind `url`.
+    pub content_hash: Option<BytesN<32>>,
+    /// Category tags (max `MAX_TAGS`).
+    pub tags: Vec<Symbol>,
+    /// Where funds for the project should be sent.
+    pub payout_address: Address,
+    /// Team members (max `MAX_TEAM_MEMBERS`).
+    pub team: Vec<Address>,
+}
+
+/// One entry in a project's metadata history.  Versions start at 1.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MetadataVersion {
+    pub version: u32,
+    pub metadata: ProjectMetadata,
+    pub updated_at: u64,
+}
+
+/// A progress report posted by the project owner.  Only the hash of the
+/// report is stored on-chain.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgressUpdate {
+    pub index: u32,
+    pub content_hash: BytesN<32>,
+    pub posted_at: u64,
 }
 
 /// Global config set at initialization
@@ -49,6 +143,32 @@ pub struct RegistryConfig {
     pub contributor_registry: Option<Address>,
     /// Minimum weight a voter must have to cast a vote
     pub min_voter_weight: i128,
+    /// Length of each voting round in seconds
+    pub voting_period_secs: u64,
+    /// Seconds after a rejection before the owner may re-apply
+    pub reapply_cooldown_secs: u64,
+    /// Appeal quorum as basis points of `quorum_threshold` (>= 10_000)
+    pub appeal_quorum_bps: u32,
+    /// How long a verification stays valid in seconds (0 = indefinitely)
+    pub verification_validity_secs: u64,
+    /// Most weight one vote can carry, in basis points of the round's
+    /// quorum (0 = uncapped)
+    pub max_weight_bps: u32,
+    /// SqrtTokenTier multipliers in basis points, indexed by tier
+    /// (Novice, Builder, Architect, Core)
+    pub tier_multipliers_bps: Vec<u32>,
+}
+
+/// `RegistryConfig` as stored before voting rounds existed, under
+/// `DataKey::Config`.  The newer fields take their defaults.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyRegistryConfig {
+    pub quorum_threshold: i128,
+    pub weight_mode: WeightMode,
+    pub governance_token: Option<Address>,
+    pub contributor_registry: Option<Address>,
+    pub min_voter_weight: i128,
 }
 
 #[contracttype]
@@ -56,8 +176,21 @@ pub struct RegistryConfig {
 pub enum DataKey {
     Admin,
     Paused,
-    Config,
-    Project(u64),              // project_id -> ProjectEntry
-    VoteCast(u64, Address),    // (project_id, voter) -> bool
-    VoterWeight(u64, Address), // (project_id, voter) -> i128 (recorded at vote time)
+    Config,                              // -> LegacyRegistryConfig (pre-upgrade deployments)
+    Project(u64),                        // project_id -> LegacyProjectEntry (pre-upgrade entries)
+    VoteCast(u64, Address),              // (project_id, voter) -> bool (round 0)
+    VoterWeight(u64, Address), // (project_id, voter) -> i128 (round 0, recorded at vote time)
+    PermissionRegistry,        // -> Address (contributor_registry used for has_permission)
+    SignedNonce(Address),      // signer -> next nonce for cast_vote_with_sig
+    MetadataCount(u64),        // project_id -> number of metadata versions
+    MetadataAt(u64, u32),      // (project_id, version) -> MetadataVersion
+    ProgressCount(u64),        // project_id -> number of progress updates
+    ProgressAt(u64, u32),      // (project_id, index) -> ProgressUpdate
+    LockedTokens(Address),     // voter -> Vec<LockCheckpoint>
+    VoteCredits(Address),      // voter -> unspent quadratic vote credits
+    ConfigV2,                  // -> RegistryConfig
+    ProjectV2(u64),            // project_id -> ProjectEntry
+    RoundVoteCast(u64, u32, Address), // (project_id, round, voter) -> bool (rounds after 0)
+    RoundVoterWeight(u64, u32, Address), // (project_id, round, voter) -> i128 (rounds after 0)
+    ChallengeFailedAt(u64),    // project_id -> when the last challenge was voted down
 }
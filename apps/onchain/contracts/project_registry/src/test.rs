@@ -1,10 +1,12 @@
 use crate::errors::RegistryError;
-use crate::storage::{VerificationStatus, WeightMode};
+use crate::storage::{DelegationScope, ProjectMetadata, VerificationStatus, WeightMode};
 use crate::{ProjectRegistryContract, ProjectRegistryContractClient};
+use permission_interface::testutils::{MockPermissionRegistry, MockPermissionRegistryClient};
+use permission_interface::{PERMISSION_PROPOSE_PROJECT, PERMISSION_REVIEW};
 use soroban_sdk::{
-    symbol_short,
+    contract, contractimpl, symbol_short,
     testutils::{Address as _, Ledger},
-    Address, Env,
+    vec, Address, BytesN, Env, String, Symbol,
 };
 
 fn setup<'a>(
@@ -222,26 +224,120 @@ fn test_token_balance_weight_mode() {
         &1i128,
     );
 
+    // Voter with 60 locked tokens
+    let voter = Address::generate(&env);
+    token_admin_client.mint(&voter, &60);
+    client.lock_tokens(&voter, &60);
+    // Second voter with 50 locked tokens
+    let voter2 = Address::generate(&env);
+    token_admin_client.mint(&voter2, &50);
+    client.lock_tokens(&voter2, &50);
+    // Locks count from the next ledger on.
+    env.ledger().set_timestamp(1);
+
     let owner = Address::generate(&env);
     client.register_project(&owner, &1u64, &symbol_short!("P"));
 
-    // Voter with 60 tokens
-    let voter = Address::generate(&env);
-    token_admin_client.mint(&voter, &60);
     client.cast_vote(&voter, &1u64, &true);
-
     let entry = client.get_project(&1u64);
     assert_eq!(entry.votes_for, 60);
     assert_eq!(entry.status, VerificationStatus::Pending); // 60 < 100
 
-    // Second voter with 50 tokens → total 110 >= 100 → Verified
-    let voter2 = Address::generate(&env);
-    token_admin_client.mint(&voter2, &50);
+    // Total 110 >= 100 → Verified
     client.cast_vote(&voter2, &1u64, &true);
-
     assert!(client.is_verified(&1u64));
 }
 
+#[test]
+fn test_token_weight_is_snapshotted_at_round_start() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let admin = Address::generate(&env);
+    let token_addr = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    let token = soroban_sdk::token::StellarAssetClient::new(&env, &token_addr.address());
+    let id = env.register(ProjectRegistryContract, ());
+    let client = ProjectRegistryContractClient::new(&env, &id);
+    client.initialize(
+        &admin,
+        &100i128,
+        &WeightMode::TokenBalance,
+        &Some(token_addr.address()),
+        &None,
+        &1i128,
+    );
+
+    let voter = Address::generate(&env);
+    token.mint(&voter, &60);
+    client.lock_tokens(&voter, &60);
+
+    env.ledger().set_timestamp(2_000);
+    let owner = Address::generate(&env);
+    client.register_project(&owner, &1u64, &symbol_short!("P"));
+    client.cast_vote(&voter, &1u64, &true);
+
+    // Moving the tokens to a fresh wallet mid-round does not add weight.
+    env.ledger().set_timestamp(3_000);
+    assert_eq!(client.unlock_tokens(&voter, &60), 0);
+    let sybil = Address::generate(&env);
+    soroban_sdk::token::TokenClient::new(&env, &token_addr.address()).transfer(&voter, &sybil, &60);
+    client.lock_tokens(&sybil, &60);
+    assert_eq!(client.get_locked_tokens_at(&sybil, &2_000), 0);
+    assert_eq!(
+        client.try_cast_vote(&sybil, &1u64, &true),
+        Err(Ok(RegistryError::InsufficientWeight))
+    );
+    assert_eq!(client.get_project(&1u64).votes_for, 60);
+    assert_eq!(
+        client.try_unlock_tokens(&voter, &1),
+        Err(Ok(RegistryError::InsufficientLockedBalance))
+    );
+}
+
+#[test]
+fn test_tokens_relocked_in_the_snapshot_ledger_do_not_vote_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let admin = Address::generate(&env);
+    let token_addr = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    let token = soroban_sdk::token::StellarAssetClient::new(&env, &token_addr.address());
+    let id = env.register(ProjectRegistryContract, ());
+    let client = ProjectRegistryContractClient::new(&env, &id);
+    client.initialize(
+        &admin,
+        &100i128,
+        &WeightMode::TokenBalance,
+        &Some(token_addr.address()),
+        &None,
+        &1i128,
+    );
+    let voter = Address::generate(&env);
+    token.mint(&voter, &60);
+    client.lock_tokens(&voter, &30);
+
+    // Everything below happens in the ledger the round opens in.
+    env.ledger().set_timestamp(2_000);
+    let owner = Address::generate(&env);
+    client.register_project(&owner, &1u64, &symbol_short!("P"));
+    client.lock_tokens(&voter, &30);
+    client.cast_vote(&voter, &1u64, &true);
+    assert_eq!(client.get_voter_weight(&1u64, &voter), 30);
+
+    client.unlock_tokens(&voter, &60);
+    let second = Address::generate(&env);
+    soroban_sdk::token::TokenClient::new(&env, &token_addr.address())
+        .transfer(&voter, &second, &60);
+    client.lock_tokens(&second, &60);
+    assert_eq!(
+        client.try_cast_vote(&second, &1u64, &true),
+        Err(Ok(RegistryError::InsufficientWeight))
+    );
+    assert_eq!(client.get_project(&1u64).votes_for, 30);
+}
+
 // ── Admin override ────────────────────────────────────────────────────────────
 
 #[test]
@@ -338,3 +434,787 @@ fn test_pause_blocks_votes() {
         Err(Ok(RegistryError::ContractPaused))
     );
 }
+
+// ── Permission gating ─────────────────────────────────────────────────────────
+
+#[test]
+fn test_permission_registry_gates_registration_and_votes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env, 10, WeightMode::Flat);
+
+    let perms_id = env.register(MockPermissionRegistry, ());
+    let perms = MockPermissionRegistryClient::new(&env, &perms_id);
+    client.set_permission_registry(&admin, &Some(perms_id.clone()));
+    assert_eq!(client.get_permission_registry(), Some(perms_id));
+
+    let owner = Address::generate(&env);
+    assert_eq!(
+        client.try_register_project(&owner, &1u64, &symbol_short!("P")),
+        Err(Ok(RegistryError::PermissionDenied))
+    );
+    perms.allow(&owner, &Symbol::new(&env, PERMISSION_PROPOSE_PROJECT));
+    client.register_project(&owner, &1u64, &symbol_short!("P"));
+
+    let voter = Address::generate(&env);
+    assert_eq!(
+        client.try_cast_vote(&voter, &1u64, &true),
+        Err(Ok(RegistryError::PermissionDenied))
+    );
+    perms.allow(&voter, &Symbol::new(&env, PERMISSION_REVIEW));
+    client.cast_vote(&voter, &1u64, &true);
+    assert!(client.has_voted(&1u64, &voter));
+
+    // Clearing the registry lifts the gate.
+    client.set_permission_registry(&admin, &None);
+    let other = Address::generate(&env);
+    client.register_project(&other, &2u64, &symbol_short!("Q"));
+}
+
+// ── Gasless voting ────────────────────────────────────────────────────────────
+
+#[test]
+fn test_cast_vote_with_sig_pays_fee_in_governance_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    let token_admin_client = soroban_sdk::token::StellarAssetClient::new(&env, &token_addr);
+
+    let id = env.register(ProjectRegistryContract, ());
+    let client = ProjectRegistryContractClient::new(&env, &id);
+    client.initialize(
+        &admin,
+        &100i128,
+        &WeightMode::TokenBalance,
+        &Some(token_addr.clone()),
+        &None,
+        &1i128,
+    );
+
+    let voter = Address::generate(&env);
+    let relayer = Address::generate(&env);
+    token_admin_client.mint(&voter, &65);
+    client.lock_tokens(&voter, &60);
+    env.ledger().set_timestamp(1);
+
+    let owner = Address::generate(&env);
+    client.register_project(&owner, &1u64, &symbol_short!("P"));
+
+    let fee = Some(signed_intent::RelayerFee {
+        relayer: relayer.clone(),
+        amount: 5,
+    });
+    client.cast_vote_with_sig(&voter, &1u64, &true, &fee);
+
+    // Weight comes from the locked tokens; the fee from the free balance.
+    assert_eq!(client.get_project(&1u64).votes_for, 60);
+    assert_eq!(client.get_signed_nonce(&voter), 1);
+    let token = soroban_sdk::token::TokenClient::new(&env, &token_addr);
+    assert_eq!(token.balance(&relayer), 5);
+    assert_eq!(token.balance(&voter), 0);
+}
+
+#[test]
+fn test_cast_vote_with_sig_fee_requires_governance_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _) = setup(&env, 3, WeightMode::Flat);
+    let owner = Address::generate(&env);
+    client.register_project(&owner, &1u64, &symbol_short!("P"));
+
+    let voter = Address::generate(&env);
+    let fee = Some(signed_intent::RelayerFee {
+        relayer: Address::generate(&env),
+        amount: 1,
+    });
+    assert_eq!(
+        client.try_cast_vote_with_sig(&voter, &1u64, &true, &fee),
+        Err(Ok(RegistryError::InvalidRelayerFee))
+    );
+
+    client.cast_vote_with_sig(&voter, &1u64, &true, &None);
+    assert!(client.has_voted(&1u64, &voter));
+}
+
+// ── Delegated reputation ──────────────────────────────────────────────────────
+
+/// Stand-in for contributor_registry's `get_voting_power_in`: returns the
+/// power set for an exact (voter, scope, timestamp) and 0 otherwise.
+#[contract]
+pub struct MockVotingPowerRegistry;
+
+#[contractimpl]
+impl MockVotingPowerRegistry {
+    pub fn set_power(env: Env, who: Address, scope: DelegationScope, timestamp: u64, power: u64) {
+        env.storage()
+            .persistent()
+            .set(&(who, scope, timestamp), &power);
+    }
+
+    pub fn get_voting_power_in(
+        env: Env,
+        who: Address,
+        scope: DelegationScope,
+        timestamp: u64,
+    ) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&(who, scope, timestamp))
+            .unwrap_or(0)
+    }
+}
+
+#[test]
+fn test_reputation_weight_uses_scoped_power_at_registration() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let registry_id = env.register(MockVotingPowerRegistry, ());
+    let registry = MockVotingPowerRegistryClient::new(&env, &registry_id);
+
+    let admin = Address::generate(&env);
+    let id = env.register(ProjectRegistryContract, ());
+    let client = ProjectRegistryContractClient::new(&env, &id);
+    client.initialize(
+        &admin,
+        &100i128,
+        &WeightMode::Reputation,
+        &None,
+        &Some(registry_id.clone()),
+        &1i128,
+    );
+
+    env.ledger().set_timestamp(1_000);
+    let owner = Address::generate(&env);
+    client.register_project(&owner, &1u64, &symbol_short!("P"));
+
+    let voter = Address::generate(&env);
+    let scope = DelegationScope::Contract(id.clone());
+    registry.set_power(&voter, &scope, &1_000, &42);
+    // Power gained after registration is ignored.
+    registry.set_power(&voter, &scope, &2_000, &90);
+
+    env.ledger().set_timestamp(2_000);
+    client.cast_vote(&voter, &1u64, &true);
+    assert_eq!(client.get_project(&1u64).votes_for, 42);
+}
+
+// ── Metadata & progress feed ──────────────────────────────────────────────────
+
+fn sample_metadata(env: &Env, description: &str, payout: &Address) -> ProjectMetadata {
+    ProjectMetadata {
+        description: String::from_str(env, description),
+        url: String::from_str(env, "ipfs://bafybeigdyrzt"),
+        content_hash: Some(BytesN::from_array(env, &[7u8; 32])),
+        tags: vec![env, symbol_short!("defi"), symbol_short!("tooling")],
+        payout_address: payout.clone(),
+        team: vec![env, Address::generate(env)],
+    }
+}
+
+#[test]
+fn test_metadata_updates_are_versioned() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _) = setup(&env, 10, WeightMode::Flat);
+    let owner = Address::generate(&env);
+    client.register_project(&owner, &1u64, &symbol_short!("P"));
+
+    assert_eq!(client.get_metadata(&1u64), None);
+    assert_eq!(client.get_payout_address(&1u64), owner);
+
+    let payout = Address::generate(&env);
+    let first = sample_metadata(&env, "first pitch", &payout);
+    env.ledger().set_timestamp(10);
+    assert_eq!(client.update_metadata(&owner, &1u64, &first), 1);
+
+    let second = sample_metadata(&env, "revised pitch", &payout);
+    env.ledger().set_timestamp(20);
+    assert_eq!(client.update_metadata(&owner, &1u64, &second), 2);
+
+    let latest = client.get_metadata(&1u64).unwrap();
+    assert_eq!(latest.version, 2);
+    assert_eq!(latest.metadata, second);
+    assert_eq!(latest.updated_at, 20);
+    assert_eq!(client.get_metadata_version(&1u64, &1).metadata, first);
+    assert_eq!(client.get_payout_address(&1u64), payout);
+    assert_eq!(
+        client.try_get_metadata_version(&1u64, &3),
+        Err(Ok(RegistryError::MetadataVersionNotFound))
+    );
+}
+
+#[test]
+fn test_metadata_update_requires_owner_and_valid_fields() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _) = setup(&env, 10, WeightMode::Flat);
+    let owner = Address::generate(&env);
+    client.register_project(&owner, &1u64, &symbol_short!("P"));
+
+    let stranger = Address::generate(&env);
+    let metadata = sample_metadata(&env, "pitch", &owner);
+    assert_eq!(
+        client.try_update_metadata(&stranger, &1u64, &metadata),
+        Err(Ok(RegistryError::Unauthorized))
+    );
+
+    let empty = sample_metadata(&env, "", &owner);
+    assert_eq!(
+        client.try_update_metadata(&owner, &1u64, &empty),
+        Err(Ok(RegistryError::InvalidMetadata))
+    );
+
+    let mut tagged = sample_metadata(&env, "pitch", &owner);
+    for _ in 0..8 {
+        tagged.tags.push_back(symbol_short!("extra"));
+    }
+    assert_eq!(
+        client.try_update_metadata(&owner, &1u64, &tagged),
+        Err(Ok(RegistryError::InvalidMetadata))
+    );
+}
+
+#[test]
+fn test_progress_updates_feed() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _) = setup(&env, 10, WeightMode::Flat);
+    let owner = Address::generate(&env);
+    client.register_project(&owner, &1u64, &symbol_short!("P"));
+
+    for i in 0..3u8 {
+        let index = client.post_progress_update(&owner, &1u64, &BytesN::from_array(&env, &[i; 32]));
+        assert_eq!(index, i as u32);
+    }
+    assert_eq!(client.get_progress_update_count(&1u64), 3);
+
+    let page = client.get_progress_updates(&1u64, &1, &5);
+    assert_eq!(page.len(), 2);
+    assert_eq!(
+        page.get(0).unwrap().content_hash,
+        BytesN::from_array(&env, &[1u8; 32])
+    );
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_post_progress_update(&stranger, &1u64, &BytesN::from_array(&env, &[9u8; 32])),
+        Err(Ok(RegistryError::Unauthorized))
+    );
+}
+
+// ── Voting rounds & appeals ───────────────────────────────────────────────────
+
+const WEEK: u64 = 7 * 24 * 60 * 60;
+const MONTH: u64 = 30 * 24 * 60 * 60;
+
+#[test]
+fn test_votes_close_at_deadline() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (client, _) = setup(&env, 3, WeightMode::Flat);
+    let owner = Address::generate(&env);
+    client.register_project(&owner, &1u64, &symbol_short!("P"));
+    let entry = client.get_project(&1u64);
+    assert_eq!(entry.voting_ends_at, 1_000 + WEEK);
+    assert_eq!(entry.quorum, 3);
+
+    assert_eq!(
+        client.try_finalize_vote(&1u64),
+        Err(Ok(RegistryError::VotingPeriodActive))
+    );
+
+    env.ledger().set_timestamp(1_000 + WEEK + 1);
+    assert_eq!(
+        client.try_cast_vote(&Address::generate(&env), &1u64, &true),
+        Err(Ok(RegistryError::VotingPeriodEnded))
+    );
+}
+
+#[test]
+fn test_finalize_rejects_without_quorum_or_majority() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (client, _) = setup(&env, 2, WeightMode::Flat);
+    let owner = Address::generate(&env);
+    client.register_project(&owner, &1u64, &symbol_short!("P"));
+    client.cast_vote(&Address::generate(&env), &1u64, &true);
+
+    env.ledger().set_timestamp(1_000 + WEEK + 1);
+    assert_eq!(client.finalize_vote(&1u64), VerificationStatus::Rejected);
+    let entry = client.get_project(&1u64);
+    assert_eq!(entry.resolved_at, 1_000 + WEEK + 1);
+    assert_eq!(
+        client.try_finalize_vote(&1u64),
+        Err(Ok(RegistryError::VotingClosed))
+    );
+}
+
+#[test]
+fn test_running_round_keeps_its_quorum() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env, 2, WeightMode::Flat);
+    let owner = Address::generate(&env);
+    client.register_project(&owner, &1u64, &symbol_short!("P"));
+    client.update_config(&admin, &5, &1);
+
+    client.cast_vote(&Address::generate(&env), &1u64, &false);
+    client.cast_vote(&Address::generate(&env), &1u64, &true);
+    assert_eq!(
+        client.get_project(&1u64).status,
+        VerificationStatus::Pending
+    );
+    client.cast_vote(&Address::generate(&env), &1u64, &true);
+    assert!(client.is_verified(&1u64));
+}
+
+#[test]
+fn test_reapply_after_cooldown_opens_new_round() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (client, _) = setup(&env, 1, WeightMode::Flat);
+    let owner = Address::generate(&env);
+    client.register_project(&owner, &1u64, &symbol_short!("P"));
+    let voter = Address::generate(&env);
+    client.cast_vote(&voter, &1u64, &false);
+    assert_eq!(
+        client.get_project(&1u64).status,
+        VerificationStatus::Rejected
+    );
+
+    assert_eq!(
+        client.try_reapply(&owner, &1u64),
+        Err(Ok(RegistryError::ReapplyCooldownActive))
+    );
+
+    env.ledger().set_timestamp(1_000 + MONTH);
+    client.reapply(&owner, &1u64);
+    let entry = client.get_project(&1u64);
+    assert_eq!(entry.status, VerificationStatus::Pending);
+    assert_eq!(entry.round, 1);
+    assert_eq!(entry.resolved_at, 0);
+    assert_eq!(entry.votes_against, 0);
+    // Votes are tracked per round.
+    assert!(!client.has_voted(&1u64, &voter));
+    client.cast_vote(&voter, &1u64, &true);
+    assert!(client.is_verified(&1u64));
+}
+
+#[test]
+fn test_appeal_requires_higher_quorum_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env, 2, WeightMode::Flat);
+    let owner = Address::generate(&env);
+    client.register_project(&owner, &1u64, &symbol_short!("P"));
+    assert_eq!(
+        client.try_appeal(&owner, &1u64),
+        Err(Ok(RegistryError::AppealNotAllowed))
+    );
+    client.override_verification(&admin, &1u64, &false);
+
+    client.appeal(&owner, &1u64);
+    let entry = client.get_project(&1u64);
+    assert!(entry.appeal);
+    assert_eq!(entry.quorum, 3); // 150% of 2
+
+    for _ in 0..3 {
+        client.cast_vote(&Address::generate(&env), &1u64, &false);
+    }
+    assert_eq!(
+        client.try_appeal(&owner, &1u64),
+        Err(Ok(RegistryError::AppealNotAllowed))
+    );
+}
+
+// ── Verification expiry & challenges ──────────────────────────────────────────
+
+#[test]
+fn test_verification_expires_and_can_be_renewed() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (client, admin) = setup(&env, 1, WeightMode::Flat);
+    client.set_verification_validity(&admin, &MONTH);
+    let owner = Address::generate(&env);
+    client.register_project(&owner, &1u64, &symbol_short!("P"));
+    client.cast_vote(&Address::generate(&env), &1u64, &true);
+    assert_eq!(client.get_project(&1u64).verified_until, 1_000 + MONTH);
+    assert!(client.is_verified(&1u64));
+    assert_eq!(
+        client.try_request_reverification(&owner, &1u64),
+        Err(Ok(RegistryError::VerificationNotExpired))
+    );
+
+    env.ledger().set_timestamp(1_000 + MONTH);
+    assert!(!client.is_verified(&1u64));
+    assert_eq!(
+        client.try_challenge_verification(&Address::generate(&env), &1u64),
+        Err(Ok(RegistryError::NotVerified))
+    );
+
+    client.request_reverification(&owner, &1u64);
+    let entry = client.get_project(&1u64);
+    assert_eq!(entry.status, VerificationStatus::Pending);
+    assert_eq!(entry.round, 1);
+    client.cast_vote(&Address::generate(&env), &1u64, &true);
+    assert_eq!(client.get_project(&1u64).verified_until, 1_000 + 2 * MONTH);
+    assert!(client.is_verified(&1u64));
+}
+
+#[test]
+fn test_challenge_suspends_and_vote_revokes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env, 2, WeightMode::Flat);
+    let owner = Address::generate(&env);
+    client.register_project(&owner, &1u64, &symbol_short!("P"));
+    assert_eq!(
+        client.try_challenge_verification(&Address::generate(&env), &1u64),
+        Err(Ok(RegistryError::NotVerified))
+    );
+    client.override_verification(&admin, &1u64, &true);
+
+    let challenger = Address::generate(&env);
+    client.challenge_verification(&challenger, &1u64);
+    let entry = client.get_project(&1u64);
+    assert_eq!(entry.status, VerificationStatus::Suspended);
+    assert_eq!(entry.round, 1);
+    assert_eq!(entry.votes_against, 1);
+    assert!(client.has_voted(&1u64, &challenger));
+    assert!(!client.is_verified(&1u64));
+
+    client.cast_vote(&Address::generate(&env), &1u64, &false);
+    assert_eq!(
+        client.get_project(&1u64).status,
+        VerificationStatus::Rejected
+    );
+}
+
+#[test]
+fn test_failed_challenge_restores_verification() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (client, admin) = setup(&env, 3, WeightMode::Flat);
+    client.set_verification_validity(&admin, &MONTH);
+    let owner = Address::generate(&env);
+    client.register_project(&owner, &1u64, &symbol_short!("P"));
+    client.override_verification(&admin, &1u64, &true);
+
+    env.ledger().set_timestamp(2_000);
+    client.challenge_verification(&Address::generate(&env), &1u64);
+    client.cast_vote(&Address::generate(&env), &1u64, &true);
+
+    env.ledger().set_timestamp(2_000 + WEEK + 1);
+    assert_eq!(client.finalize_vote(&1u64), VerificationStatus::Verified);
+    // A restored verification keeps its original expiry.
+    assert_eq!(client.get_project(&1u64).verified_until, 1_000 + MONTH);
+    assert!(client.is_verified(&1u64));
+}
+
+#[test]
+fn test_tied_challenge_restores_verification() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env, 2, WeightMode::Flat);
+    let owner = Address::generate(&env);
+    client.register_project(&owner, &1u64, &symbol_short!("P"));
+    client.override_verification(&admin, &1u64, &true);
+
+    client.challenge_verification(&Address::generate(&env), &1u64);
+    client.cast_vote(&Address::generate(&env), &1u64, &true);
+    let entry = client.get_project(&1u64);
+    assert_eq!(entry.votes_for, entry.votes_against);
+    assert_eq!(entry.status, VerificationStatus::Suspended);
+
+    env.ledger().set_timestamp(WEEK + 1);
+    assert_eq!(client.finalize_vote(&1u64), VerificationStatus::Verified);
+}
+
+#[test]
+fn test_tied_verification_vote_rejects() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _) = setup(&env, 2, WeightMode::Flat);
+    let owner = Address::generate(&env);
+    client.register_project(&owner, &1u64, &symbol_short!("P"));
+
+    client.cast_vote(&Address::generate(&env), &1u64, &true);
+    client.cast_vote(&Address::generate(&env), &1u64, &false);
+    assert_eq!(
+        client.get_project(&1u64).status,
+        VerificationStatus::Pending
+    );
+
+    env.ledger().set_timestamp(WEEK + 1);
+    assert_eq!(client.finalize_vote(&1u64), VerificationStatus::Rejected);
+}
+
+#[test]
+fn test_repeated_challenges_wait_out_cooldown() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (client, admin) = setup(&env, 2, WeightMode::Flat);
+    client.set_voting_rules(&admin, &WEEK, &MONTH, &15_000);
+    let owner = Address::generate(&env);
+    client.register_project(&owner, &1u64, &symbol_short!("P"));
+    client.override_verification(&admin, &1u64, &true);
+
+    // A challenge nobody else backs is restored at the deadline.
+    client.challenge_verification(&Address::generate(&env), &1u64);
+    env.ledger().set_timestamp(1_000 + WEEK + 1);
+    assert_eq!(client.finalize_vote(&1u64), VerificationStatus::Verified);
+
+    // Neither the same nor a fresh challenger can suspend it again yet.
+    for _ in 0..2 {
+        assert_eq!(
+            client.try_challenge_verification(&Address::generate(&env), &1u64),
+            Err(Ok(RegistryError::ChallengeCooldownActive))
+        );
+    }
+    assert!(client.is_verified(&1u64));
+
+    env.ledger().set_timestamp(1_000 + WEEK + 1 + MONTH);
+    client.challenge_verification(&Address::generate(&env), &1u64);
+    assert_eq!(
+        client.get_project(&1u64).status,
+        VerificationStatus::Suspended
+    );
+}
+
+// ── Hybrid & quadratic weights ────────────────────────────────────────────────
+
+#[contract]
+pub struct MockTierRegistry;
+
+#[contractimpl]
+impl MockTierRegistry {
+    pub fn set_tier(env: Env, who: Address, tier: u32) {
+        env.storage().persistent().set(&who, &tier);
+    }
+
+    pub fn get_tier(env: Env, who: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&who)
+            .expect("not registered")
+    }
+}
+
+#[test]
+fn test_sqrt_token_tier_weight() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let token_addr = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    let token = soroban_sdk::token::StellarAssetClient::new(&env, &token_addr.address());
+    let tiers_id = env.register(MockTierRegistry, ());
+    let tiers = MockTierRegistryClient::new(&env, &tiers_id);
+    let id = env.register(ProjectRegistryContract, ());
+    let client = ProjectRegistryContractClient::new(&env, &id);
+    client.initialize(
+        &admin,
+        &100i128,
+        &WeightMode::SqrtTokenTier,
+        &Some(token_addr.address()),
+        &Some(tiers_id),
+        &1i128,
+    );
+
+    let core = Address::generate(&env);
+    token.mint(&core, &400);
+    client.lock_tokens(&core, &400);
+    tiers.set_tier(&core, &4);
+    let outsider = Address::generate(&env);
+    token.mint(&outsider, &10_000);
+    client.lock_tokens(&outsider, &10_000);
+    env.ledger().set_timestamp(1);
+
+    let owner = Address::generate(&env);
+    client.register_project(&owner, &1u64, &symbol_short!("P"));
+    client.cast_vote(&core, &1u64, &true);
+    // sqrt(400) × 3.0
+    assert_eq!(client.get_voter_weight(&1u64, &core), 60);
+    // Unregistered addresses have no tier and so no weight.
+    assert_eq!(
+        client.try_cast_vote(&outsider, &1u64, &true),
+        Err(Ok(RegistryError::InsufficientWeight))
+    );
+}
+
+#[test]
+fn test_quadratic_votes_spend_credits() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env, 10, WeightMode::Quadratic);
+    let owner = Address::generate(&env);
+    client.register_project(&owner, &1u64, &symbol_short!("P"));
+    client.register_project(&owner, &2u64, &symbol_short!("Q"));
+
+    let voter = Address::generate(&env);
+    client.grant_vote_credits(&admin, &voter, &100);
+    assert_eq!(
+        client.try_cast_vote(&voter, &1u64, &true),
+        Err(Ok(RegistryError::WrongWeightMode))
+    );
+    client.cast_quadratic_vote(&voter, &1u64, &true, &64);
+    assert_eq!(client.get_voter_weight(&1u64, &voter), 8);
+    assert_eq!(client.get_vote_credits(&voter), 36);
+    assert_eq!(
+        client.try_cast_quadratic_vote(&voter, &2u64, &true, &37),
+        Err(Ok(RegistryError::InsufficientCredits))
+    );
+    client.cast_quadratic_vote(&voter, &2u64, &false, &36);
+    assert_eq!(client.get_voter_weight(&2u64, &voter), 6);
+    assert_eq!(client.get_vote_credits(&voter), 0);
+}
+
+#[test]
+fn test_quadratic_vote_rejected_in_other_modes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env, 10, WeightMode::Flat);
+    let owner = Address::generate(&env);
+    client.register_project(&owner, &1u64, &symbol_short!("P"));
+    let voter = Address::generate(&env);
+    client.grant_vote_credits(&admin, &voter, &100);
+    assert_eq!(
+        client.try_cast_quadratic_vote(&voter, &1u64, &true, &4),
+        Err(Ok(RegistryError::WrongWeightMode))
+    );
+}
+
+#[test]
+fn test_weight_cap_limits_single_vote() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env, 10, WeightMode::Quadratic);
+    client.set_weight_rules(&admin, &3_000, &vec![&env, 10_000, 15_000, 20_000, 30_000]);
+    assert_eq!(
+        client.try_set_weight_rules(&admin, &0, &vec![&env, 10_000]),
+        Err(Ok(RegistryError::InvalidThreshold))
+    );
+    let owner = Address::generate(&env);
+    client.register_project(&owner, &1u64, &symbol_short!("P"));
+
+    let whale = Address::generate(&env);
+    client.grant_vote_credits(&admin, &whale, &10_000);
+    client.cast_quadratic_vote(&whale, &1u64, &true, &10_000);
+    // sqrt(10_000) = 100, capped at 30% of the quorum; only the 3² credits
+    // the capped weight costs are spent.
+    assert_eq!(client.get_voter_weight(&1u64, &whale), 3);
+    assert_eq!(client.get_vote_credits(&whale), 10_000 - 9);
+    assert_eq!(
+        client.get_project(&1u64).status,
+        VerificationStatus::Pending
+    );
+}
+
+// ── Upgrades ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_records_written_before_voting_rounds_still_load() {
+    use crate::storage::{DataKey, LegacyProjectEntry, LegacyRegistryConfig};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let early_voter = Address::generate(&env);
+    let id = env.register(ProjectRegistryContract, ());
+    let client = ProjectRegistryContractClient::new(&env, &id);
+
+    env.as_contract(&id, || {
+        let instance = env.storage().instance();
+        instance.set(&DataKey::Admin, &admin);
+        instance.set(&DataKey::Paused, &false);
+        instance.set(
+            &DataKey::Config,
+            &LegacyRegistryConfig {
+                quorum_threshold: 2,
+                weight_mode: WeightMode::Flat,
+                governance_token: None,
+                contributor_registry: None,
+                min_voter_weight: 1,
+            },
+        );
+        let persistent = env.storage().persistent();
+        persistent.set(
+            &DataKey::Project(1),
+            &LegacyProjectEntry {
+                project_id: 1,
+                owner: owner.clone(),
+                name: symbol_short!("Old"),
+                status: VerificationStatus::Pending,
+                votes_for: 1,
+                votes_against: 0,
+                registered_at: 0,
+                resolved_at: 0,
+            },
+        );
+        persistent.set(
+            &DataKey::Project(2),
+            &LegacyProjectEntry {
+                project_id: 2,
+                owner: owner.clone(),
+                name: symbol_short!("Stale"),
+                status: VerificationStatus::Pending,
+                votes_for: 0,
+                votes_against: 0,
+                registered_at: 0,
+                resolved_at: 0,
+            },
+        );
+        persistent.set(&DataKey::VoteCast(1, early_voter.clone()), &true);
+        persistent.set(&DataKey::VoterWeight(1, early_voter.clone()), &1i128);
+    });
+
+    let config = client.get_config();
+    assert_eq!(config.quorum_threshold, 2);
+    assert!(config.voting_period_secs > 0);
+
+    let entry = client.get_project(&1u64);
+    assert_eq!(entry.round, 0);
+    assert_eq!(entry.quorum, 2);
+    assert_eq!(entry.votes_for, 1);
+    assert!(client.has_voted(&1u64, &early_voter));
+    assert_eq!(client.get_voter_weight(&1u64, &early_voter), 1);
+
+    // Votes recorded before the upgrade still block a second vote.
+    assert_eq!(
+        client.try_cast_vote(&early_voter, &1u64, &true),
+        Err(Ok(RegistryError::AlreadyVoted))
+    );
+    let status = client.cast_vote(&Address::generate(&env), &1u64, &true);
+    assert_eq!(status, VerificationStatus::Verified);
+    assert_eq!(client.get_project(&1u64).votes_for, 2);
+
+    // Legacy rounds end one voting period after registration.
+    let period = config.voting_period_secs;
+    assert_eq!(client.get_project(&2u64).voting_ends_at, period);
+    env.ledger().with_mut(|l| l.timestamp = period);
+    assert_eq!(
+        client.try_finalize_vote(&2u64),
+        Err(Ok(RegistryError::VotingPeriodActive))
+    );
+    env.ledger().with_mut(|l| l.timestamp = period + 1);
+    assert_eq!(client.finalize_vote(&2u64), VerificationStatus::Rejected);
+    assert_eq!(
+        client.get_project(&2u64).status,
+        VerificationStatus::Rejected
+    );
+
+    client.update_config(&admin, &3, &1);
+    assert_eq!(client.get_config().quorum_threshold, 3);
+    assert!(client.is_verified(&1u64));
+}
@@ -0,0 +1,201 @@
+#![no_std]
+
+//! Relayer-friendly ("gasless") entrypoints.
+//!
+//! A signer authorizes a specific invocation off-chain by signing a
+//! `SorobanAuthorizationEntry` whose arguments are
+//!
+//!   `(function_name, args..., nonce)`
+//!
+//! and a relayer submits the transaction and pays the network fee.  Each
+//! contract keeps a per-signer nonce under a storage key of its choosing, so
+//! a signed intent can be executed exactly once.  Optionally the signer can
+//! reimburse the relayer with a `RelayerFee` paid in the operation's token;
+//! the fee must be part of the signed `args` so a relayer cannot inflate it.
+
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{contracterror, contracttype, Address, Env, IntoVal, Symbol, Val, Vec};
+
+/// TTL bump applied to nonce entries (mirrors the contracts' own values).
+const LEDGER_THRESHOLD: u32 = 100_000;
+const LEDGER_BUMP: u32 = 518_400;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum IntentError {
+    InvalidRelayerFee = 1,
+}
+
+/// Reimbursement the signer agrees to pay the relayer, denominated in the
+/// token of the operation being relayed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RelayerFee {
+    pub relayer: Address,
+    pub amount: i128,
+}
+
+/// Current nonce stored under `key` (0 if none has been consumed yet).
+pub fn nonce_of<K>(env: &Env, key: &K) -> u64
+where
+    K: IntoVal<Env, Val>,
+{
+    let nonce = env.storage().persistent().get(key).unwrap_or(0);
+    if env.storage().persistent().has(key) {
+        env.storage()
+            .persistent()
+            .extend_ttl(key, LEDGER_THRESHOLD, LEDGER_BUMP);
+    }
+    nonce
+}
+
+/// Require `signer` to have authorized `(function, args..., nonce)` for the
+/// nonce currently stored under `nonce_key`, then advance that nonce.
+/// Returns the nonce that was consumed.
+pub fn require_signed<K>(
+    env: &Env,
+    nonce_key: &K,
+    signer: &Address,
+    function: &str,
+    args: Vec<Val>,
+) -> u64
+where
+    K: IntoVal<Env, Val>,
+{
+    let nonce = nonce_of(env, nonce_key);
+
+    let mut scope: Vec<Val> = Vec::new(env);
+    scope.push_back(Symbol::new(env, function).into_val(env));
+    scope.append(&args);
+    scope.push_back(nonce.into_val(env));
+    signer.require_auth_for_args(scope);
+
+    env.storage().persistent().set(nonce_key, &(nonce + 1));
+    env.storage()
+        .persistent()
+        .extend_ttl(nonce_key, LEDGER_THRESHOLD, LEDGER_BUMP);
+    nonce
+}
+
+/// Transfer the relayer fee, if any, from `signer` to the relayer in `token`.
+pub fn pay_relayer_fee(
+    env: &Env,
+    token: &Address,
+    signer: &Address,
+    fee: &Option<RelayerFee>,
+) -> Result<(), IntentError> {
+    let fee = match fee {
+        Some(fee) => fee,
+        None => return Ok(()),
+    };
+    if fee.amount < 0 {
+        return Err(IntentError::InvalidRelayerFee);
+    }
+    if fee.amount > 0 {
+        TokenClient::new(env, token).transfer(signer, &fee.relayer, &fee.amount);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{nonce_of, pay_relayer_fee, require_signed, IntentError, RelayerFee};
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::token::{StellarAssetClient, TokenClient};
+    use soroban_sdk::{contract, contractimpl, symbol_short, vec, Address, Env, IntoVal, Symbol};
+
+    fn nonce_key(signer: &Address) -> (Symbol, Address) {
+        (symbol_short!("NONCE"), signer.clone())
+    }
+
+    #[contract]
+    struct DummyContract;
+
+    #[contractimpl]
+    impl DummyContract {
+        pub fn relay(
+            env: Env,
+            signer: Address,
+            token: Address,
+            fee: Option<RelayerFee>,
+        ) -> Result<u64, IntentError> {
+            let nonce = require_signed(
+                &env,
+                &nonce_key(&signer),
+                &signer,
+                "relay",
+                vec![&env, token.into_val(&env), fee.into_val(&env)],
+            );
+            pay_relayer_fee(&env, &token, &signer, &fee)?;
+            Ok(nonce)
+        }
+
+        pub fn nonce(env: Env, signer: Address) -> u64 {
+            nonce_of(&env, &nonce_key(&signer))
+        }
+    }
+
+    struct Setup {
+        env: Env,
+        client: DummyContractClient<'static>,
+        token: Address,
+        signer: Address,
+        relayer: Address,
+    }
+
+    fn setup() -> Setup {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(DummyContract, ());
+        let client = DummyContractClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        let signer = Address::generate(&env);
+        let relayer = Address::generate(&env);
+        let token = env.register_stellar_asset_contract_v2(admin).address();
+        StellarAssetClient::new(&env, &token).mint(&signer, &100);
+        Setup {
+            env,
+            client,
+            token,
+            signer,
+            relayer,
+        }
+    }
+
+    #[test]
+    fn require_signed_advances_nonce() {
+        let s = setup();
+        assert_eq!(s.client.nonce(&s.signer), 0);
+        assert_eq!(s.client.relay(&s.signer, &s.token, &None), 0);
+        assert_eq!(s.client.relay(&s.signer, &s.token, &None), 1);
+        assert_eq!(s.client.nonce(&s.signer), 2);
+    }
+
+    #[test]
+    fn relayer_fee_is_paid_in_token() {
+        let s = setup();
+        let fee = Some(RelayerFee {
+            relayer: s.relayer.clone(),
+            amount: 15,
+        });
+        s.client.relay(&s.signer, &s.token, &fee);
+
+        let token = TokenClient::new(&s.env, &s.token);
+        assert_eq!(token.balance(&s.relayer), 15);
+        assert_eq!(token.balance(&s.signer), 85);
+    }
+
+    #[test]
+    fn negative_relayer_fee_is_rejected() {
+        let s = setup();
+        let fee = Some(RelayerFee {
+            relayer: s.relayer.clone(),
+            amount: -1,
+        });
+        assert_eq!(
+            s.client.try_relay(&s.signer, &s.token, &fee),
+            Err(Ok(IntentError::InvalidRelayerFee))
+        );
+    }
+}
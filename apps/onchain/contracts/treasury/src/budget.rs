@@ -0,0 +1,107 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::errors::TreasuryError;
+use crate::storage::{
+    BudgetCategory, CategoryLedger, CategoryReport, DataKey, LEDGER_BUMP, LEDGER_THRESHOLD,
+};
+
+pub fn get_category(env: &Env, category: &Symbol) -> Result<BudgetCategory, TreasuryError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Category(category.clone()))
+        .ok_or(TreasuryError::CategoryNotFound)
+}
+
+pub fn get_ledger(env: &Env, category: &Symbol, token: &Address) -> CategoryLedger {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CategoryLedger(category.clone(), token.clone()))
+        .unwrap_or_default()
+}
+
+fn save_ledger(env: &Env, category: &Symbol, token: &Address, ledger: &CategoryLedger) {
+    let key = DataKey::CategoryLedger(category.clone(), token.clone());
+    env.storage().persistent().set(&key, ledger);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+}
+
+/// Start of the period containing `now`.
+fn period_start(config: &BudgetCategory, now: u64) -> u64 {
+    now - now % config.period_secs
+}
+
+/// Allocated so far in the current period.
+fn current_period_allocated(config: &BudgetCategory, ledger: &CategoryLedger, now: u64) -> i128 {
+    if ledger.period_start == period_start(config, now) {
+        ledger.period_allocated
+    } else {
+        0
+    }
+}
+
+/// Charge `amount` of `token` to `category`, enforcing its per-period cap.
+/// Returns what is left of the current period's cap.
+pub fn charge(
+    env: &Env,
+    category: &Symbol,
+    token: &Address,
+    amount: i128,
+) -> Result<i128, TreasuryError> {
+    let config = get_category(env, category)?;
+    let mut ledger = get_ledger(env, category, token);
+    let now = env.ledger().timestamp();
+    let period_allocated = current_period_allocated(&config, &ledger, now) + amount;
+    if period_allocated > config.cap_per_period {
+        return Err(TreasuryError::CategoryCapExceeded);
+    }
+
+    ledger.period_start = period_start(&config, now);
+    ledger.period_allocated = period_allocated;
+    ledger.allocated += amount;
+    save_ledger(env, category, token, &ledger);
+    Ok(config.cap_per_period - period_allocated)
+}
+
+/// Record a payout to a beneficiary.
+pub fn record_claim(env: &Env, category: &Symbol, token: &Address, amount: i128) {
+    let mut ledger = get_ledger(env, category, token);
+    ledger.claimed += amount;
+    save_ledger(env, category, token, &ledger);
+}
+
+/// Record an unvested remainder returned by a cancellation.  The period
+/// cap it used is not restored.
+pub fn record_refund(env: &Env, category: &Symbol, token: &Address, amount: i128) {
+    let mut ledger = get_ledger(env, category, token);
+    ledger.allocated -= amount;
+    save_ledger(env, category, token, &ledger);
+}
+
+pub fn record_income(env: &Env, category: &Symbol, token: &Address, amount: i128) {
+    let mut ledger = get_ledger(env, category, token);
+    ledger.income += amount;
+    save_ledger(env, category, token, &ledger);
+}
+
+pub fn report(
+    env: &Env,
+    category: Symbol,
+    token: Address,
+) -> Result<CategoryReport, TreasuryError> {
+    let config = get_category(env, &category)?;
+    let ledger = get_ledger(env, &category, &token);
+    let period_allocated = current_period_allocated(&config, &ledger, env.ledger().timestamp());
+    Ok(CategoryReport {
+        category,
+        token,
+        cap_per_period: config.cap_per_period,
+        allocated: ledger.allocated,
+        claimed: ledger.claimed,
+        income: ledger.income,
+        period_start: period_start(&config, env.ledger().timestamp()),
+        period_allocated,
+        remaining: config.cap_per_period - period_allocated,
+    })
+}
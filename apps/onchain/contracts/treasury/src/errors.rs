@@ -13,4 +13,13 @@ pub enum TreasuryError {
     StreamNotFound = 7,
     NothingToClaim = 8,
     Reentrancy = 9,
+    StreamPaused = 10,
+    StreamNotPaused = 11,
+    InvalidSchedule = 12,
+    InsufficientFunds = 13,
+    CategoryNotFound = 14,
+    CategoryCapExceeded = 15,
+    InvalidCategory = 16,
+    UntrustedSource = 17,
+    InvalidBeneficiary = 18,
 }
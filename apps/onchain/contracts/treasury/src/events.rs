@@ -1,10 +1,17 @@
-use soroban_sdk::{contractevent, Address, Env};
+use soroban_sdk::{contractevent, Address, Env, Symbol};
+
+use crate::schedule;
+use crate::storage::{ScheduleType, StreamData};
 
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct StreamCreatedEvent {
     #[topic]
     pub beneficiary: Address,
+    pub stream_id: u64,
+    pub token: Address,
+    pub category: Symbol,
+    pub schedule_type: ScheduleType,
     pub amount: i128,
     pub start_time: u64,
     pub duration: u64,
@@ -15,36 +22,210 @@ pub struct StreamCreatedEvent {
 pub struct TokensClaimedEvent {
     #[topic]
     pub beneficiary: Address,
+    pub stream_id: u64,
+    pub token: Address,
+    pub category: Symbol,
+    pub schedule_type: ScheduleType,
     pub amount_claimed: i128,
     pub remaining: i128,
 }
 
-pub fn publish_stream_created(
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamCancelledEvent {
+    #[topic]
+    pub beneficiary: Address,
+    pub stream_id: u64,
+    pub token: Address,
+    pub category: Symbol,
+    pub vested_paid: i128,
+    pub refunded: i128,
+    pub refund_to: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamPausedEvent {
+    #[topic]
+    pub beneficiary: Address,
+    pub stream_id: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamResumedEvent {
+    #[topic]
+    pub beneficiary: Address,
+    pub stream_id: u64,
+    pub paused_for: u64,
+}
+
+/// `recovery` is set when the admin reassigned the stream on the previous
+/// beneficiary's behalf.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamTransferredEvent {
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub to: Address,
+    pub stream_id: u64,
+    pub claimed_amount: i128,
+    pub remaining: i128,
+    pub recovery: bool,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CategorySetEvent {
+    #[topic]
+    pub category: Symbol,
+    pub cap_per_period: i128,
+    pub period_secs: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BudgetChargedEvent {
+    #[topic]
+    pub category: Symbol,
+    #[topic]
+    pub token: Address,
+    pub stream_id: u64,
+    pub amount: i128,
+    pub period_remaining: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IncomeRecordedEvent {
+    #[topic]
+    pub category: Symbol,
+    #[topic]
+    pub token: Address,
+    pub source: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IncomeSourceSetEvent {
+    #[topic]
+    pub source: Address,
+    pub allowed: bool,
+}
+
+pub fn publish_stream_created(env: &Env, stream: &StreamData) {
+    StreamCreatedEvent {
+        beneficiary: stream.beneficiary.clone(),
+        stream_id: stream.id,
+        token: stream.token.clone(),
+        category: stream.category.clone(),
+        schedule_type: schedule::schedule_type(&stream.schedule),
+        amount: stream.total_amount,
+        start_time: stream.start_time,
+        duration: stream.duration,
+    }
+    .publish(env);
+}
+
+pub fn publish_tokens_claimed(env: &Env, stream: &StreamData, amount_claimed: i128) {
+    TokensClaimedEvent {
+        beneficiary: stream.beneficiary.clone(),
+        stream_id: stream.id,
+        token: stream.token.clone(),
+        category: stream.category.clone(),
+        schedule_type: schedule::schedule_type(&stream.schedule),
+        amount_claimed,
+        remaining: stream.total_amount - stream.claimed_amount,
+    }
+    .publish(env);
+}
+
+pub fn publish_stream_cancelled(
     env: &Env,
-    beneficiary: Address,
-    amount: i128,
-    start_time: u64,
-    duration: u64,
+    stream: &StreamData,
+    vested_paid: i128,
+    refunded: i128,
+    refund_to: Address,
 ) {
-    StreamCreatedEvent {
+    StreamCancelledEvent {
+        beneficiary: stream.beneficiary.clone(),
+        stream_id: stream.id,
+        token: stream.token.clone(),
+        category: stream.category.clone(),
+        vested_paid,
+        refunded,
+        refund_to,
+    }
+    .publish(env);
+}
+
+pub fn publish_stream_paused(env: &Env, beneficiary: Address, stream_id: u64) {
+    StreamPausedEvent {
         beneficiary,
-        amount,
-        start_time,
-        duration,
+        stream_id,
+    }
+    .publish(env);
+}
+
+pub fn publish_stream_resumed(env: &Env, beneficiary: Address, stream_id: u64, paused_for: u64) {
+    StreamResumedEvent {
+        beneficiary,
+        stream_id,
+        paused_for,
+    }
+    .publish(env);
+}
+
+pub fn publish_stream_transferred(env: &Env, from: Address, stream: &StreamData, recovery: bool) {
+    StreamTransferredEvent {
+        from,
+        to: stream.beneficiary.clone(),
+        stream_id: stream.id,
+        claimed_amount: stream.claimed_amount,
+        remaining: stream.total_amount - stream.claimed_amount,
+        recovery,
+    }
+    .publish(env);
+}
+
+pub fn publish_category_set(env: &Env, category: Symbol, cap_per_period: i128, period_secs: u64) {
+    CategorySetEvent {
+        category,
+        cap_per_period,
+        period_secs,
     }
     .publish(env);
 }
 
-pub fn publish_tokens_claimed(
+pub fn publish_budget_charged(env: &Env, stream: &StreamData, period_remaining: i128) {
+    BudgetChargedEvent {
+        category: stream.category.clone(),
+        token: stream.token.clone(),
+        stream_id: stream.id,
+        amount: stream.total_amount,
+        period_remaining,
+    }
+    .publish(env);
+}
+
+pub fn publish_income_recorded(
     env: &Env,
-    beneficiary: Address,
-    amount_claimed: i128,
-    remaining: i128,
+    category: Symbol,
+    token: Address,
+    source: Address,
+    amount: i128,
 ) {
-    TokensClaimedEvent {
-        beneficiary,
-        amount_claimed,
-        remaining,
+    IncomeRecordedEvent {
+        category,
+        token,
+        source,
+        amount,
     }
     .publish(env);
 }
+
+pub fn publish_income_source_set(env: &Env, source: Address, allowed: bool) {
+    IncomeSourceSetEvent { source, allowed }.publish(env);
+}
@@ -1,13 +1,21 @@
 #![no_std]
+// Entrypoints mirror their argument lists into generated client/args
+// helpers, which per-function allows do not reach.
+#![allow(clippy::too_many_arguments)]
 
+mod budget;
 mod errors;
 mod events;
+mod schedule;
 mod storage;
 
 use errors::TreasuryError;
 use reentrancy_guard::{acquire as acquire_reentrancy, release as release_reentrancy};
-use soroban_sdk::{contract, contractimpl, token, Address, Env};
-use storage::{DataKey, StreamData, LEDGER_BUMP, LEDGER_THRESHOLD};
+use soroban_sdk::{contract, contractimpl, token, vec, Address, Env, Symbol, Vec};
+use storage::{
+    BudgetCategory, CategoryReport, DataKey, Solvency, StreamData, UnlockSchedule, UnlockedAmount,
+    LEDGER_BUMP, LEDGER_THRESHOLD,
+};
 
 #[contract]
 pub struct TreasuryContract;
@@ -24,23 +32,206 @@ impl TreasuryContract {
         result
     }
 
-    /// Calculate how much is currently unlocked for a stream
+    /// Calculate how much is currently unlocked for a stream under its
+    /// schedule.  Time spent paused does not count towards vesting.
     fn calculate_unlocked(current_time: u64, stream: &StreamData) -> i128 {
-        if current_time < stream.start_time {
-            0
-        } else if current_time >= stream.start_time + stream.duration {
-            stream.total_amount - stream.claimed_amount
+        let now = stream
+            .paused_at
+            .map_or(current_time, |paused_at| paused_at.min(current_time));
+        let time_elapsed = now
+            .saturating_sub(stream.start_time)
+            .saturating_sub(stream.paused_duration);
+        schedule::vested(
+            &stream.schedule,
+            stream.total_amount,
+            time_elapsed,
+            stream.duration,
+        ) - stream.claimed_amount
+    }
+
+    fn require_admin(env: &Env, admin: &Address) -> Result<(), TreasuryError> {
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(TreasuryError::NotInitialized)?;
+        if *admin != stored_admin {
+            return Err(TreasuryError::Unauthorized);
+        }
+        admin.require_auth();
+        Ok(())
+    }
+
+    fn save_stream(env: &Env, stream: &StreamData) {
+        let key = DataKey::Stream(stream.id);
+        env.storage().persistent().set(&key, stream);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+    }
+
+    fn beneficiary_streams(env: &Env, beneficiary: &Address) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::BeneficiaryStreams(beneficiary.clone()))
+            .unwrap_or(vec![env])
+    }
+
+    fn set_beneficiary_streams(env: &Env, beneficiary: &Address, ids: &Vec<u64>) {
+        let key = DataKey::BeneficiaryStreams(beneficiary.clone());
+        if ids.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, ids);
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+        }
+    }
+
+    /// Hand a stream, including anything unlocked but not yet claimed, to
+    /// `new_beneficiary`.  Accrual and `claimed_amount` carry over unchanged.
+    fn reassign_stream(
+        env: &Env,
+        stream_id: u64,
+        new_beneficiary: Address,
+        recovery: bool,
+    ) -> Result<StreamData, TreasuryError> {
+        let mut stream = Self::get_stream(env.clone(), stream_id)?;
+        if stream.beneficiary == new_beneficiary
+            || new_beneficiary == env.current_contract_address()
+        {
+            return Err(TreasuryError::InvalidBeneficiary);
+        }
+
+        let previous = stream.beneficiary.clone();
+        let mut ids = Self::beneficiary_streams(env, &previous);
+        if let Some(index) = ids.first_index_of(stream_id) {
+            ids.remove(index);
+        }
+        Self::set_beneficiary_streams(env, &previous, &ids);
+
+        let mut ids = Self::beneficiary_streams(env, &new_beneficiary);
+        ids.push_back(stream_id);
+        Self::set_beneficiary_streams(env, &new_beneficiary, &ids);
+
+        stream.beneficiary = new_beneficiary;
+        Self::save_stream(env, &stream);
+        events::publish_stream_transferred(env, previous, &stream, recovery);
+        Ok(stream)
+    }
+
+    fn liabilities(env: &Env, token: &Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Liabilities(token.clone()))
+            .unwrap_or(0)
+    }
+
+    fn adjust_liabilities(env: &Env, token: &Address, delta: i128) {
+        let key = DataKey::Liabilities(token.clone());
+        let liabilities = Self::liabilities(env, token) + delta;
+        if liabilities == 0 {
+            env.storage().persistent().remove(&key);
         } else {
-            let time_elapsed = current_time - stream.start_time;
-            let total_unlocked = (stream.total_amount as u128)
-                .checked_mul(time_elapsed as u128)
-                .and_then(|x| x.checked_div(stream.duration as u128))
-                .unwrap_or(0) as i128;
-            total_unlocked - stream.claimed_amount
+            env.storage().persistent().set(&key, &liabilities);
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+        }
+    }
+
+    /// Pay out what `stream` has unlocked, dropping it once fully claimed.
+    /// Returns the amount paid and whether the stream was closed.
+    fn settle_stream(env: &Env, stream: &mut StreamData) -> (i128, bool) {
+        let unlocked = Self::calculate_unlocked(env.ledger().timestamp(), stream);
+        if unlocked <= 0 {
+            return (0, false);
+        }
+
+        token::TokenClient::new(env, &stream.token).transfer(
+            &env.current_contract_address(),
+            &stream.beneficiary,
+            &unlocked,
+        );
+        Self::adjust_liabilities(env, &stream.token, -unlocked);
+        budget::record_claim(env, &stream.category, &stream.token, unlocked);
+
+        stream.claimed_amount += unlocked;
+        let remaining = stream.total_amount - stream.claimed_amount;
+        if remaining == 0 {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::Stream(stream.id));
+        } else {
+            Self::save_stream(env, stream);
+        }
+
+        events::publish_tokens_claimed(env, stream, unlocked);
+        (unlocked, remaining == 0)
+    }
+
+    /// Validate a new stream and assign it an id.
+    fn new_stream(
+        env: &Env,
+        beneficiary: Address,
+        token: Address,
+        category: Symbol,
+        amount: i128,
+        start_time: u64,
+        duration: u64,
+        schedule: UnlockSchedule,
+    ) -> Result<StreamData, TreasuryError> {
+        if amount <= 0 {
+            return Err(TreasuryError::InvalidAmount);
         }
+        if duration == 0 {
+            return Err(TreasuryError::InvalidDuration);
+        }
+        schedule::validate(&schedule, duration)?;
+        budget::get_category(env, &category)?;
+
+        let stream_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextStreamId)
+            .unwrap_or(1);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextStreamId, &(stream_id + 1));
+
+        Ok(StreamData {
+            id: stream_id,
+            beneficiary,
+            token,
+            category,
+            total_amount: amount,
+            claimed_amount: 0,
+            start_time,
+            duration,
+            paused_at: None,
+            paused_duration: 0,
+            schedule,
+        })
+    }
+
+    /// Charge a funded stream to its category, persist and index it, and
+    /// book its liability.
+    fn open_stream(env: &Env, stream: &StreamData) -> Result<(), TreasuryError> {
+        let period_remaining =
+            budget::charge(env, &stream.category, &stream.token, stream.total_amount)?;
+        Self::save_stream(env, stream);
+        let mut ids = Self::beneficiary_streams(env, &stream.beneficiary);
+        ids.push_back(stream.id);
+        Self::set_beneficiary_streams(env, &stream.beneficiary, &ids);
+        Self::adjust_liabilities(env, &stream.token, stream.total_amount);
+        events::publish_stream_created(env, stream);
+        events::publish_budget_charged(env, stream, period_remaining);
+        Ok(())
     }
 
-    /// Initialize the treasury with admin and token
+    /// Initialize the treasury with admin and the default token, used by
+    /// the admin-funded `allocate_budget*` entrypoints
     pub fn initialize(env: Env, admin: Address, token: Address) -> Result<(), TreasuryError> {
         if env.storage().instance().has(&DataKey::Admin) {
             return Err(TreasuryError::AlreadyInitialized);
@@ -51,123 +242,461 @@ impl TreasuryContract {
         Ok(())
     }
 
-    /// Allocate a budget and start a stream
+    /// Allocate a budget from `category` and start a linear stream.  A
+    /// beneficiary may hold any number of concurrent streams; returns the
+    /// new stream's id.
     pub fn allocate_budget(
         env: Env,
         admin: Address,
+        category: Symbol,
         beneficiary: Address,
         amount: i128,
         start_time: u64,
         duration: u64,
-    ) -> Result<(), TreasuryError> {
-        Self::with_reentrancy_guard(&env, || {
-            let stored_admin: Address = env
-                .storage()
-                .instance()
-                .get(&DataKey::Admin)
-                .ok_or(TreasuryError::NotInitialized)?;
-
-            if admin != stored_admin {
-                return Err(TreasuryError::Unauthorized);
-            }
-            admin.require_auth();
-
-            if amount <= 0 {
-                return Err(TreasuryError::InvalidAmount);
-            }
-            if duration == 0 {
-                return Err(TreasuryError::InvalidDuration);
-            }
+    ) -> Result<u64, TreasuryError> {
+        Self::allocate_budget_with_schedule(
+            env,
+            admin,
+            category,
+            beneficiary,
+            amount,
+            start_time,
+            duration,
+            UnlockSchedule::Linear,
+        )
+    }
 
+    /// Allocate a budget and start a stream that unlocks per `schedule`
+    /// (cliff, periodic tranches or piecewise-linear checkpoints).
+    pub fn allocate_budget_with_schedule(
+        env: Env,
+        admin: Address,
+        category: Symbol,
+        beneficiary: Address,
+        amount: i128,
+        start_time: u64,
+        duration: u64,
+        schedule: UnlockSchedule,
+    ) -> Result<u64, TreasuryError> {
+        Self::with_reentrancy_guard(&env, || {
+            Self::require_admin(&env, &admin)?;
             let token_addr: Address = env
                 .storage()
                 .instance()
                 .get(&DataKey::Token)
                 .ok_or(TreasuryError::NotInitialized)?;
-
-            let stream = StreamData {
-                beneficiary: beneficiary.clone(),
-                total_amount: amount,
-                claimed_amount: 0,
+            let stream = Self::new_stream(
+                &env,
+                beneficiary,
+                token_addr,
+                category,
+                amount,
                 start_time,
                 duration,
-            };
-
-            env.storage()
-                .persistent()
-                .set(&DataKey::Stream(beneficiary.clone()), &stream);
-            env.storage().persistent().extend_ttl(
-                &DataKey::Stream(beneficiary.clone()),
-                LEDGER_THRESHOLD,
-                LEDGER_BUMP,
-            );
+                schedule,
+            )?;
 
             // Transfer tokens from admin to treasury
-            let token_client = token::TokenClient::new(&env, &token_addr);
+            let token_client = token::TokenClient::new(&env, &stream.token);
             token_client.transfer(&admin, env.current_contract_address(), &amount);
 
-            events::publish_stream_created(&env, beneficiary, amount, start_time, duration);
+            Self::open_stream(&env, &stream)?;
+            Ok(stream.id)
+        })
+    }
 
-            Ok(())
+    /// Start a linear stream in any `token`, funded from what the treasury
+    /// already holds of it beyond existing streams' liabilities (e.g. funds
+    /// transferred in by crowdfund_vault).
+    pub fn allocate_from_reserves(
+        env: Env,
+        admin: Address,
+        category: Symbol,
+        token: Address,
+        beneficiary: Address,
+        amount: i128,
+        start_time: u64,
+        duration: u64,
+    ) -> Result<u64, TreasuryError> {
+        Self::allocate_reserves_with_schedule(
+            env,
+            admin,
+            category,
+            token,
+            beneficiary,
+            amount,
+            start_time,
+            duration,
+            UnlockSchedule::Linear,
+        )
+    }
+
+    /// [`allocate_from_reserves`] with a custom unlock `schedule`.
+    pub fn allocate_reserves_with_schedule(
+        env: Env,
+        admin: Address,
+        category: Symbol,
+        token: Address,
+        beneficiary: Address,
+        amount: i128,
+        start_time: u64,
+        duration: u64,
+        schedule: UnlockSchedule,
+    ) -> Result<u64, TreasuryError> {
+        Self::with_reentrancy_guard(&env, || {
+            Self::require_admin(&env, &admin)?;
+            let stream = Self::new_stream(
+                &env,
+                beneficiary,
+                token,
+                category,
+                amount,
+                start_time,
+                duration,
+                schedule,
+            )?;
+            if Self::get_solvency(env.clone(), stream.token.clone()).available < amount {
+                return Err(TreasuryError::InsufficientFunds);
+            }
+
+            Self::open_stream(&env, &stream)?;
+            Ok(stream.id)
         })
     }
 
-    /// Claim unlocked funds
-    pub fn claim(env: Env, beneficiary: Address) -> Result<i128, TreasuryError> {
+    /// Claim unlocked funds from one of the beneficiary's streams, or from
+    /// all of them when `stream_id` is `None`.  Each stream pays out in its
+    /// own token; returns the sum of the amounts paid.
+    pub fn claim(
+        env: Env,
+        beneficiary: Address,
+        stream_id: Option<u64>,
+    ) -> Result<i128, TreasuryError> {
         Self::with_reentrancy_guard(&env, || {
             beneficiary.require_auth();
 
-            let key = DataKey::Stream(beneficiary.clone());
-            let mut stream: StreamData = env
-                .storage()
-                .persistent()
-                .get(&key)
-                .ok_or(TreasuryError::StreamNotFound)?;
+            let mut ids = Self::beneficiary_streams(&env, &beneficiary);
+            let targets = match stream_id {
+                Some(id) if ids.contains(id) => vec![&env, id],
+                Some(_) => return Err(TreasuryError::StreamNotFound),
+                None if ids.is_empty() => return Err(TreasuryError::StreamNotFound),
+                None => ids.clone(),
+            };
 
-            let current_time = env.ledger().timestamp();
-            let unlocked = Self::calculate_unlocked(current_time, &stream);
+            let mut total: i128 = 0;
+            for id in targets.iter() {
+                let mut stream: StreamData = env
+                    .storage()
+                    .persistent()
+                    .get(&DataKey::Stream(id))
+                    .ok_or(TreasuryError::StreamNotFound)?;
+                let (unlocked, closed) = Self::settle_stream(&env, &mut stream);
+                total += unlocked;
+                if closed {
+                    if let Some(index) = ids.first_index_of(id) {
+                        ids.remove(index);
+                    }
+                }
+            }
 
-            if unlocked <= 0 {
+            if total <= 0 {
                 return Err(TreasuryError::NothingToClaim);
             }
+            Self::set_beneficiary_streams(&env, &beneficiary, &ids);
 
-            let token_addr: Address = env
-                .storage()
-                .instance()
-                .get(&DataKey::Token)
-                .ok_or(TreasuryError::NotInitialized)?;
+            Ok(total)
+        })
+    }
 
-            stream.claimed_amount += unlocked;
-            let remaining = stream.total_amount - stream.claimed_amount;
+    /// Cancel a stream: the vested but unclaimed portion is paid to the
+    /// beneficiary and the unvested remainder returned to `refund_to`.
+    /// Returns the amount returned.
+    pub fn cancel_stream(
+        env: Env,
+        admin: Address,
+        stream_id: u64,
+        refund_to: Address,
+    ) -> Result<i128, TreasuryError> {
+        Self::with_reentrancy_guard(&env, || {
+            Self::require_admin(&env, &admin)?;
 
-            if remaining == 0 {
-                env.storage().persistent().remove(&key);
-            } else {
-                env.storage().persistent().set(&key, &stream);
-                env.storage()
-                    .persistent()
-                    .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+            let stream = Self::get_stream(env.clone(), stream_id)?;
+            let vested = Self::calculate_unlocked(env.ledger().timestamp(), &stream);
+            let refunded = stream.total_amount - stream.claimed_amount - vested;
+
+            env.storage()
+                .persistent()
+                .remove(&DataKey::Stream(stream_id));
+            let mut ids = Self::beneficiary_streams(&env, &stream.beneficiary);
+            if let Some(index) = ids.first_index_of(stream_id) {
+                ids.remove(index);
+            }
+            Self::set_beneficiary_streams(&env, &stream.beneficiary, &ids);
+
+            Self::adjust_liabilities(&env, &stream.token, -(vested + refunded));
+            budget::record_claim(&env, &stream.category, &stream.token, vested);
+            budget::record_refund(&env, &stream.category, &stream.token, refunded);
+            let token_client = token::TokenClient::new(&env, &stream.token);
+            let contract_address = env.current_contract_address();
+            if vested > 0 {
+                token_client.transfer(&contract_address, &stream.beneficiary, &vested);
+            }
+            if refunded > 0 {
+                token_client.transfer(&contract_address, &refund_to, &refunded);
             }
 
-            let token_client = token::TokenClient::new(&env, &token_addr);
-            token_client.transfer(&env.current_contract_address(), &beneficiary, &unlocked);
+            events::publish_stream_cancelled(&env, &stream, vested, refunded, refund_to);
+
+            Ok(refunded)
+        })
+    }
 
-            events::publish_tokens_claimed(&env, beneficiary, unlocked, remaining);
+    /// Move one of `beneficiary`'s streams to `new_beneficiary` (e.g. a
+    /// multisig).  The receiver must authorize taking it on.
+    pub fn transfer_stream(
+        env: Env,
+        beneficiary: Address,
+        stream_id: u64,
+        new_beneficiary: Address,
+    ) -> Result<StreamData, TreasuryError> {
+        if new_beneficiary == beneficiary {
+            return Err(TreasuryError::InvalidBeneficiary);
+        }
+        beneficiary.require_auth();
+        new_beneficiary.require_auth();
+        Self::with_reentrancy_guard(&env, || {
+            let stream = Self::get_stream(env.clone(), stream_id)?;
+            if stream.beneficiary != beneficiary {
+                return Err(TreasuryError::Unauthorized);
+            }
+            Self::reassign_stream(&env, stream_id, new_beneficiary, false)
+        })
+    }
 
-            Ok(unlocked)
+    /// Reassign a stream whose beneficiary has lost access to their keys.
+    pub fn recover_stream(
+        env: Env,
+        admin: Address,
+        stream_id: u64,
+        new_beneficiary: Address,
+    ) -> Result<StreamData, TreasuryError> {
+        Self::with_reentrancy_guard(&env, || {
+            Self::require_admin(&env, &admin)?;
+            Self::reassign_stream(&env, stream_id, new_beneficiary, true)
         })
     }
 
-    /// View currently unlocked amount
-    pub fn get_unlocked(env: Env, beneficiary: Address) -> Result<i128, TreasuryError> {
-        let key = DataKey::Stream(beneficiary);
-        let stream: StreamData = env
+    /// Create or update a budget category (e.g. `grants`, `audits`, `ops`)
+    /// capping how much may be allocated from it per period.
+    pub fn set_category(
+        env: Env,
+        admin: Address,
+        category: Symbol,
+        cap_per_period: i128,
+        period_secs: u64,
+    ) -> Result<(), TreasuryError> {
+        Self::require_admin(&env, &admin)?;
+        if cap_per_period <= 0 || period_secs == 0 {
+            return Err(TreasuryError::InvalidCategory);
+        }
+        let key = DataKey::Category(category.clone());
+        if !env.storage().persistent().has(&key) {
+            let mut categories = Self::get_categories(env.clone());
+            categories.push_back(category.clone());
+            env.storage()
+                .instance()
+                .set(&DataKey::Categories, &categories);
+        }
+        env.storage().persistent().set(
+            &key,
+            &BudgetCategory {
+                cap_per_period,
+                period_secs,
+            },
+        );
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+        events::publish_category_set(&env, category, cap_per_period, period_secs);
+        Ok(())
+    }
+
+    /// Allow or disallow `source` (e.g. crowdfund_vault) to record income.
+    pub fn set_income_source(
+        env: Env,
+        admin: Address,
+        source: Address,
+        allowed: bool,
+    ) -> Result<(), TreasuryError> {
+        Self::require_admin(&env, &admin)?;
+        let key = DataKey::IncomeSource(source.clone());
+        if allowed {
+            env.storage().persistent().set(&key, &true);
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        events::publish_income_source_set(&env, source, allowed);
+        Ok(())
+    }
+
+    /// Attribute `amount` of `token`, already transferred to the treasury by
+    /// `source`, to `category` as income.
+    pub fn record_income(
+        env: Env,
+        source: Address,
+        category: Symbol,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), TreasuryError> {
+        source.require_auth();
+        if !env
             .storage()
             .persistent()
-            .get(&key)
-            .ok_or(TreasuryError::StreamNotFound)?;
+            .has(&DataKey::IncomeSource(source.clone()))
+        {
+            return Err(TreasuryError::UntrustedSource);
+        }
+        if amount <= 0 {
+            return Err(TreasuryError::InvalidAmount);
+        }
+        budget::get_category(&env, &category)?;
+        budget::record_income(&env, &category, &token, amount);
+        events::publish_income_recorded(&env, category, token, source, amount);
+        Ok(())
+    }
+
+    /// Freeze a stream's accrual.  Already vested funds stay claimable.
+    pub fn pause_stream(env: Env, admin: Address, stream_id: u64) -> Result<(), TreasuryError> {
+        Self::require_admin(&env, &admin)?;
+        let mut stream = Self::get_stream(env.clone(), stream_id)?;
+        if stream.paused_at.is_some() {
+            return Err(TreasuryError::StreamPaused);
+        }
+        stream.paused_at = Some(env.ledger().timestamp());
+        Self::save_stream(&env, &stream);
+        events::publish_stream_paused(&env, stream.beneficiary, stream_id);
+        Ok(())
+    }
+
+    /// Resume a paused stream; its end moves back by the time spent paused.
+    pub fn resume_stream(env: Env, admin: Address, stream_id: u64) -> Result<(), TreasuryError> {
+        Self::require_admin(&env, &admin)?;
+        let mut stream = Self::get_stream(env.clone(), stream_id)?;
+        let paused_at = stream.paused_at.ok_or(TreasuryError::StreamNotPaused)?;
+        let now = env.ledger().timestamp();
+        let paused_for = now.max(stream.start_time) - paused_at.max(stream.start_time);
+        stream.paused_duration += paused_for;
+        stream.paused_at = None;
+        Self::save_stream(&env, &stream);
+        events::publish_stream_resumed(&env, stream.beneficiary, stream_id, paused_for);
+        Ok(())
+    }
+
+    /// View currently unlocked amount across the beneficiary's streams in
+    /// `token` (see `get_unlocked_by_stream` for every token)
+    pub fn get_unlocked(
+        env: Env,
+        beneficiary: Address,
+        token: Address,
+    ) -> Result<i128, TreasuryError> {
+        let mut streams = Self::get_streams(env.clone(), beneficiary)
+            .into_iter()
+            .filter(|stream| stream.token == token)
+            .peekable();
+        if streams.peek().is_none() {
+            return Err(TreasuryError::StreamNotFound);
+        }
+        let now = env.ledger().timestamp();
+        Ok(streams
+            .map(|stream| Self::calculate_unlocked(now, &stream))
+            .sum())
+    }
 
-        Ok(Self::calculate_unlocked(env.ledger().timestamp(), &stream))
+    /// View currently unlocked amount of a single stream, with its schedule type
+    pub fn get_stream_unlocked(env: Env, stream_id: u64) -> Result<UnlockedAmount, TreasuryError> {
+        let stream = Self::get_stream(env.clone(), stream_id)?;
+        Ok(UnlockedAmount {
+            stream_id,
+            token: stream.token.clone(),
+            schedule_type: schedule::schedule_type(&stream.schedule),
+            unlocked: Self::calculate_unlocked(env.ledger().timestamp(), &stream),
+        })
+    }
+
+    /// Per-stream breakdown of `get_unlocked`
+    pub fn get_unlocked_by_stream(env: Env, beneficiary: Address) -> Vec<UnlockedAmount> {
+        let now = env.ledger().timestamp();
+        let mut amounts = Vec::new(&env);
+        for stream in Self::get_streams(env.clone(), beneficiary).iter() {
+            amounts.push_back(UnlockedAmount {
+                stream_id: stream.id,
+                token: stream.token.clone(),
+                schedule_type: schedule::schedule_type(&stream.schedule),
+                unlocked: Self::calculate_unlocked(now, &stream),
+            });
+        }
+        amounts
+    }
+
+    pub fn get_stream(env: Env, stream_id: u64) -> Result<StreamData, TreasuryError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Stream(stream_id))
+            .ok_or(TreasuryError::StreamNotFound)
+    }
+
+    /// Open (not fully claimed) streams of a beneficiary, oldest first
+    pub fn get_streams(env: Env, beneficiary: Address) -> Vec<StreamData> {
+        let mut streams = Vec::new(&env);
+        for id in Self::beneficiary_streams(&env, &beneficiary).iter() {
+            if let Some(stream) = env.storage().persistent().get(&DataKey::Stream(id)) {
+                streams.push_back(stream);
+            }
+        }
+        streams
+    }
+
+    /// Amount of `token` still owed to open streams.
+    pub fn get_liabilities(env: Env, token: Address) -> i128 {
+        Self::liabilities(&env, &token)
+    }
+
+    /// Held `token` balance against open streams' liabilities.
+    pub fn get_solvency(env: Env, token: Address) -> Solvency {
+        let balance =
+            token::TokenClient::new(&env, &token).balance(&env.current_contract_address());
+        let liabilities = Self::liabilities(&env, &token);
+        Solvency {
+            token,
+            balance,
+            liabilities,
+            available: balance - liabilities,
+        }
+    }
+
+    /// Configured budget categories, in creation order.
+    pub fn get_categories(env: Env) -> Vec<Symbol> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Categories)
+            .unwrap_or(vec![&env])
+    }
+
+    pub fn get_category(env: Env, category: Symbol) -> Result<BudgetCategory, TreasuryError> {
+        budget::get_category(&env, &category)
+    }
+
+    /// Allocated, claimed and income totals of `category` in `token`, with
+    /// what is left of the current period's cap.
+    pub fn get_category_report(
+        env: Env,
+        category: Symbol,
+        token: Address,
+    ) -> Result<CategoryReport, TreasuryError> {
+        budget::report(&env, category, token)
     }
 
     pub fn get_admin(env: Env) -> Result<Address, TreasuryError> {
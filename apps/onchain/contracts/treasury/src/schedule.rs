@@ -0,0 +1,88 @@
+use crate::errors::TreasuryError;
+use crate::storage::{ScheduleType, UnlockSchedule};
+
+/// Upper bound on piecewise checkpoints, to keep vesting math cheap.
+pub const MAX_CHECKPOINTS: u32 = 32;
+
+const BPS_DENOMINATOR: u128 = 10_000;
+
+pub fn schedule_type(schedule: &UnlockSchedule) -> ScheduleType {
+    match schedule {
+        UnlockSchedule::Linear => ScheduleType::Linear,
+        UnlockSchedule::Cliff(_) => ScheduleType::Cliff,
+        UnlockSchedule::Steps(_) => ScheduleType::Steps,
+        UnlockSchedule::Piecewise(_) => ScheduleType::Piecewise,
+    }
+}
+
+pub fn validate(schedule: &UnlockSchedule, duration: u64) -> Result<(), TreasuryError> {
+    let valid = match schedule {
+        UnlockSchedule::Linear => true,
+        UnlockSchedule::Cliff(cliff) => *cliff <= duration,
+        UnlockSchedule::Steps(period) => *period > 0 && *period <= duration,
+        UnlockSchedule::Piecewise(checkpoints) => {
+            let mut prev_offset = 0u64;
+            let mut prev_bps = 0u32;
+            for checkpoint in checkpoints.iter() {
+                if checkpoint.offset <= prev_offset
+                    || checkpoint.unlocked_bps < prev_bps
+                    || checkpoint.unlocked_bps as u128 > BPS_DENOMINATOR
+                {
+                    return Err(TreasuryError::InvalidSchedule);
+                }
+                prev_offset = checkpoint.offset;
+                prev_bps = checkpoint.unlocked_bps;
+            }
+            checkpoints.len() <= MAX_CHECKPOINTS
+                && prev_offset == duration
+                && prev_bps as u128 == BPS_DENOMINATOR
+        }
+    };
+    if !valid {
+        return Err(TreasuryError::InvalidSchedule);
+    }
+    Ok(())
+}
+
+/// Amount of `total` unlocked `elapsed` seconds into a stream of `duration`.
+pub fn vested(schedule: &UnlockSchedule, total: i128, elapsed: u64, duration: u64) -> i128 {
+    if elapsed >= duration {
+        return total;
+    }
+    let linear = |elapsed: u64| {
+        (total as u128)
+            .checked_mul(elapsed as u128)
+            .and_then(|x| x.checked_div(duration as u128))
+            .unwrap_or(0) as i128
+    };
+    match schedule {
+        UnlockSchedule::Linear => linear(elapsed),
+        UnlockSchedule::Cliff(cliff) => {
+            if elapsed < *cliff {
+                0
+            } else {
+                linear(elapsed)
+            }
+        }
+        UnlockSchedule::Steps(period) => linear(elapsed - elapsed % period),
+        UnlockSchedule::Piecewise(checkpoints) => {
+            let mut prev_offset = 0u64;
+            let mut prev_bps = 0u128;
+            for checkpoint in checkpoints.iter() {
+                let bps = checkpoint.unlocked_bps as u128;
+                if elapsed < checkpoint.offset {
+                    let span = (checkpoint.offset - prev_offset) as u128;
+                    let scaled =
+                        prev_bps * span + (bps - prev_bps) * (elapsed - prev_offset) as u128;
+                    return (total as u128)
+                        .checked_mul(scaled)
+                        .and_then(|x| x.checked_div(BPS_DENOMINATOR * span))
+                        .unwrap_or(0) as i128;
+                }
+                prev_offset = checkpoint.offset;
+                prev_bps = bps;
+            }
+            total
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address};
+use soroban_sdk::{contracttype, Address, Symbol, Vec};
 
 pub const LEDGER_THRESHOLD: u32 = 120_960; // ~1 week
 pub const LEDGER_BUMP: u32 = 241_920; // ~2 weeks
@@ -8,15 +8,137 @@ pub const LEDGER_BUMP: u32 = 241_920; // ~2 weeks
 pub enum DataKey {
     Admin,
     Token,
-    Stream(Address), // beneficiary -> StreamData
+    NextStreamId,                    // -> u64
+    Stream(u64),                     // stream_id -> StreamData
+    BeneficiaryStreams(Address),     // beneficiary -> Vec<u64> of open stream ids
+    Liabilities(Address),            // token -> i128 still owed to open streams
+    Categories,                      // -> Vec<Symbol> of configured categories
+    Category(Symbol),                // category -> BudgetCategory
+    CategoryLedger(Symbol, Address), // (category, token) -> CategoryLedger
+    IncomeSource(Address),           // source -> bool, may call record_income
 }
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct StreamData {
+    pub id: u64,
     pub beneficiary: Address,
+    /// Token the stream pays out in.
+    pub token: Address,
+    /// Budget category the stream was charged to.
+    pub category: Symbol,
     pub total_amount: i128,
     pub claimed_amount: i128,
     pub start_time: u64,
     pub duration: u64,
+    /// When the stream was paused; accrual is frozen until resumed.
+    pub paused_at: Option<u64>,
+    /// Seconds spent paused after `start_time`, which push the end back.
+    pub paused_duration: u64,
+    /// How `total_amount` unlocks over `duration`.
+    pub schedule: UnlockSchedule,
+}
+
+/// Point of a piecewise-linear schedule: `unlocked_bps` of the total is
+/// unlocked `offset` seconds after the start.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnlockCheckpoint {
+    pub offset: u64,
+    pub unlocked_bps: u32,
+}
+
+/// Shape of a stream's unlock curve.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum UnlockSchedule {
+    /// Evenly over the whole duration.
+    Linear,
+    /// Nothing for the given number of seconds, then whatever the linear
+    /// curve would have unlocked by then, continuing linearly.
+    Cliff(u64),
+    /// Equal tranches released at the end of each period of the given
+    /// number of seconds.
+    Steps(u64),
+    /// Linear between checkpoints, from 0 at the start; the last checkpoint
+    /// must be 100% at `duration`.
+    Piecewise(Vec<UnlockCheckpoint>),
+}
+
+/// `UnlockSchedule` without its parameters, for events and views.
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum ScheduleType {
+    Linear = 0,
+    Cliff = 1,
+    Steps = 2,
+    Piecewise = 3,
+}
+
+/// What a stream has unlocked but not yet paid out.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnlockedAmount {
+    pub stream_id: u64,
+    pub token: Address,
+    pub schedule_type: ScheduleType,
+    pub unlocked: i128,
+}
+
+/// Held funds of one token against what open streams still owe.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Solvency {
+    pub token: Address,
+    /// Tokens held by the treasury.
+    pub balance: i128,
+    /// Unclaimed amounts of open streams.
+    pub liabilities: i128,
+    /// `balance - liabilities`; what new streams can still be funded from.
+    /// Negative only if tokens left the treasury outside of streams.
+    pub available: i128,
+}
+
+/// Spending limit of a budget category.  Amounts are in the units of
+/// whichever token is spent, and each token is capped separately.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BudgetCategory {
+    /// Most that may be allocated per period.
+    pub cap_per_period: i128,
+    /// Length of a period in seconds; periods are aligned to multiples of it.
+    pub period_secs: u64,
+}
+
+/// Running totals of a category in one token.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CategoryLedger {
+    /// Committed to streams, less what cancellations returned.
+    pub allocated: i128,
+    /// Paid out to beneficiaries.
+    pub claimed: i128,
+    /// Received from income sources such as crowdfund_vault fees.
+    pub income: i128,
+    /// Start of the period `period_allocated` belongs to.
+    pub period_start: u64,
+    /// Allocated during that period.
+    pub period_allocated: i128,
+}
+
+/// Spending report for one category and token.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CategoryReport {
+    pub category: Symbol,
+    pub token: Address,
+    pub cap_per_period: i128,
+    pub allocated: i128,
+    pub claimed: i128,
+    pub income: i128,
+    pub period_start: u64,
+    pub period_allocated: i128,
+    /// What may still be allocated in the current period.
+    pub remaining: i128,
 }
@@ -1,6 +1,33 @@
 use super::*;
 use soroban_sdk::testutils::{Address as _, Ledger};
-use soroban_sdk::{token, Address, Env};
+use soroban_sdk::{symbol_short, token, vec, Address, Env};
+use storage::{ScheduleType, UnlockCheckpoint};
+
+struct Setup<'a> {
+    admin: Address,
+    treasury: TreasuryContractClient<'a>,
+    token: token::TokenClient<'a>,
+    token_admin: token::StellarAssetClient<'a>,
+}
+
+/// Treasury over a fresh token, with a `grants` category roomy enough for
+/// any test allocation.
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    let admin = Address::generate(env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let treasury = TreasuryContractClient::new(env, &env.register(TreasuryContract, ()));
+    treasury.initialize(&admin, &token_id);
+    treasury.set_category(&admin, &symbol_short!("grants"), &1_000_000, &86_400);
+    Setup {
+        admin,
+        treasury,
+        token: token::TokenClient::new(env, &token_id),
+        token_admin: token::StellarAssetClient::new(env, &token_id),
+    }
+}
 
 #[test]
 fn test_treasury_streaming() {
@@ -22,6 +49,7 @@ fn test_treasury_streaming() {
 
     // Initialize
     treasury_client.initialize(&admin, &token_id.address());
+    treasury_client.set_category(&admin, &symbol_short!("grants"), &1_000_000, &86_400);
 
     // Mint tokens to admin
     let amount = 1000i128;
@@ -32,28 +60,518 @@ fn test_treasury_streaming() {
     let duration = 1000u64;
     env.ledger().set_timestamp(start_time);
 
-    treasury_client.allocate_budget(&admin, &beneficiary, &amount, &start_time, &duration);
+    treasury_client.allocate_budget(
+        &admin,
+        &symbol_short!("grants"),
+        &beneficiary,
+        &amount,
+        &start_time,
+        &duration,
+    );
 
     // Check unlocked at start_time (should be 0)
-    assert_eq!(treasury_client.get_unlocked(&beneficiary), 0);
+    assert_eq!(
+        treasury_client.get_unlocked(&beneficiary, &token_id.address()),
+        0
+    );
 
     // Move time forward by 500 seconds (half duration)
     env.ledger().set_timestamp(start_time + 500);
-    assert_eq!(treasury_client.get_unlocked(&beneficiary), 500);
+    assert_eq!(
+        treasury_client.get_unlocked(&beneficiary, &token_id.address()),
+        500
+    );
 
     // Claim half
-    let claimed = treasury_client.claim(&beneficiary);
+    let claimed = treasury_client.claim(&beneficiary, &None);
     assert_eq!(claimed, 500);
     assert_eq!(token_client.balance(&beneficiary), 500);
 
     // Check unlocked again (should be 0 now since we just claimed)
-    assert_eq!(treasury_client.get_unlocked(&beneficiary), 0);
+    assert_eq!(
+        treasury_client.get_unlocked(&beneficiary, &token_id.address()),
+        0
+    );
 
     // Move time forward to end
     env.ledger().set_timestamp(start_time + 1000);
-    assert_eq!(treasury_client.get_unlocked(&beneficiary), 500);
+    assert_eq!(
+        treasury_client.get_unlocked(&beneficiary, &token_id.address()),
+        500
+    );
 
     // Claim rest
-    treasury_client.claim(&beneficiary);
+    treasury_client.claim(&beneficiary, &None);
     assert_eq!(token_client.balance(&beneficiary), 1000);
 }
+
+#[test]
+fn test_multiple_streams_per_beneficiary() {
+    let env = Env::default();
+    let Setup {
+        admin,
+        treasury: treasury_client,
+        token: token_client,
+        token_admin,
+    } = setup(&env);
+    let beneficiary = Address::generate(&env);
+    token_admin.mint(&admin, &3000);
+
+    env.ledger().set_timestamp(1000);
+    let first = treasury_client.allocate_budget(
+        &admin,
+        &symbol_short!("grants"),
+        &beneficiary,
+        &1000,
+        &1000,
+        &1000,
+    );
+    let second = treasury_client.allocate_budget(
+        &admin,
+        &symbol_short!("grants"),
+        &beneficiary,
+        &2000,
+        &1000,
+        &2000,
+    );
+    assert_ne!(first, second);
+    assert_eq!(treasury_client.get_streams(&beneficiary).len(), 2);
+
+    // Half of the first stream, a quarter of the second.
+    env.ledger().set_timestamp(1500);
+    assert_eq!(
+        treasury_client.get_unlocked(&beneficiary, &token_client.address),
+        1000
+    );
+    assert_eq!(treasury_client.get_stream_unlocked(&second).unlocked, 500);
+
+    assert_eq!(treasury_client.claim(&beneficiary, &Some(second)), 500);
+    assert_eq!(treasury_client.get_stream_unlocked(&first).unlocked, 500);
+    assert_eq!(
+        treasury_client.try_claim(&Address::generate(&env), &Some(first)),
+        Err(Ok(TreasuryError::StreamNotFound))
+    );
+
+    // The first stream completes and drops out of the index.
+    env.ledger().set_timestamp(2000);
+    assert_eq!(treasury_client.claim(&beneficiary, &None), 1000 + 500);
+    let streams = treasury_client.get_streams(&beneficiary);
+    assert_eq!(streams.len(), 1);
+    assert_eq!(streams.get(0).unwrap().id, second);
+    assert_eq!(streams.get(0).unwrap().claimed_amount, 1000);
+    assert_eq!(token_client.balance(&beneficiary), 2000);
+}
+
+#[test]
+fn test_cancel_stream_splits_vested_and_unvested() {
+    let env = Env::default();
+    let Setup {
+        admin,
+        treasury: treasury_client,
+        token: token_client,
+        token_admin,
+    } = setup(&env);
+    let beneficiary = Address::generate(&env);
+    let refund_to = Address::generate(&env);
+    token_admin.mint(&admin, &1000);
+
+    env.ledger().set_timestamp(1000);
+    let stream_id = treasury_client.allocate_budget(
+        &admin,
+        &symbol_short!("grants"),
+        &beneficiary,
+        &1000,
+        &1000,
+        &1000,
+    );
+    env.ledger().set_timestamp(1200);
+    treasury_client.claim(&beneficiary, &None);
+
+    env.ledger().set_timestamp(1400);
+    assert_eq!(
+        treasury_client.cancel_stream(&admin, &stream_id, &refund_to),
+        600
+    );
+    assert_eq!(token_client.balance(&beneficiary), 400);
+    assert_eq!(token_client.balance(&refund_to), 600);
+    assert_eq!(token_client.balance(&treasury_client.address), 0);
+    assert!(treasury_client.get_streams(&beneficiary).is_empty());
+    assert_eq!(
+        treasury_client.try_cancel_stream(&admin, &stream_id, &refund_to),
+        Err(Ok(TreasuryError::StreamNotFound))
+    );
+}
+
+#[test]
+fn test_paused_stream_stops_accruing() {
+    let env = Env::default();
+    let Setup {
+        admin,
+        treasury: treasury_client,
+        token_admin,
+        ..
+    } = setup(&env);
+    let beneficiary = Address::generate(&env);
+    token_admin.mint(&admin, &1000);
+
+    env.ledger().set_timestamp(1000);
+    let stream_id = treasury_client.allocate_budget(
+        &admin,
+        &symbol_short!("grants"),
+        &beneficiary,
+        &1000,
+        &1000,
+        &1000,
+    );
+
+    env.ledger().set_timestamp(1300);
+    treasury_client.pause_stream(&admin, &stream_id);
+    assert_eq!(
+        treasury_client.try_pause_stream(&admin, &stream_id),
+        Err(Ok(TreasuryError::StreamPaused))
+    );
+
+    // Nothing accrues while paused, but vested funds stay claimable.
+    env.ledger().set_timestamp(1800);
+    assert_eq!(
+        treasury_client.get_stream_unlocked(&stream_id).unlocked,
+        300
+    );
+    assert_eq!(treasury_client.claim(&beneficiary, &None), 300);
+
+    treasury_client.resume_stream(&admin, &stream_id);
+    assert_eq!(
+        treasury_client.try_resume_stream(&admin, &stream_id),
+        Err(Ok(TreasuryError::StreamNotPaused))
+    );
+    env.ledger().set_timestamp(2000);
+    assert_eq!(
+        treasury_client.get_stream_unlocked(&stream_id).unlocked,
+        200
+    );
+
+    // The end moved back by the 500 seconds spent paused.
+    env.ledger().set_timestamp(2499);
+    assert!(treasury_client.get_stream_unlocked(&stream_id).unlocked < 700);
+    env.ledger().set_timestamp(2500);
+    assert_eq!(
+        treasury_client.get_stream_unlocked(&stream_id).unlocked,
+        700
+    );
+}
+
+#[test]
+fn test_cliff_steps_and_piecewise_schedules() {
+    let env = Env::default();
+    let Setup {
+        admin,
+        treasury: treasury_client,
+        token,
+        token_admin,
+    } = setup(&env);
+    let beneficiary = Address::generate(&env);
+    token_admin.mint(&admin, &3000);
+
+    env.ledger().set_timestamp(0);
+    let allocate = |schedule: UnlockSchedule| {
+        treasury_client.allocate_budget_with_schedule(
+            &admin,
+            &symbol_short!("grants"),
+            &beneficiary,
+            &1000,
+            &0,
+            &1000,
+            &schedule,
+        )
+    };
+    let cliff = allocate(UnlockSchedule::Cliff(250));
+    let steps = allocate(UnlockSchedule::Steps(300));
+    let piecewise = allocate(UnlockSchedule::Piecewise(vec![
+        &env,
+        UnlockCheckpoint {
+            offset: 100,
+            unlocked_bps: 5_000,
+        },
+        UnlockCheckpoint {
+            offset: 1000,
+            unlocked_bps: 10_000,
+        },
+    ]));
+    let unlocked = |id: u64| treasury_client.get_stream_unlocked(&id).unlocked;
+
+    env.ledger().set_timestamp(50);
+    assert_eq!(unlocked(cliff), 0);
+    assert_eq!(unlocked(steps), 0);
+    assert_eq!(unlocked(piecewise), 250);
+
+    env.ledger().set_timestamp(250);
+    assert_eq!(unlocked(cliff), 250);
+
+    env.ledger().set_timestamp(640);
+    assert_eq!(unlocked(cliff), 640);
+    assert_eq!(unlocked(steps), 600);
+    assert_eq!(unlocked(piecewise), 800);
+
+    env.ledger().set_timestamp(999);
+    assert_eq!(unlocked(steps), 900);
+    env.ledger().set_timestamp(1000);
+    assert_eq!(unlocked(steps), 1000);
+
+    let breakdown = treasury_client.get_unlocked_by_stream(&beneficiary);
+    assert_eq!(
+        breakdown.get(2).unwrap().schedule_type,
+        ScheduleType::Piecewise
+    );
+    assert_eq!(
+        treasury_client.get_unlocked(&beneficiary, &token.address),
+        3000
+    );
+
+    // The last checkpoint must reach 100% at the stream's end.
+    assert_eq!(
+        treasury_client.try_allocate_budget_with_schedule(
+            &admin,
+            &symbol_short!("grants"),
+            &beneficiary,
+            &1000,
+            &0,
+            &1000,
+            &UnlockSchedule::Piecewise(vec![
+                &env,
+                UnlockCheckpoint {
+                    offset: 1000,
+                    unlocked_bps: 9_000,
+                },
+            ]),
+        ),
+        Err(Ok(TreasuryError::InvalidSchedule))
+    );
+    assert_eq!(
+        treasury_client.try_allocate_budget_with_schedule(
+            &admin,
+            &symbol_short!("grants"),
+            &beneficiary,
+            &1000,
+            &0,
+            &1000,
+            &UnlockSchedule::Steps(0),
+        ),
+        Err(Ok(TreasuryError::InvalidSchedule))
+    );
+}
+
+#[test]
+fn test_reserve_funded_streams_in_any_token() {
+    let env = Env::default();
+    let Setup {
+        admin,
+        treasury: treasury_client,
+        token: default_token,
+        ..
+    } = setup(&env);
+    let beneficiary = Address::generate(&env);
+    let other_token = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    let other = token::TokenClient::new(&env, &other_token.address());
+
+    // Funds sent straight to the treasury back reserve-funded streams.
+    token::StellarAssetClient::new(&env, &other_token.address())
+        .mint(&treasury_client.address, &1000);
+    env.ledger().set_timestamp(0);
+    let stream_id = treasury_client.allocate_from_reserves(
+        &admin,
+        &symbol_short!("grants"),
+        &other_token.address(),
+        &beneficiary,
+        &600,
+        &0,
+        &100,
+    );
+    assert_eq!(
+        treasury_client.get_stream(&stream_id).token,
+        other_token.address()
+    );
+
+    let solvency = treasury_client.get_solvency(&other_token.address());
+    assert_eq!(solvency.balance, 1000);
+    assert_eq!(solvency.liabilities, 600);
+    assert_eq!(solvency.available, 400);
+    assert_eq!(
+        treasury_client.try_allocate_from_reserves(
+            &admin,
+            &symbol_short!("grants"),
+            &other_token.address(),
+            &beneficiary,
+            &401,
+            &0,
+            &100,
+        ),
+        Err(Ok(TreasuryError::InsufficientFunds))
+    );
+    assert_eq!(treasury_client.get_liabilities(&default_token.address), 0);
+
+    env.ledger().set_timestamp(50);
+    assert_eq!(
+        treasury_client.get_unlocked(&beneficiary, &other_token.address()),
+        300
+    );
+    assert_eq!(
+        treasury_client.try_get_unlocked(&beneficiary, &default_token.address),
+        Err(Ok(TreasuryError::StreamNotFound))
+    );
+    assert_eq!(treasury_client.claim(&beneficiary, &None), 300);
+    assert_eq!(other.balance(&beneficiary), 300);
+    let solvency = treasury_client.get_solvency(&other_token.address());
+    assert_eq!(solvency.liabilities, 300);
+    assert_eq!(solvency.available, 400);
+
+    let refund_to = Address::generate(&env);
+    treasury_client.cancel_stream(&admin, &stream_id, &refund_to);
+    assert_eq!(other.balance(&refund_to), 300);
+    assert_eq!(treasury_client.get_liabilities(&other_token.address()), 0);
+}
+
+#[test]
+fn test_budget_categories_cap_and_report() {
+    let env = Env::default();
+    let Setup {
+        admin,
+        treasury: treasury_client,
+        token,
+        token_admin,
+    } = setup(&env);
+    let beneficiary = Address::generate(&env);
+    let vault = Address::generate(&env);
+    let token_addr = token.address;
+    token_admin.mint(&admin, &5000);
+
+    let audits = symbol_short!("audits");
+    let ops = symbol_short!("ops");
+    treasury_client.set_category(&admin, &audits, &1000, &1000);
+    treasury_client.set_category(&admin, &ops, &500, &1000);
+    assert_eq!(
+        treasury_client.get_categories(),
+        vec![&env, symbol_short!("grants"), audits.clone(), ops.clone()]
+    );
+    assert_eq!(
+        treasury_client.try_set_category(&admin, &ops, &0, &1000),
+        Err(Ok(TreasuryError::InvalidCategory))
+    );
+
+    env.ledger().set_timestamp(0);
+    assert_eq!(
+        treasury_client.try_allocate_budget(
+            &admin,
+            &symbol_short!("legal"),
+            &beneficiary,
+            &100,
+            &0,
+            &100
+        ),
+        Err(Ok(TreasuryError::CategoryNotFound))
+    );
+    let stream_id = treasury_client.allocate_budget(&admin, &audits, &beneficiary, &600, &0, &100);
+    assert_eq!(treasury_client.get_stream(&stream_id).category, audits);
+    assert_eq!(
+        treasury_client.try_allocate_budget(&admin, &audits, &beneficiary, &401, &0, &100),
+        Err(Ok(TreasuryError::CategoryCapExceeded))
+    );
+    // Other categories have their own caps.
+    treasury_client.allocate_budget(&admin, &ops, &beneficiary, &500, &0, &100);
+
+    env.ledger().set_timestamp(50);
+    treasury_client.claim(&beneficiary, &Some(stream_id));
+    treasury_client.cancel_stream(&admin, &stream_id, &admin);
+
+    let report = treasury_client.get_category_report(&audits, &token_addr);
+    assert_eq!(report.allocated, 300);
+    assert_eq!(report.claimed, 300);
+    assert_eq!(report.period_allocated, 600);
+    assert_eq!(report.remaining, 400);
+
+    // The cap resets at the start of the next period.
+    env.ledger().set_timestamp(1000);
+    treasury_client.allocate_budget(&admin, &audits, &beneficiary, &1000, &1000, &100);
+    let report = treasury_client.get_category_report(&audits, &token_addr);
+    assert_eq!(report.period_start, 1000);
+    assert_eq!(report.allocated, 1300);
+    assert_eq!(report.remaining, 0);
+
+    // Only allowlisted sources may book income.
+    assert_eq!(
+        treasury_client.try_record_income(&vault, &ops, &token_addr, &25),
+        Err(Ok(TreasuryError::UntrustedSource))
+    );
+    treasury_client.set_income_source(&admin, &vault, &true);
+    treasury_client.record_income(&vault, &ops, &token_addr, &25);
+    assert_eq!(
+        treasury_client
+            .get_category_report(&ops, &token_addr)
+            .income,
+        25
+    );
+}
+
+#[test]
+fn test_transfer_and_recover_stream() {
+    let env = Env::default();
+    let Setup {
+        admin,
+        treasury: treasury_client,
+        token: token_client,
+        token_admin,
+    } = setup(&env);
+    let beneficiary = Address::generate(&env);
+    let multisig = Address::generate(&env);
+    let recovered = Address::generate(&env);
+    token_admin.mint(&admin, &1000);
+
+    env.ledger().set_timestamp(0);
+    let stream_id = treasury_client.allocate_budget(
+        &admin,
+        &symbol_short!("grants"),
+        &beneficiary,
+        &1000,
+        &0,
+        &1000,
+    );
+    env.ledger().set_timestamp(200);
+    treasury_client.claim(&beneficiary, &Some(stream_id));
+
+    // Only the current beneficiary may hand the stream on.
+    assert_eq!(
+        treasury_client.try_transfer_stream(&multisig, &stream_id, &recovered),
+        Err(Ok(TreasuryError::Unauthorized))
+    );
+    assert_eq!(
+        treasury_client.try_transfer_stream(&beneficiary, &stream_id, &beneficiary),
+        Err(Ok(TreasuryError::InvalidBeneficiary))
+    );
+
+    env.ledger().set_timestamp(500);
+    let stream = treasury_client.transfer_stream(&beneficiary, &stream_id, &multisig);
+    assert_eq!(stream.beneficiary, multisig);
+    assert_eq!(stream.claimed_amount, 200);
+    assert_eq!(treasury_client.get_streams(&beneficiary).len(), 0);
+    assert_eq!(treasury_client.get_streams(&multisig).len(), 1);
+
+    // Unclaimed accrual moves with the stream.
+    assert_eq!(
+        treasury_client.try_claim(&beneficiary, &None),
+        Err(Ok(TreasuryError::StreamNotFound))
+    );
+    assert_eq!(treasury_client.claim(&multisig, &None), 300);
+    assert_eq!(token_client.balance(&beneficiary), 200);
+
+    let stream = treasury_client.recover_stream(&admin, &stream_id, &recovered);
+    assert_eq!(stream.beneficiary, recovered);
+    assert_eq!(stream.claimed_amount, 500);
+    assert_eq!(
+        treasury_client.try_recover_stream(&multisig, &stream_id, &multisig),
+        Err(Ok(TreasuryError::Unauthorized))
+    );
+
+    env.ledger().set_timestamp(1000);
+    assert_eq!(treasury_client.claim(&recovered, &None), 500);
+    assert_eq!(token_client.balance(&multisig), 300);
+    assert_eq!(treasury_client.get_liabilities(&token_client.address), 0);
+}
@@ -0,0 +1,38 @@
+use soroban_sdk::{contractevent, Address};
+
+use crate::storage::VerificationPolicy;
+
+#[contractevent]
+pub struct InitializedEvent {
+    pub admin: Address,
+}
+
+#[contractevent]
+pub struct SourcesSetEvent {
+    #[topic]
+    pub admin: Address,
+    pub project_registry: Option<Address>,
+    pub curation: Option<Address>,
+}
+
+#[contractevent]
+pub struct DefaultPolicySetEvent {
+    #[topic]
+    pub admin: Address,
+    pub policy: VerificationPolicy,
+}
+
+#[contractevent]
+pub struct ProjectLinkedEvent {
+    #[topic]
+    pub project_id: u64,
+    pub registry_id: Option<u64>,
+    pub curation_id: Option<u64>,
+    pub policy: Option<VerificationPolicy>,
+}
+
+#[contractevent]
+pub struct ProjectUnlinkedEvent {
+    #[topic]
+    pub project_id: u64,
+}
@@ -0,0 +1,349 @@
+#![no_std]
+
+//! Single place for downstream contracts (crowdfund_vault, matching_pool) to
+//! ask whether a project is verified.  Projects are keyed by their
+//! crowdfund_vault id and linked to their ids in project_registry and
+//! lumenpulse-curation; a policy decides which of the two must vouch.
+
+mod errors;
+mod events;
+mod storage;
+
+use errors::AggregatorError;
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
+use storage::{DataKey, ProjectLink, VerificationPolicy, VerificationSummary};
+use verification_interface::{VerificationSourceClient, VerificationSourceTrait};
+
+#[contract]
+pub struct VerificationAggregatorContract;
+
+#[contractimpl]
+impl VerificationAggregatorContract {
+    // ── Helpers ──────────────────────────────────────────────────────────────
+
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), AggregatorError> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(AggregatorError::NotInitialized)?;
+        if caller != &admin {
+            return Err(AggregatorError::Unauthorized);
+        }
+        caller.require_auth();
+        Ok(())
+    }
+
+    /// Ask `source` about `id`.  `None` when either is missing; a source that
+    /// fails the call counts as not verifying the project.
+    fn query(env: &Env, source: Option<Address>, id: Option<u64>) -> Option<bool> {
+        let (source, id) = (source?, id?);
+        let verified = VerificationSourceClient::new(env, &source)
+            .try_is_verified(&id)
+            .ok()
+            .and_then(|result| result.ok())
+            .unwrap_or(false);
+        Some(verified)
+    }
+
+    fn summarize(env: &Env, project_id: u64) -> Result<VerificationSummary, AggregatorError> {
+        let link: ProjectLink = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Link(project_id))
+            .ok_or(AggregatorError::ProjectNotLinked)?;
+        let policy = match env.storage().persistent().get(&DataKey::Policy(project_id)) {
+            Some(policy) => policy,
+            None => Self::get_default_policy(env.clone())?,
+        };
+
+        let registry = Self::query(
+            env,
+            env.storage().instance().get(&DataKey::ProjectRegistry),
+            link.registry_id,
+        );
+        let curation = Self::query(
+            env,
+            env.storage().instance().get(&DataKey::Curation),
+            link.curation_id,
+        );
+        let (in_registry, in_curation) = (registry == Some(true), curation == Some(true));
+        let verified = match policy {
+            VerificationPolicy::Registry => in_registry,
+            VerificationPolicy::Curation => in_curation,
+            VerificationPolicy::Either => in_registry || in_curation,
+            VerificationPolicy::Both => in_registry && in_curation,
+        };
+
+        Ok(VerificationSummary {
+            registry,
+            curation,
+            policy,
+            verified,
+        })
+    }
+
+    /// Point a source id at `project_id`, failing if another project has it.
+    fn claim_id(env: &Env, key: DataKey, project_id: u64) -> Result<(), AggregatorError> {
+        if let Some(owner) = env.storage().persistent().get::<_, u64>(&key) {
+            if owner != project_id {
+                return Err(AggregatorError::IdAlreadyLinked);
+            }
+        }
+        env.storage().persistent().set(&key, &project_id);
+        Ok(())
+    }
+
+    fn release_ids(env: &Env, link: &ProjectLink) {
+        if let Some(id) = link.registry_id {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::RegistryLink(id));
+        }
+        if let Some(id) = link.curation_id {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::CurationLink(id));
+        }
+    }
+
+    // ── Initialisation ────────────────────────────────────────────────────────
+
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        project_registry: Option<Address>,
+        curation: Option<Address>,
+        default_policy: VerificationPolicy,
+    ) -> Result<(), AggregatorError> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(AggregatorError::AlreadyInitialized);
+        }
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::DefaultPolicy, &default_policy);
+        if let Some(ref registry) = project_registry {
+            env.storage()
+                .instance()
+                .set(&DataKey::ProjectRegistry, registry);
+        }
+        if let Some(ref curation) = curation {
+            env.storage().instance().set(&DataKey::Curation, curation);
+        }
+
+        events::InitializedEvent { admin }.publish(&env);
+        Ok(())
+    }
+
+    // ── Project links ─────────────────────────────────────────────────────────
+
+    /// Link a crowdfund_vault project to its ids in the verification
+    /// sources, replacing any previous link.  Each source id can belong to
+    /// one project only.  `policy` overrides the default for this project.
+    pub fn link_project(
+        env: Env,
+        admin: Address,
+        project_id: u64,
+        registry_id: Option<u64>,
+        curation_id: Option<u64>,
+        policy: Option<VerificationPolicy>,
+    ) -> Result<(), AggregatorError> {
+        Self::require_admin(&env, &admin)?;
+        if registry_id.is_none() && curation_id.is_none() {
+            return Err(AggregatorError::EmptyLink);
+        }
+
+        if let Some(previous) = env
+            .storage()
+            .persistent()
+            .get::<_, ProjectLink>(&DataKey::Link(project_id))
+        {
+            Self::release_ids(&env, &previous);
+        }
+        if let Some(id) = registry_id {
+            Self::claim_id(&env, DataKey::RegistryLink(id), project_id)?;
+        }
+        if let Some(id) = curation_id {
+            Self::claim_id(&env, DataKey::CurationLink(id), project_id)?;
+        }
+
+        let link = ProjectLink {
+            registry_id,
+            curation_id,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Link(project_id), &link);
+        match policy {
+            Some(ref policy) => env
+                .storage()
+                .persistent()
+                .set(&DataKey::Policy(project_id), policy),
+            None => env
+                .storage()
+                .persistent()
+                .remove(&DataKey::Policy(project_id)),
+        }
+
+        events::ProjectLinkedEvent {
+            project_id,
+            registry_id,
+            curation_id,
+            policy,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    pub fn unlink_project(
+        env: Env,
+        admin: Address,
+        project_id: u64,
+    ) -> Result<(), AggregatorError> {
+        Self::require_admin(&env, &admin)?;
+        let link: ProjectLink = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Link(project_id))
+            .ok_or(AggregatorError::ProjectNotLinked)?;
+        Self::release_ids(&env, &link);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Link(project_id));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Policy(project_id));
+
+        events::ProjectUnlinkedEvent { project_id }.publish(&env);
+        Ok(())
+    }
+
+    // ── Queries ───────────────────────────────────────────────────────────────
+
+    /// Per-source verification of a linked project and the policy outcome.
+    pub fn get_verification(
+        env: Env,
+        project_id: u64,
+    ) -> Result<VerificationSummary, AggregatorError> {
+        Self::summarize(&env, project_id)
+    }
+
+    pub fn get_link(env: Env, project_id: u64) -> Option<ProjectLink> {
+        env.storage().persistent().get(&DataKey::Link(project_id))
+    }
+
+    /// crowdfund_vault project linked to a project_registry id.
+    pub fn get_project_for_registry_id(env: Env, registry_id: u64) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RegistryLink(registry_id))
+    }
+
+    /// crowdfund_vault project linked to a lumenpulse-curation id.
+    pub fn get_project_for_curation_id(env: Env, curation_id: u64) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::CurationLink(curation_id))
+    }
+
+    pub fn get_default_policy(env: Env) -> Result<VerificationPolicy, AggregatorError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::DefaultPolicy)
+            .ok_or(AggregatorError::NotInitialized)
+    }
+
+    pub fn get_sources(env: Env) -> (Option<Address>, Option<Address>) {
+        (
+            env.storage().instance().get(&DataKey::ProjectRegistry),
+            env.storage().instance().get(&DataKey::Curation),
+        )
+    }
+
+    pub fn get_admin(env: Env) -> Result<Address, AggregatorError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(AggregatorError::NotInitialized)
+    }
+
+    // ── Admin controls ────────────────────────────────────────────────────────
+
+    /// Set (or clear) the verification source contracts.
+    pub fn set_sources(
+        env: Env,
+        admin: Address,
+        project_registry: Option<Address>,
+        curation: Option<Address>,
+    ) -> Result<(), AggregatorError> {
+        Self::require_admin(&env, &admin)?;
+        for (key, source) in [
+            (DataKey::ProjectRegistry, &project_registry),
+            (DataKey::Curation, &curation),
+        ] {
+            match source {
+                Some(source) => env.storage().instance().set(&key, source),
+                None => env.storage().instance().remove(&key),
+            }
+        }
+
+        events::SourcesSetEvent {
+            admin,
+            project_registry,
+            curation,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    pub fn set_default_policy(
+        env: Env,
+        admin: Address,
+        policy: VerificationPolicy,
+    ) -> Result<(), AggregatorError> {
+        Self::require_admin(&env, &admin)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::DefaultPolicy, &policy);
+
+        events::DefaultPolicySetEvent { admin, policy }.publish(&env);
+        Ok(())
+    }
+
+    pub fn set_admin(
+        env: Env,
+        current_admin: Address,
+        new_admin: Address,
+    ) -> Result<(), AggregatorError> {
+        Self::require_admin(&env, &current_admin)?;
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        Ok(())
+    }
+
+    pub fn upgrade(
+        env: Env,
+        caller: Address,
+        new_wasm_hash: BytesN<32>,
+    ) -> Result<(), AggregatorError> {
+        Self::require_admin(&env, &caller)?;
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        Ok(())
+    }
+}
+
+#[contractimpl]
+impl VerificationSourceTrait for VerificationAggregatorContract {
+    /// Whether a linked project satisfies its policy.  Unlinked projects are
+    /// never verified.
+    fn is_verified(env: Env, project_id: u64) -> bool {
+        Self::summarize(&env, project_id)
+            .map(|summary| summary.verified)
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod test;
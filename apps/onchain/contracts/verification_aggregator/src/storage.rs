@@ -0,0 +1,49 @@
+use soroban_sdk::contracttype;
+
+/// Which sources must vouch for a project.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VerificationPolicy {
+    /// Verified in project_registry.
+    Registry,
+    /// Verified in lumenpulse-curation.
+    Curation,
+    /// Verified in at least one linked source.
+    Either,
+    /// Verified in both; a project missing either link is unverified.
+    Both,
+}
+
+/// Where a crowdfund_vault project lives in each verification source.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectLink {
+    /// Id in project_registry, if registered there.
+    pub registry_id: Option<u64>,
+    /// Id in lumenpulse-curation, if proposed there.
+    pub curation_id: Option<u64>,
+}
+
+/// Per-source breakdown behind `is_verified`.  A source is `None` when the
+/// project is not linked to it or the source is not configured.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerificationSummary {
+    pub registry: Option<bool>,
+    pub curation: Option<bool>,
+    pub policy: VerificationPolicy,
+    pub verified: bool,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,
+    ProjectRegistry,   // -> Address
+    Curation,          // -> Address
+    DefaultPolicy,     // -> VerificationPolicy
+    Link(u64),         // crowdfund_vault project_id -> ProjectLink
+    Policy(u64),       // crowdfund_vault project_id -> VerificationPolicy override
+    RegistryLink(u64), // project_registry id -> crowdfund_vault project_id
+    CurationLink(u64), // curation id -> crowdfund_vault project_id
+}
@@ -0,0 +1,183 @@
+use crate::errors::AggregatorError;
+use crate::storage::{VerificationPolicy, VerificationSummary};
+use crate::{VerificationAggregatorContract, VerificationAggregatorContractClient};
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, Env};
+
+#[contract]
+pub struct MockSource;
+
+#[contractimpl]
+impl MockSource {
+    pub fn set_verified(env: Env, project_id: u64, verified: bool) {
+        env.storage().persistent().set(&project_id, &verified);
+    }
+
+    pub fn is_verified(env: Env, project_id: u64) -> bool {
+        env.storage().persistent().get(&project_id).unwrap_or(false)
+    }
+}
+
+struct Setup<'a> {
+    client: VerificationAggregatorContractClient<'a>,
+    admin: Address,
+    registry: MockSourceClient<'a>,
+    curation: MockSourceClient<'a>,
+}
+
+fn setup(env: &Env, policy: VerificationPolicy) -> Setup<'_> {
+    let admin = Address::generate(env);
+    let registry_id = env.register(MockSource, ());
+    let curation_id = env.register(MockSource, ());
+    let id = env.register(VerificationAggregatorContract, ());
+    let client = VerificationAggregatorContractClient::new(env, &id);
+    client.initialize(
+        &admin,
+        &Some(registry_id.clone()),
+        &Some(curation_id.clone()),
+        &policy,
+    );
+    Setup {
+        client,
+        admin,
+        registry: MockSourceClient::new(env, &registry_id),
+        curation: MockSourceClient::new(env, &curation_id),
+    }
+}
+
+#[test]
+fn test_initialize_twice_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env, VerificationPolicy::Either);
+    assert_eq!(s.client.get_admin(), s.admin);
+    assert_eq!(
+        s.client
+            .try_initialize(&s.admin, &None, &None, &VerificationPolicy::Both),
+        Err(Ok(AggregatorError::AlreadyInitialized))
+    );
+}
+
+#[test]
+fn test_policies_combine_sources() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env, VerificationPolicy::Both);
+    // crowdfund_vault project 1 is project_registry #10 and curation #20.
+    s.client
+        .link_project(&s.admin, &1u64, &Some(10u64), &Some(20u64), &None);
+    assert!(!s.client.is_verified(&1u64));
+
+    s.registry.set_verified(&10u64, &true);
+    assert!(!s.client.is_verified(&1u64));
+    assert_eq!(
+        s.client.get_verification(&1u64),
+        VerificationSummary {
+            registry: Some(true),
+            curation: Some(false),
+            policy: VerificationPolicy::Both,
+            verified: false,
+        }
+    );
+
+    s.client
+        .set_default_policy(&s.admin, &VerificationPolicy::Either);
+    assert!(s.client.is_verified(&1u64));
+    s.client
+        .set_default_policy(&s.admin, &VerificationPolicy::Curation);
+    assert!(!s.client.is_verified(&1u64));
+
+    s.curation.set_verified(&20u64, &true);
+    s.client
+        .set_default_policy(&s.admin, &VerificationPolicy::Both);
+    assert!(s.client.is_verified(&1u64));
+
+    // Revocation in either source is reflected immediately.
+    s.registry.set_verified(&10u64, &false);
+    assert!(!s.client.is_verified(&1u64));
+}
+
+#[test]
+fn test_per_project_policy_and_missing_links() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env, VerificationPolicy::Both);
+    s.registry.set_verified(&10u64, &true);
+
+    // Only registered in project_registry: `Both` can never pass.
+    s.client
+        .link_project(&s.admin, &1u64, &Some(10u64), &None, &None);
+    assert!(!s.client.is_verified(&1u64));
+    assert_eq!(s.client.get_verification(&1u64).curation, None);
+
+    s.client.link_project(
+        &s.admin,
+        &1u64,
+        &Some(10u64),
+        &None,
+        &Some(VerificationPolicy::Registry),
+    );
+    assert!(s.client.is_verified(&1u64));
+
+    // Unlinked projects are not verified.
+    assert!(!s.client.is_verified(&2u64));
+    assert_eq!(
+        s.client.try_get_verification(&2u64),
+        Err(Ok(AggregatorError::ProjectNotLinked))
+    );
+}
+
+#[test]
+fn test_source_ids_map_to_one_project() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env, VerificationPolicy::Either);
+    s.client
+        .link_project(&s.admin, &1u64, &Some(10u64), &Some(20u64), &None);
+    assert_eq!(s.client.get_project_for_registry_id(&10u64), Some(1));
+    assert_eq!(s.client.get_project_for_curation_id(&20u64), Some(1));
+
+    assert_eq!(
+        s.client
+            .try_link_project(&s.admin, &2u64, &Some(10u64), &None, &None),
+        Err(Ok(AggregatorError::IdAlreadyLinked))
+    );
+    assert_eq!(
+        s.client
+            .try_link_project(&s.admin, &2u64, &None, &None, &None),
+        Err(Ok(AggregatorError::EmptyLink))
+    );
+
+    // Re-linking releases the old ids.
+    s.client
+        .link_project(&s.admin, &1u64, &Some(11u64), &None, &None);
+    assert_eq!(s.client.get_project_for_registry_id(&10u64), None);
+    assert_eq!(s.client.get_project_for_curation_id(&20u64), None);
+    s.client
+        .link_project(&s.admin, &2u64, &Some(10u64), &None, &None);
+
+    s.client.unlink_project(&s.admin, &1u64);
+    assert_eq!(s.client.get_link(&1u64), None);
+    assert_eq!(s.client.get_project_for_registry_id(&11u64), None);
+}
+
+#[test]
+fn test_admin_only_and_cleared_source() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env, VerificationPolicy::Either);
+    let other = Address::generate(&env);
+    assert_eq!(
+        s.client
+            .try_link_project(&other, &1u64, &Some(10u64), &None, &None),
+        Err(Ok(AggregatorError::Unauthorized))
+    );
+
+    s.client
+        .link_project(&s.admin, &1u64, &Some(10u64), &None, &None);
+    s.registry.set_verified(&10u64, &true);
+    assert!(s.client.is_verified(&1u64));
+
+    s.client.set_sources(&s.admin, &None, &None);
+    assert_eq!(s.client.get_sources(), (None, None));
+    assert!(!s.client.is_verified(&1u64));
+}
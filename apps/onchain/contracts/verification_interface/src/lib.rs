@@ -0,0 +1,12 @@
+#![no_std]
+
+use soroban_sdk::{contractclient, Env};
+
+/// Implemented by every contract that can vouch for a project:
+/// `project_registry`, `lumenpulse-curation` and `verification_aggregator`.
+/// Project ids are local to each implementation.
+#[contractclient(name = "VerificationSourceClient")]
+pub trait VerificationSourceTrait {
+    /// Whether the project is verified right now.
+    fn is_verified(env: Env, project_id: u64) -> bool;
+}